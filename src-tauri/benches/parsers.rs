@@ -0,0 +1,143 @@
+//! Parse time of the largest savegame files, on synthetic saves built by
+//! repeating the entities of the `savegame_complete` fixture.
+//!
+//! Run with `cargo bench --features bench`. The baseline printed before the
+//! measurements was taken on a Linux x86_64 machine, release profile, before
+//! any parallel or streaming parsing work; compare against it to spot regressions.
+
+use std::path::{Path, PathBuf};
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+use fs_25_save_editor_lib::bench::{parse_economy, parse_placeables, parse_vehicles};
+
+fn fixture_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("savegame_complete")
+}
+
+/// Repeats the run of `tag` elements of `xml` `copies` times. `rename` turns an
+/// element run into its n-th copy, to keep identifiers unique.
+fn repeat_elements(
+    xml: &str,
+    tag: &str,
+    copies: usize,
+    rename: impl Fn(&str, usize) -> String,
+) -> String {
+    let start = xml
+        .find(&format!("<{} ", tag))
+        .unwrap_or_else(|| panic!("no <{}> in fixture", tag));
+    let close = format!("</{}>", tag);
+    let end = xml.rfind(&close).map(|i| i + close.len()).unwrap();
+    let run = &xml[start..end];
+
+    let mut out = String::with_capacity(xml.len() + run.len() * copies);
+    out.push_str(&xml[..start]);
+    for copy in 0..copies {
+        out.push_str(&rename(run, copy));
+        out.push('\n');
+    }
+    out.push_str(&xml[end..]);
+    out
+}
+
+/// Writes a savegame folder holding the scaled-up file and returns its size in bytes.
+fn build_fixture(
+    dir: &Path,
+    file: &str,
+    tag: &str,
+    copies: usize,
+    rename: impl Fn(&str, usize) -> String,
+) -> u64 {
+    let xml = std::fs::read_to_string(fixture_path().join(file)).unwrap();
+    let scaled = repeat_elements(&xml, tag, copies, rename);
+    std::fs::create_dir_all(dir).unwrap();
+    std::fs::write(dir.join(file), &scaled).unwrap();
+    scaled.len() as u64
+}
+
+fn bench_parsers(c: &mut Criterion) {
+    let base = std::env::temp_dir().join("fs25_bench_parsers");
+    let _ = std::fs::remove_dir_all(&base);
+
+    let vehicles_dir = base.join("vehicles");
+    let vehicles_size = build_fixture(&vehicles_dir, "vehicles.xml", "vehicle", 2000, |run, n| {
+        run.replace(
+            "uniqueId=\"vehicle",
+            &format!("uniqueId=\"vehicle{:05}_", n),
+        )
+        .replace(
+            "attachedVehicleUniqueId=\"vehicle",
+            &format!("attachedVehicleUniqueId=\"vehicle{:05}_", n),
+        )
+    });
+    let placeables_dir = base.join("placeables");
+    let placeables_size = build_fixture(
+        &placeables_dir,
+        "placeables.xml",
+        "placeable",
+        1000,
+        |run, _| run.to_string(),
+    );
+    let economy_dir = base.join("economy");
+    let economy_size = build_fixture(&economy_dir, "economy.xml", "fillType", 1000, |run, n| {
+        run.replace("fillType=\"", &format!("fillType=\"BENCH{}_", n))
+    });
+
+    // (file, entities parsed, size, baseline time in ms)
+    let fixtures = [
+        (
+            "vehicles.xml",
+            parse_vehicles(&vehicles_dir).unwrap().len(),
+            vehicles_size,
+            54,
+        ),
+        (
+            "placeables.xml",
+            parse_placeables(&placeables_dir).unwrap().len(),
+            placeables_size,
+            23,
+        ),
+        (
+            "economy.xml",
+            parse_economy(&economy_dir).unwrap().fill_types.len(),
+            economy_size,
+            17,
+        ),
+    ];
+    for (file, count, size, baseline_ms) in fixtures {
+        println!(
+            "{}: {} entities, {:.1} MB, baseline ~{} ms",
+            file,
+            count,
+            size as f64 / 1_000_000.0,
+            baseline_ms
+        );
+    }
+
+    let mut group = c.benchmark_group("parse");
+    group.sample_size(20);
+
+    group.throughput(Throughput::Bytes(vehicles_size));
+    group.bench_function("vehicles", |b| {
+        b.iter(|| parse_vehicles(&vehicles_dir).unwrap())
+    });
+
+    group.throughput(Throughput::Bytes(placeables_size));
+    group.bench_function("placeables", |b| {
+        b.iter(|| parse_placeables(&placeables_dir).unwrap())
+    });
+
+    group.throughput(Throughput::Bytes(economy_size));
+    group.bench_function("economy", |b| {
+        b.iter(|| parse_economy(&economy_dir).unwrap())
+    });
+
+    group.finish();
+    let _ = std::fs::remove_dir_all(&base);
+}
+
+criterion_group!(benches, bench_parsers);
+criterion_main!(benches);
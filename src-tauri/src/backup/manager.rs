@@ -1,10 +1,14 @@
+use std::collections::{BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
 use chrono::Local;
 use fs_extra::dir::{self, CopyOptions};
 
 use crate::error::AppError;
-use crate::models::backup::BackupInfo;
+use crate::models::backup::{
+    BackupIndex, BackupInfo, BackupOverview, BackupRetentionPolicy, PruneResult, PrunedBackup,
+    SavegameBackups,
+};
 
 /// Returns the backups directory path for a given savegame path (public API).
 pub fn backups_dir_for(savegame_path: &Path) -> PathBuf {
@@ -24,6 +28,38 @@ fn backups_dir(savegame_path: &Path) -> PathBuf {
     savegame_path.parent().unwrap_or(savegame_path).join(dir_name)
 }
 
+/// Folder the game writes its own backups to, next to the savegame folders.
+const GAME_BACKUPS_DIR: &str = "savegameBackup";
+
+/// Returns the game's backup folder for a given savegame path.
+/// For savegame1/, the game writes savegameBackup/savegame1_backup2025-01-10_14-32/
+fn game_backups_dir(savegame_path: &Path) -> PathBuf {
+    savegame_path
+        .parent()
+        .unwrap_or(savegame_path)
+        .join(GAME_BACKUPS_DIR)
+}
+
+/// Returns true if `name` follows the game's backup naming scheme for this savegame.
+fn is_game_backup_name(savegame_path: &Path, name: &str) -> bool {
+    let prefix = format!(
+        "{}_backup",
+        savegame_path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+    );
+    let re = regex_lite::Regex::new(r"^\d{4}-\d{2}-\d{2}_\d{2}-\d{2}$").unwrap();
+    name.strip_prefix(&prefix)
+        .is_some_and(|timestamp| re.is_match(timestamp))
+}
+
+/// File of the backups folder holding the metadata of imported backups.
+const BACKUP_INDEX_FILE: &str = "index.json";
+
+/// Current version of the exported backup index format.
+pub const BACKUP_INDEX_VERSION: u32 = 1;
+
 /// Calculates the total size of a directory recursively.
 /// Skips symlinks to avoid following links outside the directory.
 fn dir_size(path: &Path) -> Result<u64, AppError> {
@@ -73,54 +109,306 @@ pub fn create_backup(savegame_path: &Path) -> Result<BackupInfo, AppError> {
         path: backup_path.display().to_string(),
         created_at: now.to_rfc3339(),
         size_bytes,
+        game_created: false,
     })
 }
 
-/// Lists all existing backups for a savegame, sorted by date descending.
-pub fn list_backups(savegame_path: &Path) -> Result<Vec<BackupInfo>, AppError> {
-    let backups = backups_dir(savegame_path);
-
-    if !backups.exists() {
-        return Ok(Vec::new());
+/// Savegame folders of `base_path` that may have backups: the savegames themselves,
+/// and the savegames named by editor or game backup folders, which outlive them.
+fn backed_up_savegames(base_path: &Path) -> Result<BTreeSet<PathBuf>, AppError> {
+    let mut savegames = BTreeSet::new();
+    if !base_path.is_dir() {
+        return Ok(savegames);
     }
 
-    let mut infos: Vec<BackupInfo> = Vec::new();
-
-    for entry in std::fs::read_dir(&backups)? {
+    for entry in std::fs::read_dir(base_path)? {
         let entry = entry?;
+        if !entry.file_type()?.is_dir() {
+            continue;
+        }
         let name = entry.file_name().to_string_lossy().to_string();
+        if let Some(savegame) = name.strip_suffix("_backups") {
+            savegames.insert(base_path.join(savegame));
+        } else if name.starts_with("savegame") && name != GAME_BACKUPS_DIR {
+            savegames.insert(entry.path());
+        }
+    }
+
+    let game_backups = base_path.join(GAME_BACKUPS_DIR);
+    if game_backups.is_dir() {
+        let re = regex_lite::Regex::new(r"^(.+)_backup\d{4}-\d{2}-\d{2}_\d{2}-\d{2}$").unwrap();
+        for entry in std::fs::read_dir(&game_backups)? {
+            let name = entry?.file_name().to_string_lossy().to_string();
+            if let Some(captures) = re.captures(&name) {
+                savegames.insert(base_path.join(&captures[1]));
+            }
+        }
+    }
 
-        if !name.starts_with("backup_") || !entry.file_type()?.is_dir() {
+    Ok(savegames)
+}
+
+/// Lists the backups of every savegame of `base_path` with `list_backups`, leaving
+/// out savegames without any. Savegames are sorted by folder name.
+pub fn list_all_backups(base_path: &Path) -> Result<BackupOverview, AppError> {
+    let mut savegames: Vec<SavegameBackups> = Vec::new();
+    for savegame_path in backed_up_savegames(base_path)? {
+        let backups = list_backups(&savegame_path)?;
+        if backups.is_empty() {
             continue;
         }
+        savegames.push(SavegameBackups {
+            savegame_name: savegame_path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            savegame_exists: savegame_path.is_dir(),
+            total_bytes: backups.iter().map(|b| b.size_bytes).sum(),
+            savegame_path: savegame_path.display().to_string(),
+            backups,
+        });
+    }
 
-        let path = entry.path();
-        let created_at = entry
-            .metadata()
-            .and_then(|m| m.modified())
-            .ok()
-            .and_then(|t| {
-                let datetime: chrono::DateTime<Local> = t.into();
-                Some(datetime.to_rfc3339())
-            })
-            .unwrap_or_default();
-
-        let size_bytes = dir_size(&path)?;
-
-        infos.push(BackupInfo {
-            name,
-            path: path.display().to_string(),
-            created_at,
-            size_bytes,
+    Ok(BackupOverview {
+        total_bytes: savegames.iter().map(|s| s.total_bytes).sum(),
+        savegames,
+    })
+}
+
+/// Deletes editor backups of the savegames of `base_path`, oldest first whatever
+/// their savegame, until all the backups fit in `policy.max_total_bytes`. Game
+/// backups count towards the budget but are never deleted, and the last backup
+/// of a savegame is kept unless `policy.allow_deleting_last` is set.
+pub fn prune_all_backups(
+    base_path: &Path,
+    policy: &BackupRetentionPolicy,
+) -> Result<PruneResult, AppError> {
+    let overview = list_all_backups(base_path)?;
+    let mut remaining_bytes = overview.total_bytes;
+    let mut remaining_counts: HashMap<String, usize> = overview
+        .savegames
+        .iter()
+        .map(|s| (s.savegame_path.clone(), s.backups.len()))
+        .collect();
+
+    // Editor backup names hold their timestamp, so they sort chronologically
+    let mut candidates: Vec<(&SavegameBackups, &BackupInfo)> = overview
+        .savegames
+        .iter()
+        .flat_map(|s| s.backups.iter().map(move |b| (s, b)))
+        .filter(|(_, b)| !b.game_created)
+        .collect();
+    candidates.sort_by(|(_, a), (_, b)| a.name.cmp(&b.name));
+
+    let mut pruned = Vec::new();
+    for (savegame, backup) in candidates {
+        if remaining_bytes <= policy.max_total_bytes {
+            break;
+        }
+        let count = remaining_counts
+            .get_mut(&savegame.savegame_path)
+            .expect("every listed savegame is counted");
+        if *count <= 1 && !policy.allow_deleting_last {
+            continue;
+        }
+
+        delete_backup(Path::new(&savegame.savegame_path), &backup.name)?;
+        *count -= 1;
+        remaining_bytes -= backup.size_bytes;
+        pruned.push(PrunedBackup {
+            savegame_path: savegame.savegame_path.clone(),
+            name: backup.name.clone(),
+            size_bytes: backup.size_bytes,
         });
     }
 
+    Ok(PruneResult {
+        reclaimed_bytes: pruned.iter().map(|p| p.size_bytes).sum(),
+        remaining_bytes,
+        pruned,
+    })
+}
+
+/// Builds the listing entry for a backup directory, dated by its modification time.
+fn backup_info(entry: &std::fs::DirEntry, game_created: bool) -> Result<BackupInfo, AppError> {
+    let path = entry.path();
+    let created_at = entry
+        .metadata()
+        .and_then(|m| m.modified())
+        .ok()
+        .map(|t| {
+            let datetime: chrono::DateTime<Local> = t.into();
+            datetime.to_rfc3339()
+        })
+        .unwrap_or_default();
+
+    let size_bytes = dir_size(&path)?;
+
+    Ok(BackupInfo {
+        name: entry.file_name().to_string_lossy().to_string(),
+        path: path.display().to_string(),
+        created_at,
+        size_bytes,
+        game_created,
+    })
+}
+
+/// Lists all existing backups for a savegame, sorted by date descending.
+/// Backups created by the editor come first, followed by the ones the game
+/// wrote itself into `savegameBackup/`.
+pub fn list_backups(savegame_path: &Path) -> Result<Vec<BackupInfo>, AppError> {
+    let mut infos: Vec<BackupInfo> = Vec::new();
+
+    let backups = backups_dir(savegame_path);
+    if backups.exists() {
+        for entry in std::fs::read_dir(&backups)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if !name.starts_with("backup_") || !entry.file_type()?.is_dir() {
+                continue;
+            }
+
+            infos.push(backup_info(&entry, false)?);
+        }
+
+        // Copied folders lose their modification time; keep the imported dates
+        let dates = imported_dates(&backups);
+        for info in &mut infos {
+            if let Some(created_at) = dates.get(&info.name) {
+                info.created_at = created_at.clone();
+            }
+        }
+    }
+
+    let game_backups = game_backups_dir(savegame_path);
+    if game_backups.is_dir() {
+        for entry in std::fs::read_dir(&game_backups)? {
+            let entry = entry?;
+            let name = entry.file_name().to_string_lossy().to_string();
+
+            if !is_game_backup_name(savegame_path, &name) || !entry.file_type()?.is_dir() {
+                continue;
+            }
+
+            infos.push(backup_info(&entry, true)?);
+        }
+    }
+
     // Sort by name descending (timestamp in name ensures chronological order)
-    infos.sort_by(|a, b| b.name.cmp(&a.name));
+    infos.sort_by(|a, b| {
+        a.game_created
+            .cmp(&b.game_created)
+            .then_with(|| b.name.cmp(&a.name))
+    });
 
     Ok(infos)
 }
 
+/// Creation dates of the backups listed in the index of `backups`, by backup name.
+/// A missing or unreadable index gives none.
+fn imported_dates(backups: &Path) -> HashMap<String, String> {
+    std::fs::read_to_string(backups.join(BACKUP_INDEX_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str::<BackupIndex>(&content).ok())
+        .map(|index| {
+            index
+                .backups
+                .into_iter()
+                .map(|b| (b.name, b.created_at))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn backup_index_error(e: serde_json::Error) -> AppError {
+    AppError::BackupError {
+        message: e.to_string(),
+    }
+}
+
+/// Write the list of the editor backups of a savegame to `destination` as JSON.
+/// Game backups are left out. Returns the number of backups exported.
+pub fn export_backup_index(savegame_path: &Path, destination: &Path) -> Result<usize, AppError> {
+    let backups: Vec<BackupInfo> = list_backups(savegame_path)?
+        .into_iter()
+        .filter(|b| !b.game_created)
+        .collect();
+    let index = BackupIndex {
+        version: BACKUP_INDEX_VERSION,
+        exported_at: Local::now().to_rfc3339(),
+        backups,
+    };
+    let json = serde_json::to_string_pretty(&index).map_err(backup_index_error)?;
+    std::fs::write(destination, json)?;
+    Ok(index.backups.len())
+}
+
+/// Import a backup index exported with `export_backup_index`, once the backup
+/// folders have been copied next to the savegame. Entries whose folder is missing
+/// are dropped; the others keep their original creation date. Returns the
+/// imported backups, with their paths on this machine.
+pub fn import_backup_index(
+    savegame_path: &Path,
+    source: &Path,
+) -> Result<Vec<BackupInfo>, AppError> {
+    let content = std::fs::read_to_string(source)?;
+    let index: BackupIndex = serde_json::from_str(&content).map_err(backup_index_error)?;
+    if index.version > BACKUP_INDEX_VERSION {
+        return Err(AppError::BackupError {
+            message: format!("Unsupported backup index version: {}", index.version),
+        });
+    }
+
+    let backups = backups_dir(savegame_path);
+    let mut imported: Vec<BackupInfo> = Vec::new();
+    for entry in index.backups {
+        let path = backups.join(&entry.name);
+        if validate_backup_name(&entry.name).is_err()
+            || !path.is_dir()
+            || imported.iter().any(|b| b.name == entry.name)
+        {
+            continue;
+        }
+        imported.push(BackupInfo {
+            path: path.display().to_string(),
+            size_bytes: dir_size(&path)?,
+            game_created: false,
+            ..entry
+        });
+    }
+
+    // Dates imported earlier for other backups are kept
+    let mut dates = imported_dates(&backups);
+    dates.retain(|name, _| backups.join(name).is_dir());
+    for backup in &imported {
+        dates.remove(&backup.name);
+    }
+    let mut stored: Vec<BackupInfo> = dates
+        .into_iter()
+        .map(|(name, created_at)| BackupInfo {
+            path: backups.join(&name).display().to_string(),
+            name,
+            created_at,
+            size_bytes: 0,
+            game_created: false,
+        })
+        .collect();
+    stored.extend(imported.iter().cloned());
+    stored.sort_by(|a, b| b.name.cmp(&a.name));
+
+    let stored_index = BackupIndex {
+        version: BACKUP_INDEX_VERSION,
+        exported_at: index.exported_at,
+        backups: stored,
+    };
+    let json = serde_json::to_string_pretty(&stored_index).map_err(backup_index_error)?;
+    std::fs::write(backups.join(BACKUP_INDEX_FILE), json)?;
+
+    Ok(imported)
+}
+
 /// Validates that a backup name matches the expected format and contains no path traversal.
 fn validate_backup_name(name: &str) -> Result<(), AppError> {
     let re = regex_lite::Regex::new(r"^backup_\d{4}-\d{2}-\d{2}_\d{2}h\d{2}m\d{2}s$").unwrap();
@@ -133,11 +421,15 @@ fn validate_backup_name(name: &str) -> Result<(), AppError> {
 }
 
 /// Restores a backup by replacing the savegame content.
+/// Accepts both editor backups and the game's own backups.
 /// Creates a safety backup first, then replaces.
 pub fn restore_backup(savegame_path: &Path, backup_name: &str) -> Result<(), AppError> {
-    validate_backup_name(backup_name)?;
-    let backups = backups_dir(savegame_path);
-    let backup_path = backups.join(backup_name);
+    let backup_path = if is_game_backup_name(savegame_path, backup_name) {
+        game_backups_dir(savegame_path).join(backup_name)
+    } else {
+        validate_backup_name(backup_name)?;
+        backups_dir(savegame_path).join(backup_name)
+    };
 
     if !backup_path.exists() {
         return Err(AppError::BackupError {
@@ -181,8 +473,32 @@ pub fn restore_backup(savegame_path: &Path, backup_name: &str) -> Result<(), App
     Ok(())
 }
 
+/// Restores the most recent backup the game wrote itself into `savegameBackup/`,
+/// after a safety backup of the current state. Gives a way back for users who
+/// never made an editor backup. Returns the restored backup.
+pub fn restore_latest_game_backup(savegame_path: &Path) -> Result<BackupInfo, AppError> {
+    let latest = list_backups(savegame_path)?
+        .into_iter()
+        .find(|b| b.game_created)
+        .ok_or_else(|| AppError::BackupError {
+            message: format!(
+                "No game backup found in {}",
+                game_backups_dir(savegame_path).display()
+            ),
+        })?;
+
+    restore_backup(savegame_path, &latest.name)?;
+    Ok(latest)
+}
+
 /// Deletes a specific backup.
+/// Backups written by the game are never deleted by the editor.
 pub fn delete_backup(savegame_path: &Path, backup_name: &str) -> Result<(), AppError> {
+    if is_game_backup_name(savegame_path, backup_name) {
+        return Err(AppError::BackupError {
+            message: format!("Game backups are read-only: {}", backup_name),
+        });
+    }
     validate_backup_name(backup_name)?;
     let backups = backups_dir(savegame_path);
     let backup_path = backups.join(backup_name);
@@ -221,6 +537,19 @@ mod tests {
         let _ = fs::remove_dir_all(backups);
     }
 
+    /// Creates a game-style backup folder for the savegame, as FS25 does on save.
+    fn setup_game_backup(save: &Path, timestamp: &str) -> PathBuf {
+        let name = format!(
+            "{}_backup{}",
+            save.file_name().unwrap().to_string_lossy(),
+            timestamp
+        );
+        let path = game_backups_dir(save).join(name);
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("careerSavegame.xml"), "<game>backup</game>").unwrap();
+        path
+    }
+
     #[test]
     fn test_create_backup_creates_directory() {
         let save = setup_temp_savegame("create");
@@ -274,6 +603,92 @@ mod tests {
         cleanup(&save);
     }
 
+    #[test]
+    fn test_list_all_backups() {
+        let base = std::env::temp_dir().join("fs25_backup_test_all");
+        let _ = fs::remove_dir_all(&base);
+        for save in ["savegame1", "savegame2", "savegame3"] {
+            fs::create_dir_all(base.join(save)).unwrap();
+            fs::write(base.join(save).join("careerSavegame.xml"), "<test>data</test>").unwrap();
+        }
+        create_backup(&base.join("savegame1")).unwrap();
+        setup_game_backup(&base.join("savegame2"), "2025-01-10_14-32");
+        // Backups of a deleted savegame are still listed
+        create_backup(&base.join("savegame3")).unwrap();
+        fs::remove_dir_all(base.join("savegame3")).unwrap();
+        fs::create_dir_all(base.join("savegame4")).unwrap();
+
+        let overview = list_all_backups(&base).unwrap();
+        let names: Vec<&str> = overview
+            .savegames
+            .iter()
+            .map(|s| s.savegame_name.as_str())
+            .collect();
+        assert_eq!(names, vec!["savegame1", "savegame2", "savegame3"]);
+        assert!(overview.savegames[1].backups[0].game_created);
+        assert!(!overview.savegames[2].savegame_exists);
+        assert_eq!(
+            overview.total_bytes,
+            overview.savegames.iter().map(|s| s.total_bytes).sum::<u64>()
+        );
+        assert!(overview.total_bytes > 0);
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_prune_all_backups() {
+        let base = std::env::temp_dir().join("fs25_backup_test_prune_all");
+        let _ = fs::remove_dir_all(&base);
+        let add_backup = |save: &str, name: &str| {
+            let path = base.join(format!("{}_backups", save)).join(name);
+            fs::create_dir_all(&path).unwrap();
+            fs::write(path.join("careerSavegame.xml"), "0123456789").unwrap();
+        };
+        add_backup("savegame1", "backup_2025-01-01_10h00m00s");
+        add_backup("savegame1", "backup_2025-03-01_10h00m00s");
+        add_backup("savegame2", "backup_2025-02-01_10h00m00s");
+        fs::create_dir_all(base.join("savegame2")).unwrap();
+        setup_game_backup(&base.join("savegame2"), "2024-12-01_09-00");
+        // 3 editor backups of 10 bytes and a game backup of 19 bytes
+
+        let keep_last = BackupRetentionPolicy {
+            max_total_bytes: 0,
+            allow_deleting_last: false,
+        };
+        let result = prune_all_backups(&base, &keep_last).unwrap();
+        let names: Vec<&str> = result.pruned.iter().map(|p| p.name.as_str()).collect();
+        // savegame2 keeps its game backup, so its editor backup goes too
+        assert_eq!(
+            names,
+            vec!["backup_2025-01-01_10h00m00s", "backup_2025-02-01_10h00m00s"]
+        );
+        assert_eq!(result.reclaimed_bytes, 20);
+        assert_eq!(result.remaining_bytes, 29);
+        assert!(base
+            .join("savegame1_backups")
+            .join("backup_2025-03-01_10h00m00s")
+            .exists());
+
+        let within_budget = BackupRetentionPolicy {
+            max_total_bytes: 30,
+            allow_deleting_last: true,
+        };
+        assert!(prune_all_backups(&base, &within_budget)
+            .unwrap()
+            .pruned
+            .is_empty());
+
+        let delete_last = BackupRetentionPolicy {
+            max_total_bytes: 0,
+            allow_deleting_last: true,
+        };
+        let result = prune_all_backups(&base, &delete_last).unwrap();
+        assert_eq!(result.pruned.len(), 1);
+        // Game backups are never deleted
+        assert_eq!(result.remaining_bytes, 19);
+        let _ = fs::remove_dir_all(&base);
+    }
+
     #[test]
     fn test_list_backups_empty() {
         let save = setup_temp_savegame("empty_list");
@@ -312,4 +727,112 @@ mod tests {
         cleanup(&save);
     }
 
+    #[test]
+    fn test_backup_index_export_import() {
+        let save = setup_temp_savegame("index");
+        let kept = create_backup(&save).unwrap();
+        let index_file = std::env::temp_dir().join("fs25_backup_test_index.json");
+        assert_eq!(export_backup_index(&save, &index_file).unwrap(), 1);
+
+        // Another machine: the index lists a backup whose folder was not copied
+        let mut index: BackupIndex =
+            serde_json::from_str(&fs::read_to_string(&index_file).unwrap()).unwrap();
+        index.backups[0].created_at = "2024-06-01T10:00:00+02:00".to_string();
+        index.backups[0].path = "/elsewhere/backup".to_string();
+        let mut stale = index.backups[0].clone();
+        stale.name = "backup_2020-01-01_00h00m00s".to_string();
+        index.backups.push(stale);
+        fs::write(&index_file, serde_json::to_string(&index).unwrap()).unwrap();
+
+        let imported = import_backup_index(&save, &index_file).unwrap();
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].name, kept.name);
+        assert_eq!(imported[0].path, kept.path);
+
+        let list = list_backups(&save).unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].created_at, "2024-06-01T10:00:00+02:00");
+
+        fs::write(&index_file, "{ not json").unwrap();
+        assert!(matches!(
+            import_backup_index(&save, &index_file),
+            Err(AppError::BackupError { .. })
+        ));
+        let _ = fs::remove_file(&index_file);
+        cleanup(&save);
+    }
+
+    #[test]
+    fn test_list_backups_includes_game_backups() {
+        let save = setup_temp_savegame("game_list");
+        let game_backup = setup_game_backup(&save, "2025-01-10_14-32");
+        // Not the game's naming scheme, ignored
+        fs::create_dir_all(game_backups_dir(&save).join("other_folder")).unwrap();
+        create_backup(&save).unwrap();
+
+        let list = list_backups(&save).unwrap();
+        assert_eq!(list.len(), 2);
+        assert!(!list[0].game_created);
+        assert!(list[1].game_created);
+        assert_eq!(list[1].path, game_backup.display().to_string());
+        assert!(list[1].size_bytes > 0);
+
+        let _ = fs::remove_dir_all(game_backup);
+        cleanup(&save);
+    }
+
+    #[test]
+    fn test_game_backup_restore_and_read_only() {
+        let save = setup_temp_savegame("game_restore");
+        let game_backup = setup_game_backup(&save, "2025-01-10_14-32");
+        let name = game_backup.file_name().unwrap().to_string_lossy().to_string();
+
+        assert!(delete_backup(&save, &name).is_err());
+        assert!(game_backup.exists());
+
+        restore_backup(&save, &name).unwrap();
+        let content = fs::read_to_string(save.join("careerSavegame.xml")).unwrap();
+        assert_eq!(content, "<game>backup</game>");
+        // The game's backup is left untouched
+        assert!(game_backup.join("careerSavegame.xml").exists());
+
+        let _ = fs::remove_dir_all(game_backup);
+        cleanup(&save);
+    }
+
+    #[test]
+    fn test_restore_latest_game_backup_copies_all_files() {
+        let save = setup_temp_savegame("game_restore_latest");
+        assert!(matches!(
+            restore_latest_game_backup(&save),
+            Err(AppError::BackupError { .. })
+        ));
+
+        let older = setup_game_backup(&save, "2025-01-10_14-32");
+        let latest = setup_game_backup(&save, "2025-02-01_09-05");
+        fs::write(latest.join("vehicles.xml"), "<vehicles>game</vehicles>").unwrap();
+        fs::create_dir_all(latest.join("mods")).unwrap();
+        fs::write(latest.join("mods").join("state.xml"), "<mods/>").unwrap();
+
+        let restored = restore_latest_game_backup(&save).unwrap();
+        assert_eq!(restored.path, latest.display().to_string());
+
+        let mut files: Vec<String> = fs::read_dir(&save)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        assert_eq!(files, vec!["careerSavegame.xml", "mods", "vehicles.xml"]);
+        assert_eq!(
+            fs::read_to_string(save.join("vehicles.xml")).unwrap(),
+            "<vehicles>game</vehicles>"
+        );
+        assert!(save.join("mods").join("state.xml").exists());
+        // The state before the restore was kept as an editor backup
+        assert!(list_backups(&save).unwrap().iter().any(|b| !b.game_created));
+
+        let _ = fs::remove_dir_all(older);
+        let _ = fs::remove_dir_all(latest);
+        cleanup(&save);
+    }
 }
@@ -0,0 +1,813 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
+
+use crate::backup::manager;
+use crate::error::AppError;
+use crate::models::catalog::{missing_vehicle_mods, used_mods, MissingVehicleMod, UsedMod};
+use crate::models::farm::{
+    project_finances as project_farm_finances, FinanceProjection, PlayerEntry,
+};
+use crate::models::field::{
+    valid_fruit_types as fruit_types_for, FarmFieldOwnership, FieldDetail, FieldOwnershipSummary,
+};
+use crate::models::mission::MissionStatus;
+use crate::models::net_worth::{
+    calculate_net_worth, calculate_vehicle_value, farmland_value, NetWorth, VehicleValue,
+};
+use crate::models::placeable::SellingStation;
+use crate::models::progress::{MilestoneProgress, MILESTONES};
+use crate::models::save_diff::SavegameDiff;
+use crate::models::save_size::{LandscapingReport, SaveFileSize, LANDSCAPING_FILES};
+use crate::models::vehicle::{
+    build_attachment_tree, ActiveWorker, AttachmentNode, FuelKind, FuelLevel, VehicleFuelReport,
+};
+use crate::models::SavegameData;
+use crate::parsers::career::parse_savegame_mods;
+use crate::parsers::economy::parse_economy;
+use crate::parsers::farm::parse_farms;
+use crate::parsers::field::{parse_farmlands, parse_fields};
+use crate::parsers::files::{
+    COLLECTIBLES, FARMLAND, FARMS, FIELDS, MISSIONS, PLACEABLES, SALES, TREE_PLANT, VEHICLES,
+};
+use crate::parsers::mission::parse_missions;
+use crate::parsers::placeable::parse_selling_stations;
+use crate::parsers::vehicle::parse_vehicles;
+use crate::services::catalog::{scan_installed_mods, scan_vehicle_catalog};
+use crate::services::density_map::map_farmland_areas;
+use crate::services::report::{render_report, ReportFormat};
+use crate::services::savegame_diff::diff_savegame_dirs;
+use crate::validators::path::validate_game_path;
+
+use super::catalog::get_mods_dir;
+use super::savegame::{load_savegame, resolve_save_path};
+
+/// Load the savegame and compute the net worth breakdown of one farm.
+#[tauri::command]
+pub fn compute_net_worth(path: String, farm_id: u8) -> Result<NetWorth, AppError> {
+    let data = load_savegame(path)?;
+    let farm = data
+        .farms
+        .iter()
+        .find(|f| f.farm_id == farm_id)
+        .ok_or_else(|| AppError::Generic(format!("Farm not found: {}", farm_id)))?;
+
+    Ok(calculate_net_worth(
+        farm,
+        &data.vehicles,
+        &data.placeables,
+        data.economy.as_ref(),
+    ))
+}
+
+/// Longest projection `project_finances` accepts, in in-game days.
+pub const MAX_PROJECTION_DAYS: u32 = 365;
+
+/// Estimate the balance of a farm over the next `days` in-game days, from the
+/// average of its recent daily finances, as a series for charting.
+#[tauri::command]
+pub fn project_finances(
+    path: String,
+    farm_id: u8,
+    days: u32,
+) -> Result<FinanceProjection, AppError> {
+    if days == 0 || days > MAX_PROJECTION_DAYS {
+        return Err(AppError::Generic(format!(
+            "Projection length must be between 1 and {} days",
+            MAX_PROJECTION_DAYS
+        )));
+    }
+    let data = load_savegame(path)?;
+    let farm = data
+        .farms
+        .iter()
+        .find(|f| f.farm_id == farm_id)
+        .ok_or_else(|| AppError::Generic(format!("Farm not found: {}", farm_id)))?;
+
+    Ok(project_farm_finances(farm, days))
+}
+
+/// Value of a vehicle including its attached implements and the goods they
+/// carry, at the average economy price.
+#[tauri::command]
+pub fn compute_vehicle_value(path: String, unique_id: String) -> Result<VehicleValue, AppError> {
+    let data = load_savegame(path)?;
+    calculate_vehicle_value(&unique_id, &data.vehicles, data.economy.as_ref())
+        .ok_or_else(|| AppError::Generic(format!("Vehicle not found: {}", unique_id)))
+}
+
+/// Fruit types a field of this savegame can be set to: the base game crops plus
+/// the ones its fields already use or economy.xml prices, so modded map crops are
+/// kept selectable. Only fields.xml and economy.xml are read.
+#[tauri::command]
+pub fn valid_fruit_types(path: String) -> Result<Vec<String>, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    let fields = parse_fields(&save_path)?;
+    let economy = parse_economy(&save_path).ok();
+    Ok(fruit_types_for(&fields, economy.as_ref()))
+}
+
+/// List every field with its owner, crop and growth state. With `game_path`, the
+/// area of each field is read from the map's farmland layer and its land valued;
+/// without it, or when the map data cannot be read, areas and values are `None`.
+#[tauri::command]
+pub fn list_fields_detailed(
+    path: String,
+    game_path: Option<String>,
+) -> Result<Vec<FieldDetail>, AppError> {
+    let data = load_savegame(path)?;
+    let areas = game_path
+        .and_then(|game_path| validate_game_path(&game_path).ok())
+        .and_then(|game_dir| map_farmland_areas(&game_dir, &data.career.map_id).ok())
+        .unwrap_or_default();
+    Ok(field_details(data, &areas))
+}
+
+/// Details of the fields of `data`, with their farmland areas in `areas`.
+fn field_details(data: SavegameData, areas: &HashMap<u32, f64>) -> Vec<FieldDetail> {
+    let owners: HashMap<u32, u8> = data.farmlands.iter().map(|f| (f.id, f.farm_id)).collect();
+    let multipliers = &data.career.economic_multipliers;
+
+    data.fields
+        .into_iter()
+        .map(|field| {
+            let area_hectares = areas.get(&field.id).copied();
+            FieldDetail {
+                id: field.id,
+                farm_id: owners.get(&field.id).copied().unwrap_or(0),
+                area_hectares,
+                value: area_hectares.map(|area| farmland_value(area, multipliers)),
+                fruit_type: field.fruit_type,
+                growth_state: field.growth_state,
+            }
+        })
+        .collect()
+}
+
+/// Count the fields owned, overall and per farm, and those under a running
+/// contract. Only fields.xml, farmland.xml and missions.xml are read.
+#[tauri::command]
+pub fn field_ownership_summary(path: String) -> Result<FieldOwnershipSummary, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    let fields = parse_fields(&save_path)?;
+    let owners: HashMap<u32, u8> = parse_farmlands(&save_path)?
+        .into_iter()
+        .map(|f| (f.id, f.farm_id))
+        .collect();
+    let contracted: HashSet<u32> = parse_missions(&save_path)?
+        .into_iter()
+        .filter(|m| m.status == MissionStatus::Running)
+        .filter_map(|m| m.field_id)
+        .collect();
+
+    let total_fields = fields.len();
+    let percent = |count: usize| {
+        if total_fields == 0 {
+            0.0
+        } else {
+            count as f64 * 100.0 / total_fields as f64
+        }
+    };
+
+    let mut owned_by_farm: BTreeMap<u8, usize> = BTreeMap::new();
+    for field in &fields {
+        match owners.get(&field.id) {
+            Some(&farm_id) if farm_id != 0 => *owned_by_farm.entry(farm_id).or_default() += 1,
+            _ => {}
+        }
+    }
+    let owned_fields = owned_by_farm.values().sum();
+
+    Ok(FieldOwnershipSummary {
+        total_fields,
+        owned_fields,
+        contracted_fields: fields.iter().filter(|f| contracted.contains(&f.id)).count(),
+        owned_percent: percent(owned_fields),
+        farms: owned_by_farm
+            .into_iter()
+            .map(|(farm_id, owned_fields)| FarmFieldOwnership {
+                farm_id,
+                owned_fields,
+                owned_percent: percent(owned_fields),
+            })
+            .collect(),
+    })
+}
+
+/// List the vehicles driven by a hired worker, with the worker's task, to find
+/// a forgotten worker still burning fuel and wages.
+#[tauri::command]
+pub fn list_active_workers(path: String) -> Result<Vec<ActiveWorker>, AppError> {
+    let data = load_savegame(path)?;
+    Ok(data
+        .vehicles
+        .into_iter()
+        .filter_map(|v| {
+            v.worker_task.map(|task| ActiveWorker {
+                unique_id: v.unique_id,
+                display_name: v.display_name,
+                farm_id: v.farm_id,
+                task,
+            })
+        })
+        .collect())
+}
+
+/// Share of capacity under which `fuel_report` flags a consumable, by default.
+const DEFAULT_LOW_FUEL_THRESHOLD: f64 = 0.25;
+
+/// List the vehicles of `farm_id` with diesel, DEF, electric charge or methane below
+/// `threshold` of capacity (a share between 0 and 1, 25% by default), to plan
+/// refueling. Fill units without a capacity in the save are left out.
+#[tauri::command]
+pub fn fuel_report(
+    path: String,
+    farm_id: u8,
+    threshold: Option<f64>,
+) -> Result<Vec<VehicleFuelReport>, AppError> {
+    let threshold = threshold.unwrap_or(DEFAULT_LOW_FUEL_THRESHOLD);
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(AppError::Generic(format!(
+            "Invalid fuel threshold: {}",
+            threshold
+        )));
+    }
+
+    let data = load_savegame(path)?;
+    Ok(data
+        .vehicles
+        .into_iter()
+        .filter(|v| v.farm_id == farm_id)
+        .filter_map(|v| {
+            let levels: Vec<FuelLevel> = v
+                .fill_units
+                .iter()
+                .filter_map(|unit| {
+                    let kind = FuelKind::from_fill_type(&unit.fill_type)?;
+                    let capacity = unit.capacity.filter(|c| *c > 0.0)?;
+                    Some(FuelLevel {
+                        kind,
+                        fill_level: unit.fill_level,
+                        capacity,
+                        is_low: unit.fill_level < capacity * threshold,
+                    })
+                })
+                .collect();
+            if !levels.iter().any(|l| l.is_low) {
+                return None;
+            }
+            Some(VehicleFuelReport {
+                unique_id: v.unique_id,
+                display_name: v.display_name,
+                levels,
+            })
+        })
+        .collect())
+}
+
+/// Progress of the player's farm (farm 1) towards achievement-style milestones on
+/// its statistics and money: hectares worked, animals bred, first million...
+#[tauri::command]
+pub fn progress_summary(path: String) -> Result<Vec<MilestoneProgress>, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    let farms = parse_farms(&save_path)?;
+    let farm = farms
+        .iter()
+        .find(|f| f.farm_id == 1)
+        .ok_or_else(|| AppError::Generic("Farm not found: 1".to_string()))?;
+
+    Ok(MILESTONES.iter().map(|m| m.progress(farm)).collect())
+}
+
+/// List the players of every farm with their permissions, so a co-op host can
+/// audit who may do what.
+#[tauri::command]
+pub fn list_players(path: String) -> Result<Vec<PlayerEntry>, AppError> {
+    let data = load_savegame(path)?;
+    Ok(data
+        .farms
+        .into_iter()
+        .flat_map(|farm| {
+            let (farm_id, farm_name) = (farm.farm_id, farm.name);
+            farm.players.into_iter().map(move |player| PlayerEntry {
+                farm_id,
+                farm_name: farm_name.clone(),
+                player,
+            })
+        })
+        .collect())
+}
+
+/// List the selling stations for which the save keeps sales statistics.
+/// Saves that do not store them give an empty list.
+#[tauri::command]
+pub fn list_selling_stations(path: String) -> Result<Vec<SellingStation>, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    parse_selling_stations(&save_path)
+}
+
+/// List the mods of the mods folder the savegame uses, with the version the save
+/// was played with next to the installed one. Mods the save lists but that are
+/// not installed are left out.
+#[tauri::command]
+pub fn list_used_mods(path: String) -> Result<Vec<UsedMod>, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    let save_mods = parse_savegame_mods(&save_path)?;
+    if save_mods.is_empty() {
+        return Ok(Vec::new());
+    }
+    Ok(used_mods(&save_mods, &scan_installed_mods(&get_mods_dir())))
+}
+
+/// List the mods whose vehicles, owned or leased by a farm, are missing from the
+/// vehicle catalog of `game_path` and the mods folder, with the vehicles the
+/// save would lose. Unlike `list_used_mods`, this ties each mod to equipment.
+#[tauri::command]
+pub fn list_missing_vehicle_mods(
+    path: String,
+    game_path: String,
+) -> Result<Vec<MissingVehicleMod>, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    let game_path = validate_game_path(&game_path)?;
+    let vehicles = parse_vehicles(&save_path)?;
+    let catalog = scan_vehicle_catalog(&game_path, &get_mods_dir());
+    Ok(missing_vehicle_mods(&vehicles, &catalog))
+}
+
+/// Group the savegame's vehicles into attachment trees, so a whole rig can be
+/// shown and handled together.
+#[tauri::command]
+pub fn get_attachment_tree(path: String) -> Result<Vec<AttachmentNode>, AppError> {
+    let data = load_savegame(path)?;
+    Ok(build_attachment_tree(&data.vehicles))
+}
+
+/// Number of entities the editor parsed from `file`, if it is a file it models as a list.
+fn entity_count(file: &str, data: &SavegameData) -> Option<usize> {
+    match file {
+        FARMS => Some(data.farms.len()),
+        VEHICLES => Some(data.vehicles.len()),
+        SALES => Some(data.sales.len()),
+        FIELDS => Some(data.fields.len()),
+        FARMLAND => Some(data.farmlands.len()),
+        PLACEABLES => Some(data.placeables.len()),
+        MISSIONS => Some(data.missions.len()),
+        COLLECTIBLES => Some(data.collectibles.len()),
+        TREE_PLANT => Some(data.trees.len()),
+        _ => None,
+    }
+}
+
+/// List every XML file of the savegame with its size and entity count,
+/// largest first, to spot bloated files.
+#[tauri::command]
+pub fn analyze_save_size(path: String) -> Result<Vec<SaveFileSize>, AppError> {
+    let data = load_savegame(path)?;
+
+    let mut sizes = Vec::new();
+    for entry in std::fs::read_dir(&data.path)? {
+        let entry = entry?;
+        let entry_path = entry.path();
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() || entry_path.extension().and_then(|e| e.to_str()) != Some("xml") {
+            continue;
+        }
+
+        let file = entry.file_name().to_string_lossy().to_string();
+        sizes.push(SaveFileSize {
+            entity_count: entity_count(&file, &data),
+            size_bytes: metadata.len(),
+            file,
+        });
+    }
+
+    sizes.sort_by(|a, b| {
+        b.size_bytes
+            .cmp(&a.size_bytes)
+            .then_with(|| a.file.cmp(&b.file))
+    });
+    Ok(sizes)
+}
+
+/// Report the landscaping files of the savegame and the number of placed
+/// decorations, to explain a large save. Missing files are simply not listed.
+#[tauri::command]
+pub fn analyze_landscaping(path: String) -> Result<LandscapingReport, AppError> {
+    let data = load_savegame(path)?;
+    let save_path = Path::new(&data.path);
+
+    let files: Vec<SaveFileSize> = LANDSCAPING_FILES
+        .iter()
+        .filter_map(|file| {
+            let metadata = std::fs::metadata(save_path.join(file)).ok()?;
+            Some(SaveFileSize {
+                file: file.to_string(),
+                size_bytes: metadata.len(),
+                entity_count: None,
+            })
+        })
+        .collect();
+
+    Ok(LandscapingReport {
+        total_bytes: files.iter().map(|f| f.size_bytes).sum(),
+        files,
+        decoration_count: data
+            .placeables
+            .iter()
+            .filter(|p| p.filename.to_lowercase().contains("/decoration"))
+            .count(),
+    })
+}
+
+/// Write a markdown or HTML summary report of the savegame to `out_path`.
+#[tauri::command]
+pub fn export_report(path: String, format: ReportFormat, out_path: String) -> Result<(), AppError> {
+    let data = load_savegame(path)?;
+    let report = render_report(&data, format);
+    std::fs::write(&out_path, report).map_err(|e| AppError::IoError {
+        message: format!("{}: {}", out_path, e),
+    })?;
+    Ok(())
+}
+
+/// Compare the savegame with its most recent editor backup, to review the last
+/// edit or see what the game changed since. Returns `None` when there is no backup.
+#[tauri::command]
+pub fn diff_against_latest_backup(path: String) -> Result<Option<SavegameDiff>, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    let Some(latest) = manager::list_backups(&save_path)?
+        .into_iter()
+        .find(|b| !b.game_created)
+    else {
+        return Ok(None);
+    };
+
+    diff_savegame_dirs(Path::new(&latest.path), &save_path).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::net_worth::FARMLAND_PRICE_PER_HECTARE;
+
+    fn complete_fixture_path() -> String {
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_complete")
+            .display()
+            .to_string()
+    }
+
+    #[test]
+    fn test_compute_net_worth_breakdown() {
+        let worth = compute_net_worth(complete_fixture_path(), 1).unwrap();
+        assert_eq!(worth.farm_id, 1);
+        assert_eq!(worth.money, 1000000.0);
+        // The rented vehicle is excluded
+        assert_eq!(worth.vehicles_value, 868000.0);
+        assert_eq!(worth.placeables_value, 405000.0);
+        // 2500 L of wheat at the yearly average price; flour has no price
+        assert!((worth.stored_crops_value - 2500.0 * 4292.0 / 12000.0).abs() < 1e-6);
+        assert_eq!(worth.loan, 50000.0);
+        let expected = 1000000.0 + 868000.0 + 405000.0 + worth.stored_crops_value - 50000.0;
+        assert!((worth.total - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_compute_net_worth_unknown_farm() {
+        let result = compute_net_worth(complete_fixture_path(), 42);
+        assert!(matches!(result, Err(AppError::Generic(_))));
+    }
+
+    #[test]
+    fn test_compute_vehicle_value_with_loaded_trailer() {
+        let value =
+            compute_vehicle_value(complete_fixture_path(), "vehicle0001".to_string()).unwrap();
+        assert_eq!(value.price, 348000.0);
+        assert_eq!(value.implements_price, 45000.0);
+        // 12000 L of wheat in the trailer at the yearly average price
+        assert!((value.fill_value - 12000.0 * 4292.0 / 12000.0).abs() < 1e-6);
+        assert!((value.total - (348000.0 + 45000.0 + value.fill_value)).abs() < 1e-6);
+        // The tractor's fuel has no economy price
+        assert_eq!(value.unpriced_fill_types, vec!["DEF", "DIESEL"]);
+
+        let trailer =
+            compute_vehicle_value(complete_fixture_path(), "vehicle0003".to_string()).unwrap();
+        assert_eq!(trailer.implements_price, 0.0);
+        assert!(trailer.unpriced_fill_types.is_empty());
+
+        let result = compute_vehicle_value(complete_fixture_path(), "vehicle9999".to_string());
+        assert!(matches!(result, Err(AppError::Generic(_))));
+    }
+
+    /// Copy of the complete fixture in a temp directory, for tests adding files to it.
+    fn writable_fixture(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("fs25_test_an_{}", name));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        for entry in std::fs::read_dir(complete_fixture_path()).unwrap() {
+            let entry = entry.unwrap();
+            std::fs::copy(entry.path(), path.join(entry.file_name())).unwrap();
+        }
+        path
+    }
+
+    #[test]
+    fn test_valid_fruit_types() {
+        let path = writable_fixture("fruit_types");
+        let fields_xml = path.join("fields.xml");
+        let content = std::fs::read_to_string(&fields_xml).unwrap();
+        std::fs::write(
+            &fields_xml,
+            content.replace(r#"plannedFruit="BARLEY""#, r#"plannedFruit="RYE""#),
+        )
+        .unwrap();
+
+        let economy_xml = path.join("economy.xml");
+        let content = std::fs::read_to_string(&economy_xml).unwrap();
+        let priced = r#"<fillType fillType="SPELT" totalAmount="10"></fillType>
+            <fillType fillType="MILK" totalAmount="10"></fillType>"#;
+        std::fs::write(
+            &economy_xml,
+            content.replace(r#"<fillType fillType="UNKNOWN"/>"#, priced),
+        )
+        .unwrap();
+
+        let fruits = valid_fruit_types(path.display().to_string()).unwrap();
+        assert_eq!(fruits[0], "WHEAT");
+        assert!(fruits.contains(&"FALLOW".to_string()));
+        // The modded crops come last, once, sorted
+        let extra = &fruits[fruits.len() - 2..];
+        assert_eq!(extra, ["RYE".to_string(), "SPELT".to_string()]);
+        assert_eq!(fruits.iter().filter(|f| f.as_str() == "CANOLA").count(), 1);
+        // A priced product is not a crop
+        assert!(!fruits.contains(&"MILK".to_string()));
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_list_active_workers() {
+        let workers = list_active_workers(complete_fixture_path()).unwrap();
+        assert_eq!(workers.len(), 1);
+        assert_eq!(workers[0].unique_id, "vehicle0001");
+        assert_eq!(workers[0].display_name, "Fendt 942 Vario");
+        assert_eq!(workers[0].task, "FIELDWORK");
+    }
+
+    #[test]
+    fn test_project_finances() {
+        let projection = project_finances(complete_fixture_path(), 1, 3).unwrap();
+        // Days 1 and 2 of the history net -192150 and +69650
+        assert_eq!(projection.days_sampled, 2);
+        assert_eq!(projection.average_daily_net, -61250.0);
+        let money: Vec<f64> = projection.balances.iter().map(|b| b.money).collect();
+        assert_eq!(money, vec![938750.0, 877500.0, 816250.0]);
+        assert_eq!(projection.balances[2].day, 3);
+
+        assert!(matches!(
+            project_finances(complete_fixture_path(), 1, 0),
+            Err(AppError::Generic(_))
+        ));
+        assert!(matches!(
+            project_finances(complete_fixture_path(), 9, 3),
+            Err(AppError::Generic(_))
+        ));
+    }
+
+    #[test]
+    fn test_list_fields_detailed() {
+        let fields = list_fields_detailed(complete_fixture_path(), None).unwrap();
+        assert_eq!(fields.len(), 4);
+        assert_eq!(fields[1].id, 2);
+        assert_eq!(fields[1].farm_id, 1);
+        assert_eq!(fields[2].farm_id, 0);
+        assert!(fields.iter().all(|f| f.area_hectares.is_none()));
+
+        // An unusable game path only leaves the areas out
+        let missing = std::env::temp_dir().join("fs25_test_an_no_game");
+        let fields =
+            list_fields_detailed(complete_fixture_path(), Some(missing.display().to_string()))
+                .unwrap();
+        assert_eq!(fields.len(), 4);
+        assert!(fields[0].area_hectares.is_none());
+        assert!(fields.iter().all(|f| f.value.is_none()));
+    }
+
+    #[test]
+    fn test_field_details_value() {
+        let data = load_savegame(complete_fixture_path()).unwrap();
+        let expenses = data.career.economic_multipliers.expenses;
+        let areas = HashMap::from([(2, 1.5)]);
+        let fields = field_details(data, &areas);
+
+        assert_eq!(fields[1].area_hectares, Some(1.5));
+        assert_eq!(fields[1].value, Some(1.5 * FARMLAND_PRICE_PER_HECTARE * expenses));
+        // No area, no value
+        assert_eq!(fields[0].value, None);
+    }
+
+    #[test]
+    fn test_field_ownership_summary() {
+        let summary = field_ownership_summary(complete_fixture_path()).unwrap();
+        assert_eq!(summary.total_fields, 4);
+        // Farmlands 1, 2 and 4 belong to farm 1
+        assert_eq!(summary.owned_fields, 3);
+        assert!((summary.owned_percent - 75.0).abs() < 1e-9);
+        // The running mission is on field 5, which fields.xml does not list
+        assert_eq!(summary.contracted_fields, 0);
+        assert_eq!(summary.farms.len(), 1);
+        assert_eq!(summary.farms[0].farm_id, 1);
+        assert_eq!(summary.farms[0].owned_fields, 3);
+    }
+
+    #[test]
+    fn test_fuel_report() {
+        // Tanks of the fixture are half full: none is low by default
+        let report = fuel_report(complete_fixture_path(), 1, None).unwrap();
+        assert!(report.is_empty());
+
+        let report = fuel_report(complete_fixture_path(), 1, Some(0.6)).unwrap();
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].unique_id, "vehicle0001");
+        assert_eq!(report[0].levels.len(), 2);
+        assert_eq!(report[0].levels[1].kind, FuelKind::Def);
+        assert!((report[0].levels[1].capacity - 30.0).abs() < 1e-9);
+        assert!(report.iter().all(|v| v.levels.iter().all(|l| l.is_low)));
+
+        assert!(fuel_report(complete_fixture_path(), 1, Some(1.5)).is_err());
+    }
+
+    #[test]
+    fn test_progress_summary() {
+        let progress = progress_summary(complete_fixture_path()).unwrap();
+        assert_eq!(progress.len(), MILESTONES.len());
+        let find = |id: &str| progress.iter().find(|p| p.id == id).unwrap();
+
+        // 120.5 ha worked and exactly one million on the farm
+        let worked = find("workedHectares1000");
+        assert!((worked.current - 120.5).abs() < 1e-9);
+        assert!((worked.percent - 12.05).abs() < 1e-9);
+        assert!(!worked.achieved);
+        assert!(find("workedHectares100").achieved);
+        assert_eq!(find("workedHectares100").percent, 100.0);
+        assert!(find("firstMillion").achieved);
+        assert_eq!(find("bales1000").current, 42.0);
+
+        assert!(progress_summary("/nonexistent/savegame1".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_list_players() {
+        let players = list_players(complete_fixture_path()).unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].farm_id, 1);
+        assert_eq!(players[0].farm_name, "My Farm");
+        assert_eq!(players[0].player.last_nickname, "TestPlayer");
+        assert!(players[0].player.farm_manager);
+        assert!(players[0].player.manage_contracts);
+    }
+
+    #[test]
+    fn test_get_attachment_tree() {
+        let roots = get_attachment_tree(complete_fixture_path()).unwrap();
+        let ids: Vec<&str> = roots.iter().map(|r| r.unique_id.as_str()).collect();
+        assert_eq!(ids, vec!["vehicle0001", "vehicle0002"]);
+        assert_eq!(roots[0].children.len(), 1);
+        assert_eq!(roots[0].children[0].unique_id, "vehicle0003");
+        assert!(roots[0].children[0].children.is_empty());
+    }
+
+    #[test]
+    fn test_attachment_tree_breaks_cycles() {
+        let mut vehicles = load_savegame(complete_fixture_path()).unwrap().vehicles;
+        // Corrupt save: the trailer claims the tractor pulling it
+        let mut back_link = vehicles[0].attached_implements[0].clone();
+        back_link.attached_vehicle_unique_id = "vehicle0001".to_string();
+        vehicles[2].attached_implements.push(back_link);
+
+        let roots = build_attachment_tree(&vehicles);
+        let ids: Vec<&str> = roots.iter().map(|r| r.unique_id.as_str()).collect();
+        assert_eq!(ids, vec!["vehicle0002", "vehicle0001"]);
+        assert_eq!(roots[1].children[0].unique_id, "vehicle0003");
+        assert!(roots[1].children[0].children.is_empty());
+    }
+
+    #[test]
+    fn test_analyze_save_size_sorted_with_counts() {
+        let sizes = analyze_save_size(complete_fixture_path()).unwrap();
+
+        assert!(sizes.windows(2).all(|w| w[0].size_bytes >= w[1].size_bytes));
+        assert!(sizes.iter().all(|s| s.file.ends_with(".xml")));
+
+        let vehicles = sizes.iter().find(|s| s.file == "vehicles.xml").unwrap();
+        let expected =
+            std::fs::metadata(std::path::Path::new(&complete_fixture_path()).join("vehicles.xml"))
+                .unwrap()
+                .len();
+        assert_eq!(vehicles.size_bytes, expected);
+        assert!(vehicles.entity_count.unwrap() > 0);
+
+        let trees = sizes.iter().find(|s| s.file == "treePlant.xml").unwrap();
+        assert_eq!(trees.entity_count, Some(4));
+
+        let career = sizes
+            .iter()
+            .find(|s| s.file == "careerSavegame.xml")
+            .unwrap();
+        assert_eq!(career.entity_count, None);
+    }
+
+    #[test]
+    fn test_analyze_landscaping() {
+        let report = analyze_landscaping(complete_fixture_path()).unwrap();
+        assert!(report.files.is_empty());
+        assert_eq!(report.total_bytes, 0);
+
+        let path = writable_fixture("landscaping");
+        std::fs::write(path.join("terrain.heightmap.png"), vec![0u8; 2048]).unwrap();
+        let report = analyze_landscaping(path.display().to_string()).unwrap();
+        assert_eq!(report.files.len(), 1);
+        assert_eq!(report.files[0].file, "terrain.heightmap.png");
+        assert_eq!(report.total_bytes, 2048);
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_export_report_markdown() {
+        let out = std::env::temp_dir().join("fs25_test_report.md");
+        export_report(
+            complete_fixture_path(),
+            ReportFormat::Markdown,
+            out.display().to_string(),
+        )
+        .unwrap();
+
+        let report = std::fs::read_to_string(&out).unwrap();
+        assert!(report.starts_with("# Test Complete\n"));
+        assert!(report.contains("## Farm finances"));
+        assert!(report.contains("| 1 | My Farm | 1000000 | 50000 |"));
+        assert!(report.contains("## Fleet (3 vehicles)"));
+        assert!(report.contains("| Rented |"));
+        assert!(report.contains("## Fields"));
+        assert!(report.contains("## Diagnostics"));
+        let _ = std::fs::remove_file(&out);
+    }
+
+    #[test]
+    fn test_export_report_html() {
+        let out = std::env::temp_dir().join("fs25_test_report.html");
+        export_report(
+            complete_fixture_path(),
+            ReportFormat::Html,
+            out.display().to_string(),
+        )
+        .unwrap();
+
+        let report = std::fs::read_to_string(&out).unwrap();
+        assert!(report.starts_with("<!DOCTYPE html>"));
+        assert!(report.contains("<h1>Test Complete</h1>"));
+        assert!(report.contains("<td>My Farm</td>"));
+        assert!(report.trim_end().ends_with("</html>"));
+        let _ = std::fs::remove_file(&out);
+    }
+
+    #[test]
+    fn test_diff_against_latest_backup() {
+        let src = std::path::PathBuf::from(complete_fixture_path());
+        let save = std::env::temp_dir().join("fs25_test_diff_latest_backup");
+        let backups = std::env::temp_dir().join("fs25_test_diff_latest_backup_backups");
+        let _ = std::fs::remove_dir_all(&save);
+        let _ = std::fs::remove_dir_all(&backups);
+        let mut opts = fs_extra::dir::CopyOptions::new();
+        opts.copy_inside = true;
+        fs_extra::dir::copy(&src, &save, &opts).unwrap();
+        let path = save.display().to_string();
+
+        assert!(diff_against_latest_backup(path.clone()).unwrap().is_none());
+
+        manager::create_backup(&save).unwrap();
+        let farms = save.join("farms.xml");
+        let content = std::fs::read_to_string(&farms).unwrap();
+        std::fs::write(&farms, content.replace("1000000", "5")).unwrap();
+
+        let diff = diff_against_latest_backup(path).unwrap().unwrap();
+        assert_eq!(diff.files.len(), 1);
+        assert_eq!(diff.files[0].file, "farms.xml");
+        assert_eq!(
+            diff.files[0].change,
+            crate::models::save_diff::FileChange::Modified
+        );
+
+        let _ = std::fs::remove_dir_all(&save);
+        let _ = std::fs::remove_dir_all(&backups);
+    }
+}
@@ -1,10 +1,12 @@
+use std::path::PathBuf;
+
 use tauri::AppHandle;
 use tauri_plugin_opener::OpenerExt;
 
 use crate::backup::manager;
 use crate::error::AppError;
-use crate::models::backup::BackupInfo;
-use crate::validators::path::validate_savegame_path;
+use crate::models::backup::{BackupInfo, BackupOverview, BackupRetentionPolicy, PruneResult};
+use crate::validators::path::{validate_savegame_path, validate_savegames_base_path};
 
 #[tauri::command]
 pub fn list_backups(savegame_path: String) -> Result<Vec<BackupInfo>, AppError> {
@@ -12,6 +14,25 @@ pub fn list_backups(savegame_path: String) -> Result<Vec<BackupInfo>, AppError>
     manager::list_backups(&path)
 }
 
+/// List the backups of every savegame of the savegames folder `base_path`, with
+/// their total size, to see what takes disk space.
+#[tauri::command]
+pub fn list_all_backups(base_path: String) -> Result<BackupOverview, AppError> {
+    let path = validate_savegames_base_path(&base_path)?;
+    manager::list_all_backups(&path)
+}
+
+/// Delete the oldest editor backups of the savegames folder `base_path` until all
+/// the backups fit in the disk budget of `policy`.
+#[tauri::command]
+pub fn prune_all_backups(
+    base_path: String,
+    policy: BackupRetentionPolicy,
+) -> Result<PruneResult, AppError> {
+    let path = validate_savegames_base_path(&base_path)?;
+    manager::prune_all_backups(&path, &policy)
+}
+
 #[tauri::command]
 pub fn create_backup(savegame_path: String) -> Result<BackupInfo, AppError> {
     let path = validate_savegame_path(&savegame_path)?;
@@ -24,12 +45,39 @@ pub fn restore_backup(savegame_path: String, backup_name: String) -> Result<(),
     manager::restore_backup(&path, &backup_name)
 }
 
+/// Restore the latest backup written by the game itself, for users who never
+/// made a backup with the editor.
+#[tauri::command]
+pub fn restore_game_backup(savegame_path: String) -> Result<BackupInfo, AppError> {
+    let path = validate_savegame_path(&savegame_path)?;
+    manager::restore_latest_game_backup(&path)
+}
+
 #[tauri::command]
 pub fn delete_backup(savegame_path: String, backup_name: String) -> Result<(), AppError> {
     let path = validate_savegame_path(&savegame_path)?;
     manager::delete_backup(&path, &backup_name)
 }
 
+/// Export the list of editor backups with their metadata to a JSON file, to move
+/// it to another machine along with the backup folders. Returns the number exported.
+#[tauri::command]
+pub fn export_backup_index(savegame_path: String, destination: String) -> Result<usize, AppError> {
+    let path = validate_savegame_path(&savegame_path)?;
+    manager::export_backup_index(&path, &PathBuf::from(destination))
+}
+
+/// Import a backup index once the backup folders were copied. Entries without a
+/// backup folder are dropped. Returns the imported backups.
+#[tauri::command]
+pub fn import_backup_index(
+    savegame_path: String,
+    source: String,
+) -> Result<Vec<BackupInfo>, AppError> {
+    let path = validate_savegame_path(&savegame_path)?;
+    manager::import_backup_index(&path, &PathBuf::from(source))
+}
+
 #[tauri::command]
 pub fn open_backups_folder(app: AppHandle, savegame_path: String) -> Result<(), AppError> {
     let validated = validate_savegame_path(&savegame_path)?;
@@ -1,13 +1,16 @@
 use std::path::PathBuf;
 use std::sync::Mutex;
 
-use tauri::State;
+use tauri::{AppHandle, Emitter, State};
 
 use crate::error::AppError;
-use crate::models::catalog::CatalogVehicle;
-use crate::services::catalog::scan_vehicle_catalog;
+use crate::models::catalog::{CatalogKind, CatalogVehicle};
+use crate::services::catalog::{filter_catalog, scan_vehicle_catalog, scan_vehicle_catalog_chunked};
 use crate::validators::path::validate_game_path;
 
+/// Event carrying a batch of catalog vehicles while `stream_vehicle_catalog` scans.
+pub const CATALOG_CHUNK_EVENT: &str = "catalog-chunk";
+
 pub struct CatalogState {
     cache: Mutex<Option<(String, Vec<CatalogVehicle>)>>,
 }
@@ -21,7 +24,7 @@ impl CatalogState {
 }
 
 /// Get the FS25 user profile mods directory.
-fn get_mods_dir() -> PathBuf {
+pub(crate) fn get_mods_dir() -> PathBuf {
     dirs::document_dir()
         .unwrap_or_default()
         .join("My Games")
@@ -29,9 +32,12 @@ fn get_mods_dir() -> PathBuf {
         .join("mods")
 }
 
+/// Scan the vehicle catalog, restricted to `kinds` when given (all entries otherwise).
+/// The full catalog is cached per game path; filtering happens on the way out.
 #[tauri::command]
 pub async fn get_vehicle_catalog(
     game_path: String,
+    kinds: Option<Vec<CatalogKind>>,
     state: State<'_, CatalogState>,
 ) -> Result<Vec<CatalogVehicle>, AppError> {
     // Check cache
@@ -39,7 +45,7 @@ pub async fn get_vehicle_catalog(
         let cache = state.cache.lock().unwrap();
         if let Some((cached_path, cached_catalog)) = cache.as_ref() {
             if cached_path == &game_path {
-                return Ok(cached_catalog.clone());
+                return Ok(filter_catalog(cached_catalog, kinds.as_deref()));
             }
         }
     }
@@ -54,11 +60,63 @@ pub async fn get_vehicle_catalog(
         message: e.to_string(),
     })??;
 
+    let filtered = filter_catalog(&catalog, kinds.as_deref());
+
+    // Update cache
+    {
+        let mut cache = state.cache.lock().unwrap();
+        *cache = Some((game_path, catalog));
+    }
+
+    Ok(filtered)
+}
+
+/// Like `get_vehicle_catalog`, but emits `CATALOG_CHUNK_EVENT` with the vehicles
+/// of each source as it is scanned, so large mod folders fill the list
+/// progressively. The returned catalog is sorted like `get_vehicle_catalog`'s
+/// and replaces what the chunks built up. A cached catalog is emitted as a single chunk.
+#[tauri::command]
+pub async fn stream_vehicle_catalog(
+    app: AppHandle,
+    game_path: String,
+    kinds: Option<Vec<CatalogKind>>,
+    state: State<'_, CatalogState>,
+) -> Result<Vec<CatalogVehicle>, AppError> {
+    // Check cache
+    {
+        let cache = state.cache.lock().unwrap();
+        if let Some((cached_path, cached_catalog)) = cache.as_ref() {
+            if cached_path == &game_path {
+                let catalog = filter_catalog(cached_catalog, kinds.as_deref());
+                let _ = app.emit(CATALOG_CHUNK_EVENT, &catalog);
+                return Ok(catalog);
+            }
+        }
+    }
+
+    let validated_path = validate_game_path(&game_path)?;
+    let chunk_kinds = kinds.clone();
+    let catalog = tauri::async_runtime::spawn_blocking(move || -> Result<Vec<CatalogVehicle>, AppError> {
+        let mods_dir = get_mods_dir();
+        Ok(scan_vehicle_catalog_chunked(&validated_path, &mods_dir, |chunk| {
+            let chunk = filter_catalog(chunk, chunk_kinds.as_deref());
+            if !chunk.is_empty() {
+                let _ = app.emit(CATALOG_CHUNK_EVENT, chunk);
+            }
+        }))
+    })
+    .await
+    .map_err(|e| AppError::IoError {
+        message: e.to_string(),
+    })??;
+
+    let filtered = filter_catalog(&catalog, kinds.as_deref());
+
     // Update cache
     {
         let mut cache = state.cache.lock().unwrap();
-        *cache = Some((game_path, catalog.clone()));
+        *cache = Some((game_path, catalog));
     }
 
-    Ok(catalog)
+    Ok(filtered)
 }
@@ -1,6 +1,9 @@
+pub mod analysis;
 pub mod backup;
 pub mod catalog;
 pub mod density;
 pub mod savegame;
+pub mod settings;
 pub mod update;
+pub mod validation;
 pub mod vehicle_image;
@@ -1,11 +1,36 @@
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tauri::AppHandle;
+use tauri_plugin_opener::OpenerExt;
 
 use crate::backup::manager as backup_manager;
 use crate::error::AppError;
-use crate::models::career::SavegameSummary;
-use crate::models::changes::{SavegameChanges, SaveResult};
+use crate::models::career::{
+    economic_difficulty_code, starting_money, SavegameListing, SavegameSummary,
+};
+use crate::models::changes::{
+    BatchSaveResult, EconomyChanges, EnvironmentChanges, FarmlandChange, FieldChange,
+    FieldOperation, FinanceChanges, FreshStartKeep, FreshStartResult, GreatDemandChange,
+    PlayerPermissionChanges, SaleAddition, SaleChange, SaveResult, SavegameChanges, UnmatchedChange,
+    UnsavedChanges, VehicleChange, VerifiedSaveResult,
+};
 use crate::models::common::LocalizedMessage;
-use crate::models::SavegameData;
+use crate::models::farm::{LoanPayoff, COUNT_STATISTICS, VALUE_STATISTICS};
+use crate::models::field::valid_fruit_types;
+use crate::models::item::{ClearedItems, LooseItemKind};
+use crate::models::sale::BoughtConfiguration;
+use crate::models::tree::count_trees_by_type;
+use crate::models::vehicle::{
+    build_attachment_tree, AttachmentNode, FleetWear, FleetWearScaling, PropertyState, Vehicle,
+};
+use crate::models::{FileLoadError, SafeLoadResult, SavegameData};
+use crate::services::catalog::scan_vehicle_catalog;
+use crate::services::save_verification::verify_changes;
+use crate::services::xml_diff::xml_equivalent;
 use crate::parsers::career::{parse_career, parse_career_summary};
 use crate::parsers::farm::parse_farms;
 use crate::parsers::economy::parse_economy;
@@ -13,14 +38,28 @@ use crate::parsers::environment::parse_environment;
 use crate::parsers::field::{parse_farmlands, parse_fields};
 use crate::parsers::sale::parse_sales;
 use crate::parsers::collectible::parse_collectibles;
+use crate::parsers::common::{
+    files_without_xml_declaration, has_xml_declaration, read_xml_file,
+    read_xml_file_with_encoding, XmlEncoding,
+};
 use crate::parsers::contract::parse_contract_settings;
+use crate::parsers::files::{
+    savegame_file, CAREER_SAVEGAME, COLLECTIBLES, CONTRACTS, ECONOMY, ENVIRONMENT, FARMLAND, FARMS,
+    FIELDS, MISSIONS, PLACEABLES, SALES, TREE_PLANT, VEHICLES,
+};
 use crate::parsers::mission::parse_missions;
 use crate::parsers::placeable::parse_placeables;
-use crate::parsers::vehicle::parse_vehicles;
-use crate::validators::path::{validate_savegame_path, validate_savegames_base_path};
-use crate::validators::savegame::validate_savegame;
+use crate::parsers::tree::parse_trees;
+use crate::parsers::vehicle::{parse_vehicles, parse_vehicles_partial};
+use crate::validators::path::{
+    validate_game_path, validate_savegame_path, validate_savegames_base_path,
+};
+use crate::validators::savegame::{has_incoherent_growth_state, validate_savegame};
+use crate::validators::version::{ensure_supported_version, legacy_name_hint};
 use crate::writers;
 
+use super::catalog::get_mods_dir;
+
 /// Returns the default FarmingSimulator2025 save folder path based on the OS.
 fn default_savegame_path() -> Result<PathBuf, AppError> {
     #[cfg(target_os = "windows")]
@@ -61,11 +100,16 @@ pub fn list_savegames(custom_path: Option<String>) -> Result<Vec<SavegameSummary
         None => default_savegame_path()?,
     };
 
+    scan_savegames(&base_path)
+}
+
+/// Summaries of the `savegame*` folders of `base_path`, most recently modified first.
+fn scan_savegames(base_path: &Path) -> Result<Vec<SavegameSummary>, AppError> {
     if !base_path.exists() {
         return Ok(Vec::new());
     }
 
-    let mut entries: Vec<_> = std::fs::read_dir(&base_path)?
+    let mut entries: Vec<_> = std::fs::read_dir(base_path)?
         .filter_map(|entry| entry.ok())
         .filter(|entry| {
             let name = entry.file_name();
@@ -92,7 +136,7 @@ pub fn list_savegames(custom_path: Option<String>) -> Result<Vec<SavegameSummary
         .iter()
         .filter_map(|entry| {
             let path = entry.path();
-            if path.join("careerSavegame.xml").exists() {
+            if savegame_file(&path, CAREER_SAVEGAME).exists() {
                 parse_career_summary(&path).ok()
             } else {
                 None
@@ -103,20 +147,85 @@ pub fn list_savegames(custom_path: Option<String>) -> Result<Vec<SavegameSummary
     Ok(summaries)
 }
 
+/// List the savegames of several save folders, e.g. the default one and a mod
+/// manager profile. Each savegame is tagged with the folder it was found in, and a
+/// savegame reachable from two folders (symlink, overlapping paths) is listed once.
 #[tauri::command]
-pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
-    let save_path = validate_savegame_path(&path).map_err(|_| AppError::SavegameNotFound {
-        path: path.clone(),
-    })?;
+pub fn list_savegames_multi(paths: Vec<String>) -> Result<Vec<SavegameListing>, AppError> {
+    let mut seen: HashSet<PathBuf> = HashSet::new();
+    let mut listings = Vec::new();
+
+    for base in paths {
+        let base_path = validate_savegames_base_path(&base)?;
+        for summary in scan_savegames(&base_path)? {
+            let canonical = std::fs::canonicalize(&summary.path)
+                .unwrap_or_else(|_| PathBuf::from(&summary.path));
+            if seen.insert(canonical) {
+                listings.push(SavegameListing {
+                    base_path: base.clone(),
+                    summary,
+                });
+            }
+        }
+    }
 
-    if !save_path.exists() {
-        return Err(AppError::SavegameNotFound { path });
+    Ok(listings)
+}
+
+/// Validate `path` as a savegame folder that exists on disk, reporting any failure as
+/// `SavegameNotFound` for the frontend.
+pub(crate) fn resolve_save_path(path: &str) -> Result<PathBuf, AppError> {
+    match validate_savegame_path(path) {
+        Ok(save_path) if save_path.exists() => Ok(save_path),
+        _ => Err(AppError::SavegameNotFound {
+            path: path.to_string(),
+        }),
     }
+}
+
+/// Resolve `path` like `resolve_save_path` for a command writing to the savegame,
+/// refusing a savegame of another game version before anything is written.
+fn resolve_writable_save_path(path: &str) -> Result<PathBuf, AppError> {
+    let save_path = resolve_save_path(path)?;
+    ensure_supported_version(&save_path)?;
+    Ok(save_path)
+}
+
+#[tauri::command]
+pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
+    let mut data = read_savegame(path)?;
+
+    // Run cross-file validators and append any warnings
+    let validation_warnings = validate_savegame(&data);
+    data.warnings.extend(validation_warnings);
+
+    Ok(data)
+}
+
+/// Parse every file of the savegame like `load_savegame`, without running the
+/// cross-file validators. `data.warnings` holds the loading warnings only.
+pub(crate) fn read_savegame(path: String) -> Result<SavegameData, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    ensure_supported_version(&save_path)?;
 
     let mut warnings: Vec<LocalizedMessage> = Vec::new();
+    if let Some(game) = legacy_name_hint(&save_path)? {
+        warnings.push(LocalizedMessage::new("errors.legacyModNames").with_param("game", game));
+    }
 
     // Parse career (required)
     let career = parse_career(&save_path)?;
+    let career_file = savegame_file(&save_path, CAREER_SAVEGAME);
+    if matches!(
+        read_xml_file_with_encoding(&career_file),
+        Ok((_, XmlEncoding::RepairedUtf8 | XmlEncoding::RepairedUtf8Bom))
+    ) {
+        // Usually a savegame name edited by hand; saving stores it as valid UTF-8
+        warnings.push(
+            LocalizedMessage::new("errors.invalidUtf8Repaired").with_param("file", CAREER_SAVEGAME),
+        );
+    }
 
     // Parse farms (required)
     let farms = parse_farms(&save_path)?;
@@ -124,6 +233,24 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
     // Parse vehicles (required)
     let vehicles = match parse_vehicles(&save_path) {
         Ok(v) => v,
+        // Show what precedes the cut; writers still refuse the file
+        Err(AppError::TruncatedFile { file }) => match parse_vehicles_partial(&save_path) {
+            Ok((v, _)) => {
+                warnings.push(
+                    LocalizedMessage::new("errors.truncatedFileRecovered")
+                        .with_param("file", VEHICLES)
+                        .with_param("count", v.len()),
+                );
+                v
+            }
+            Err(_) => {
+                warnings.push(
+                    LocalizedMessage::new("errors.vehicleParseError")
+                        .with_param("details", AppError::TruncatedFile { file }),
+                );
+                Vec::new()
+            }
+        },
         Err(e) => {
             warnings.push(
                 LocalizedMessage::new("errors.vehicleParseError")
@@ -139,7 +266,7 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "sales.xml"),
+                    .with_param("file", SALES),
             );
             Vec::new()
         }
@@ -151,7 +278,7 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "fields.xml"),
+                    .with_param("file", FIELDS),
             );
             Vec::new()
         }
@@ -163,7 +290,7 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "farmland.xml"),
+                    .with_param("file", FARMLAND),
             );
             Vec::new()
         }
@@ -175,7 +302,7 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "placeables.xml"),
+                    .with_param("file", PLACEABLES),
             );
             Vec::new()
         }
@@ -187,7 +314,7 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "missions.xml"),
+                    .with_param("file", MISSIONS),
             );
             Vec::new()
         }
@@ -199,11 +326,24 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "collectibles.xml"),
+                    .with_param("file", COLLECTIBLES),
+            );
+            Vec::new()
+        }
+    };
+
+    // Parse planted trees (optional)
+    let trees = match parse_trees(&save_path) {
+        Ok(t) => t,
+        Err(_) => {
+            warnings.push(
+                LocalizedMessage::new("errors.fileUnreadable")
+                    .with_param("file", TREE_PLANT),
             );
             Vec::new()
         }
     };
+    let tree_counts = count_trees_by_type(&trees);
 
     // Parse contract settings (optional)
     let contract_settings = match parse_contract_settings(&save_path) {
@@ -211,7 +351,7 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "r_contracts.xml"),
+                    .with_param("file", CONTRACTS),
             );
             None
         }
@@ -223,7 +363,7 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "environment.xml"),
+                    .with_param("file", ENVIRONMENT),
             );
             None
         }
@@ -235,13 +375,13 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         Err(_) => {
             warnings.push(
                 LocalizedMessage::new("errors.fileUnreadable")
-                    .with_param("file", "economy.xml"),
+                    .with_param("file", ECONOMY),
             );
             None
         }
     };
 
-    let mut data = SavegameData {
+    Ok(SavegameData {
         path,
         career,
         farms,
@@ -252,34 +392,112 @@ pub fn load_savegame(path: String) -> Result<SavegameData, AppError> {
         placeables,
         missions,
         collectibles,
+        trees,
+        tree_counts,
         contract_settings,
         environment,
         economy,
+        files_without_declaration: files_without_xml_declaration(&save_path),
         warnings,
+    })
+}
+
+/// Load as much of a damaged savegame as possible, for inspection and salvage:
+/// every file is parsed on its own, and a failure, even of careerSavegame.xml or
+/// farms.xml, is reported in the result instead of failing the whole load. Saves
+/// of another game version are still refused.
+#[tauri::command]
+pub fn load_savegame_safe(path: String) -> Result<SafeLoadResult, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    // An unreadable careerSavegame.xml is reported below with the other files
+    if let Err(e @ AppError::UnsupportedSaveVersion { .. }) = ensure_supported_version(&save_path) {
+        return Err(e);
+    }
+
+    let mut errors = Vec::new();
+    let career = attempt(&mut errors, CAREER_SAVEGAME, parse_career(&save_path));
+    let career_loaded = career.is_some();
+    let vehicles = match parse_vehicles(&save_path) {
+        Ok(v) => v,
+        Err(e @ AppError::TruncatedFile { .. }) => {
+            errors.push(FileLoadError {
+                file: VEHICLES.to_string(),
+                message: e.to_string(),
+            });
+            parse_vehicles_partial(&save_path)
+                .map(|(v, _)| v)
+                .unwrap_or_default()
+        }
+        Err(e) => attempt(&mut errors, VEHICLES, Err(e)).unwrap_or_default(),
     };
+    let farms = attempt(&mut errors, FARMS, parse_farms(&save_path));
+    let sales = attempt(&mut errors, SALES, parse_sales(&save_path));
+    let fields = attempt(&mut errors, FIELDS, parse_fields(&save_path));
+    let farmlands = attempt(&mut errors, FARMLAND, parse_farmlands(&save_path));
+    let placeables = attempt(&mut errors, PLACEABLES, parse_placeables(&save_path));
+    let missions = attempt(&mut errors, MISSIONS, parse_missions(&save_path));
+    let collectibles = attempt(&mut errors, COLLECTIBLES, parse_collectibles(&save_path));
+    let trees = attempt(&mut errors, TREE_PLANT, parse_trees(&save_path)).unwrap_or_default();
+    let contract_settings = attempt(
+        &mut errors,
+        CONTRACTS,
+        parse_contract_settings(&save_path),
+    );
+    let environment = attempt(&mut errors, ENVIRONMENT, parse_environment(&save_path));
+    let economy = attempt(&mut errors, ECONOMY, parse_economy(&save_path));
 
-    // Run cross-file validators and append any warnings
-    let validation_warnings = validate_savegame(&data);
-    data.warnings.extend(validation_warnings);
+    let mut data = SavegameData {
+        path,
+        career: career.unwrap_or_default(),
+        farms: farms.unwrap_or_default(),
+        vehicles,
+        sales: sales.unwrap_or_default(),
+        fields: fields.unwrap_or_default(),
+        farmlands: farmlands.unwrap_or_default(),
+        placeables: placeables.unwrap_or_default(),
+        missions: missions.unwrap_or_default(),
+        collectibles: collectibles.unwrap_or_default(),
+        tree_counts: count_trees_by_type(&trees),
+        trees,
+        contract_settings,
+        environment,
+        economy,
+        files_without_declaration: files_without_xml_declaration(&save_path),
+        warnings: Vec::new(),
+    };
 
-    Ok(data)
+    // The cross-file checks compare against the career, meaningless when blank
+    if career_loaded {
+        data.warnings = validate_savegame(&data);
+    }
+
+    Ok(SafeLoadResult { data, errors })
+}
+
+/// The parsed value of `file`, or `None` with the failure recorded in `errors`.
+fn attempt<T>(
+    errors: &mut Vec<FileLoadError>,
+    file: &str,
+    result: Result<T, AppError>,
+) -> Option<T> {
+    result
+        .map_err(|e| {
+            errors.push(FileLoadError {
+                file: file.to_string(),
+                message: e.to_string(),
+            })
+        })
+        .ok()
 }
 
 #[tauri::command]
 pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult, AppError> {
-    let save_path = validate_savegame_path(&path).map_err(|_| AppError::SavegameNotFound {
-        path: path.clone(),
-    })?;
-
-    if !save_path.exists() {
-        return Err(AppError::SavegameNotFound { path });
-    }
-
-    let mut files_modified: Vec<String> = Vec::new();
-    let mut errors: Vec<LocalizedMessage> = Vec::new();
+    let save_path = resolve_writable_save_path(&path)?;
 
     // Check if there are any changes to apply
     let has_changes = changes.finance.is_some()
+        || changes.farms.is_some()
         || changes.vehicles.is_some()
         || changes.sales.is_some()
         || changes.sale_additions.is_some()
@@ -290,73 +508,167 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
         || changes.collectibles.is_some()
         || changes.contract_settings.is_some()
         || changes.environment.is_some()
-        || changes.economy.is_some();
+        || changes.economy.is_some()
+        || changes.trees.is_some();
 
     if !has_changes {
         return Ok(SaveResult {
             success: true,
             backup_path: None,
-            files_modified,
-            errors,
+            files_modified: Vec::new(),
+            errors: Vec::new(),
+            unmatched_changes: Vec::new(),
         });
     }
 
     // Create backup before any write (mandatory)
     let backup_info = backup_manager::create_backup(&save_path)?;
 
+    let (files_modified, errors, unmatched_changes) = apply_changes(&save_path, &changes);
+
+    Ok(SaveResult {
+        success: errors.is_empty(),
+        backup_path: Some(backup_info.path),
+        files_modified,
+        errors,
+        unmatched_changes,
+    })
+}
+
+/// Save `changes` like `save_changes`, then read the written files back and list
+/// the requested values they do not hold, e.g. an edit whose target matched nothing.
+#[tauri::command]
+pub fn save_and_verify(
+    path: String,
+    changes: SavegameChanges,
+) -> Result<VerifiedSaveResult, AppError> {
+    let mut result = save_changes(path.clone(), changes.clone())?;
+    let save_path = PathBuf::from(&path);
+
+    let verification = verify_changes(&save_path, &changes, &result.files_modified);
+    result.errors.extend(verification.errors);
+    result.success = result.errors.is_empty() && verification.unapplied.is_empty();
+
+    Ok(VerifiedSaveResult {
+        result,
+        unapplied: verification.unapplied,
+        unverified: verification.unverified,
+    })
+}
+
+/// Run every writer concerned by `changes` against the savegame at `save_path`, without
+/// creating a backup. Returns the files written, the errors of the writers that failed
+/// and the changes whose target the writers did not find.
+fn apply_changes(
+    save_path: &Path,
+    changes: &SavegameChanges,
+) -> (Vec<String>, Vec<LocalizedMessage>, Vec<UnmatchedChange>) {
+    let changes = &changes.selected_only();
+    let mut files_modified: Vec<String> = Vec::new();
+    let mut errors: Vec<LocalizedMessage> = Vec::new();
+    let mut unmatched: Vec<UnmatchedChange> = Vec::new();
+
     // Apply finance changes
     if let Some(ref finance) = changes.finance {
-        if let Some(money) = finance.money {
+        if let Some(money) = finance.money.filter(|_| changes.is_selected(CAREER_SAVEGAME)) {
             // Write money to careerSavegame.xml
-            match writers::career::write_career_money(&save_path, money) {
-                Ok(()) => files_modified.push("careerSavegame.xml".to_string()),
+            match writers::career::write_career_money(save_path, money) {
+                Ok(()) => files_modified.push(CAREER_SAVEGAME.to_string()),
                 Err(e) => errors.push(
                     LocalizedMessage::new("errors.fileWriteError")
-                        .with_param("file", "careerSavegame.xml")
+                        .with_param("file", CAREER_SAVEGAME)
                         .with_param("details", e),
                 ),
             }
+        }
+    }
+    if let Some(finance) = changes.finance.as_ref().filter(|_| changes.is_selected(FARMS)) {
+        if let Some(money) = finance.money {
             // Sync money to farms.xml (farm 1)
-            match writers::farm::write_farm_finances(&save_path, 1, Some(money), None) {
+            match writers::farm::write_farm_finances(save_path, 1, Some(money), None, None) {
                 Ok(()) => {
-                    if !files_modified.contains(&"farms.xml".to_string()) {
-                        files_modified.push("farms.xml".to_string());
+                    if !files_modified.contains(&FARMS.to_string()) {
+                        files_modified.push(FARMS.to_string());
                     }
                 }
                 Err(e) => errors.push(
                     LocalizedMessage::new("errors.fileWriteError")
-                        .with_param("file", "farms.xml")
+                        .with_param("file", FARMS)
                         .with_param("details", e),
                 ),
             }
         }
         if let Some(loan) = finance.loan {
-            match writers::farm::write_farm_finances(&save_path, 1, None, Some(loan)) {
+            match writers::farm::write_farm_finances(save_path, 1, None, Some(loan), None) {
                 Ok(()) => {
-                    if !files_modified.contains(&"farms.xml".to_string()) {
-                        files_modified.push("farms.xml".to_string());
+                    if !files_modified.contains(&FARMS.to_string()) {
+                        files_modified.push(FARMS.to_string());
                     }
                 }
                 Err(e) => errors.push(
                     LocalizedMessage::new("errors.fileWriteError")
-                        .with_param("file", "farms.xml")
+                        .with_param("file", FARMS)
                         .with_param("details", e),
                 ),
             }
         }
+        if let Some(rate) = finance.loan_interest_rate {
+            // Not every save stores the interest rate; skip silently when absent
+            let has_rate = parse_farms(save_path)
+                .map(|farms| {
+                    farms
+                        .iter()
+                        .any(|f| f.farm_id == 1 && f.loan_interest_rate.is_some())
+                })
+                .unwrap_or(false);
+            if has_rate {
+                match writers::farm::write_farm_finances(save_path, 1, None, None, Some(rate)) {
+                    Ok(()) => {
+                        if !files_modified.contains(&FARMS.to_string()) {
+                            files_modified.push(FARMS.to_string());
+                        }
+                    }
+                    Err(e) => errors.push(
+                        LocalizedMessage::new("errors.fileWriteError")
+                            .with_param("file", FARMS)
+                            .with_param("details", e),
+                    ),
+                }
+            }
+        }
+    }
+
+    // Apply farm changes
+    if let Some(ref farm_changes) = changes.farms {
+        match writers::farm::write_farm_changes(save_path, farm_changes) {
+            Ok(matched) => {
+                let requested = farm_changes.iter().map(|c| c.farm_id);
+                unmatched.extend(unmatched_keys(FARMS, requested, &matched));
+                if !files_modified.contains(&FARMS.to_string()) {
+                    files_modified.push(FARMS.to_string());
+                }
+            }
+            Err(e) => errors.push(
+                LocalizedMessage::new("errors.fileWriteError")
+                    .with_param("file", FARMS)
+                    .with_param("details", e),
+            ),
+        }
     }
 
     // Apply vehicle changes
     if let Some(ref vehicle_changes) = changes.vehicles {
-        match writers::vehicle::write_vehicle_changes(&save_path, vehicle_changes) {
-            Ok(()) => {
-                if !files_modified.contains(&"vehicles.xml".to_string()) {
-                    files_modified.push("vehicles.xml".to_string());
+        match writers::vehicle::write_vehicle_changes(save_path, vehicle_changes) {
+            Ok(matched) => {
+                let requested = vehicle_changes.iter().map(|c| c.unique_id.clone());
+                unmatched.extend(unmatched_keys(VEHICLES, requested, &matched));
+                if !files_modified.contains(&VEHICLES.to_string()) {
+                    files_modified.push(VEHICLES.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "vehicles.xml")
+                    .with_param("file", VEHICLES)
                     .with_param("details", e),
             ),
         }
@@ -364,15 +676,17 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply sale changes
     if let Some(ref sale_changes) = changes.sales {
-        match writers::sale::write_sale_changes(&save_path, sale_changes) {
-            Ok(()) => {
-                if !files_modified.contains(&"sales.xml".to_string()) {
-                    files_modified.push("sales.xml".to_string());
+        match writers::sale::write_sale_changes(save_path, sale_changes) {
+            Ok(matched) => {
+                let requested = sale_changes.iter().map(|c| c.index);
+                unmatched.extend(unmatched_keys(SALES, requested, &matched));
+                if !files_modified.contains(&SALES.to_string()) {
+                    files_modified.push(SALES.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "sales.xml")
+                    .with_param("file", SALES)
                     .with_param("details", e),
             ),
         }
@@ -380,15 +694,15 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply sale additions (new items)
     if let Some(ref sale_additions) = changes.sale_additions {
-        match writers::sale::write_sale_additions(&save_path, sale_additions) {
+        match writers::sale::write_sale_additions(save_path, sale_additions) {
             Ok(()) => {
-                if !files_modified.contains(&"sales.xml".to_string()) {
-                    files_modified.push("sales.xml".to_string());
+                if !files_modified.contains(&SALES.to_string()) {
+                    files_modified.push(SALES.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "sales.xml")
+                    .with_param("file", SALES)
                     .with_param("details", e),
             ),
         }
@@ -396,15 +710,17 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply field changes
     if let Some(ref field_changes) = changes.fields {
-        match writers::field::write_field_changes(&save_path, field_changes) {
-            Ok(()) => {
-                if !files_modified.contains(&"fields.xml".to_string()) {
-                    files_modified.push("fields.xml".to_string());
+        match writers::field::write_field_changes(save_path, field_changes) {
+            Ok(matched) => {
+                let requested = field_changes.iter().map(|c| c.id);
+                unmatched.extend(unmatched_keys(FIELDS, requested, &matched));
+                if !files_modified.contains(&FIELDS.to_string()) {
+                    files_modified.push(FIELDS.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "fields.xml")
+                    .with_param("file", FIELDS)
                     .with_param("details", e),
             ),
         }
@@ -412,15 +728,17 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply farmland changes
     if let Some(ref farmland_changes) = changes.farmlands {
-        match writers::field::write_farmland_changes(&save_path, farmland_changes) {
-            Ok(()) => {
-                if !files_modified.contains(&"farmland.xml".to_string()) {
-                    files_modified.push("farmland.xml".to_string());
+        match writers::field::write_farmland_changes(save_path, farmland_changes) {
+            Ok(matched) => {
+                let requested = farmland_changes.iter().map(|c| c.id);
+                unmatched.extend(unmatched_keys(FARMLAND, requested, &matched));
+                if !files_modified.contains(&FARMLAND.to_string()) {
+                    files_modified.push(FARMLAND.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "farmland.xml")
+                    .with_param("file", FARMLAND)
                     .with_param("details", e),
             ),
         }
@@ -428,15 +746,17 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply placeable changes
     if let Some(ref placeable_changes) = changes.placeables {
-        match writers::placeable::write_placeable_changes(&save_path, placeable_changes) {
-            Ok(()) => {
-                if !files_modified.contains(&"placeables.xml".to_string()) {
-                    files_modified.push("placeables.xml".to_string());
+        match writers::placeable::write_placeable_changes(save_path, placeable_changes) {
+            Ok(matched) => {
+                let requested = placeable_changes.iter().map(|c| c.index);
+                unmatched.extend(unmatched_keys(PLACEABLES, requested, &matched));
+                if !files_modified.contains(&PLACEABLES.to_string()) {
+                    files_modified.push(PLACEABLES.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "placeables.xml")
+                    .with_param("file", PLACEABLES)
                     .with_param("details", e),
             ),
         }
@@ -444,15 +764,17 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply mission changes
     if let Some(ref mission_changes) = changes.missions {
-        match writers::mission::write_mission_changes(&save_path, mission_changes) {
-            Ok(()) => {
-                if !files_modified.contains(&"missions.xml".to_string()) {
-                    files_modified.push("missions.xml".to_string());
+        match writers::mission::write_mission_changes(save_path, mission_changes) {
+            Ok(matched) => {
+                let requested = mission_changes.iter().map(|c| c.unique_id.clone());
+                unmatched.extend(unmatched_keys(MISSIONS, requested, &matched));
+                if !files_modified.contains(&MISSIONS.to_string()) {
+                    files_modified.push(MISSIONS.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "missions.xml")
+                    .with_param("file", MISSIONS)
                     .with_param("details", e),
             ),
         }
@@ -460,15 +782,17 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply collectible changes
     if let Some(ref collectible_changes) = changes.collectibles {
-        match writers::collectible::write_collectible_changes(&save_path, collectible_changes) {
-            Ok(()) => {
-                if !files_modified.contains(&"collectibles.xml".to_string()) {
-                    files_modified.push("collectibles.xml".to_string());
+        match writers::collectible::write_collectible_changes(save_path, collectible_changes) {
+            Ok(matched) => {
+                let requested = collectible_changes.iter().map(|c| c.index);
+                unmatched.extend(unmatched_keys(COLLECTIBLES, requested, &matched));
+                if !files_modified.contains(&COLLECTIBLES.to_string()) {
+                    files_modified.push(COLLECTIBLES.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "collectibles.xml")
+                    .with_param("file", COLLECTIBLES)
                     .with_param("details", e),
             ),
         }
@@ -476,15 +800,15 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply contract settings changes
     if let Some(ref contract_changes) = changes.contract_settings {
-        match writers::contract::write_contract_settings(&save_path, contract_changes) {
+        match writers::contract::write_contract_settings(save_path, contract_changes) {
             Ok(()) => {
-                if !files_modified.contains(&"r_contracts.xml".to_string()) {
-                    files_modified.push("r_contracts.xml".to_string());
+                if !files_modified.contains(&CONTRACTS.to_string()) {
+                    files_modified.push(CONTRACTS.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "r_contracts.xml")
+                    .with_param("file", CONTRACTS)
                     .with_param("details", e),
             ),
         }
@@ -492,15 +816,15 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply environment changes
     if let Some(ref env_changes) = changes.environment {
-        match writers::environment::write_environment_changes(&save_path, env_changes) {
+        match writers::environment::write_environment_changes(save_path, env_changes) {
             Ok(()) => {
-                if !files_modified.contains(&"environment.xml".to_string()) {
-                    files_modified.push("environment.xml".to_string());
+                if !files_modified.contains(&ENVIRONMENT.to_string()) {
+                    files_modified.push(ENVIRONMENT.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "environment.xml")
+                    .with_param("file", ENVIRONMENT)
                     .with_param("details", e),
             ),
         }
@@ -508,151 +832,1337 @@ pub fn save_changes(path: String, changes: SavegameChanges) -> Result<SaveResult
 
     // Apply economy changes
     if let Some(ref economy_changes) = changes.economy {
-        match writers::economy::write_economy_changes(&save_path, economy_changes) {
+        match writers::economy::write_economy_changes(save_path, economy_changes) {
             Ok(()) => {
-                if !files_modified.contains(&"economy.xml".to_string()) {
-                    files_modified.push("economy.xml".to_string());
+                if !files_modified.contains(&ECONOMY.to_string()) {
+                    files_modified.push(ECONOMY.to_string());
                 }
             }
             Err(e) => errors.push(
                 LocalizedMessage::new("errors.fileWriteError")
-                    .with_param("file", "economy.xml")
+                    .with_param("file", ECONOMY)
                     .with_param("details", e),
             ),
         }
     }
 
-    Ok(SaveResult {
-        success: errors.is_empty(),
-        backup_path: Some(backup_info.path),
-        files_modified,
-        errors,
-    })
-}
+    // Apply tree changes
+    if let Some(ref tree_changes) = changes.trees {
+        match writers::tree::write_tree_changes(save_path, tree_changes) {
+            Ok(()) => {
+                if !files_modified.contains(&TREE_PLANT.to_string()) {
+                    files_modified.push(TREE_PLANT.to_string());
+                }
+            }
+            Err(e) => errors.push(
+                LocalizedMessage::new("errors.fileWriteError")
+                    .with_param("file", TREE_PLANT)
+                    .with_param("details", e),
+            ),
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    (files_modified, errors, unmatched)
+}
 
-    fn fixtures_path() -> String {
-        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("tests")
-            .join("fixtures")
-            .join("test_saves")
-            .display()
-            .to_string()
+/// The keys of `requested` that a writer of `file` did not find, in request order.
+fn unmatched_keys<K: Eq + Hash + Display>(
+    file: &str,
+    requested: impl Iterator<Item = K>,
+    matched: &HashSet<K>,
+) -> Vec<UnmatchedChange> {
+    let mut unmatched: Vec<UnmatchedChange> = Vec::new();
+    for key in requested.filter(|key| !matched.contains(key)) {
+        let change = UnmatchedChange {
+            file: file.to_string(),
+            key: key.to_string(),
+        };
+        if !unmatched.contains(&change) {
+            unmatched.push(change);
+        }
     }
+    unmatched
+}
 
-    fn complete_fixture_path() -> String {
-        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("tests")
-            .join("fixtures")
-            .join("savegame_complete")
-            .display()
-            .to_string()
-    }
+/// Distinguishes the scratch folders of concurrent `has_unsaved_changes` calls.
+static DRY_RUN_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
-    #[test]
-    fn test_list_savegames_with_fixtures() {
-        let result = list_savegames(Some(fixtures_path())).unwrap();
-        assert_eq!(result.len(), 2);
-        let names: Vec<&str> = result.iter().map(|s| s.name.as_str()).collect();
-        assert!(names.contains(&"Ma partie"));
-        assert!(names.contains(&"Partie 2"));
+/// Apply `changes` to a scratch copy of the savegame's XML files and report which files
+/// would actually differ from disk. A change equal to the current value is not counted;
+/// one whose writer fails is, since it could not be checked.
+#[tauri::command]
+pub fn has_unsaved_changes(
+    path: String,
+    changes: SavegameChanges,
+) -> Result<UnsavedChanges, AppError> {
+    let save_path = resolve_save_path(&path)?;
+
+    let scratch = std::env::temp_dir().join(format!(
+        "fs25_dry_run_{}_{}",
+        std::process::id(),
+        DRY_RUN_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    let _ = std::fs::remove_dir_all(&scratch);
+    std::fs::create_dir_all(&scratch)?;
+
+    let copied = (|| -> Result<(), AppError> {
+        for entry in std::fs::read_dir(&save_path)? {
+            let entry = entry?;
+            let entry_path = entry.path();
+            if entry.file_type()?.is_file()
+                && entry_path
+                    .extension()
+                    .is_some_and(|e| e.eq_ignore_ascii_case("xml"))
+            {
+                std::fs::copy(&entry_path, scratch.join(entry.file_name()))?;
+            }
+        }
+        Ok(())
+    })();
+    if let Err(e) = copied {
+        let _ = std::fs::remove_dir_all(&scratch);
+        return Err(e);
     }
 
-    #[test]
-    fn test_list_savegames_empty_dir() {
-        let dir = std::env::temp_dir().join("fs25_test_empty_saves");
-        let _ = std::fs::create_dir_all(&dir);
+    let (files_written, errors, _) = apply_changes(&scratch, &changes);
+    let files: Vec<String> = files_written
+        .into_iter()
+        .filter(|file| {
+            match (
+                read_xml_file(&savegame_file(&save_path, file)),
+                read_xml_file(&savegame_file(&scratch, file)),
+            ) {
+                (Ok(before), Ok(after)) => !xml_equivalent(&before, &after),
+                _ => true,
+            }
+        })
+        .collect();
 
-        let result = list_savegames(Some(dir.display().to_string())).unwrap();
-        assert!(result.is_empty());
+    let _ = std::fs::remove_dir_all(&scratch);
 
-        let _ = std::fs::remove_dir_all(&dir);
+    Ok(UnsavedChanges {
+        has_changes: !files.is_empty() || !errors.is_empty(),
+        files,
+        errors,
+    })
+}
+
+/// Maximum number of savegames written concurrently by `apply_changes_to_saves`.
+const MAX_PARALLEL_SAVES: usize = 4;
+
+/// Apply the same changes to several savegames, each through `save_changes` (and so with
+/// its own backup). A failing savegame is reported in its result without stopping the batch.
+#[tauri::command]
+pub fn apply_changes_to_saves(
+    paths: Vec<String>,
+    changes: SavegameChanges,
+) -> Vec<BatchSaveResult> {
+    // The same folder twice would mean two concurrent writers on the same files
+    let mut unique_paths: Vec<String> = Vec::new();
+    for path in paths {
+        if !unique_paths.contains(&path) {
+            unique_paths.push(path);
+        }
     }
 
-    #[test]
-    fn test_list_savegames_nonexistent_path() {
-        let result = list_savegames(Some("/nonexistent/path/nowhere".to_string())).unwrap();
-        assert!(result.is_empty());
+    let mut results = Vec::with_capacity(unique_paths.len());
+    for chunk in unique_paths.chunks(MAX_PARALLEL_SAVES) {
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = chunk
+                .iter()
+                .map(|path| {
+                    let changes = changes.clone();
+                    scope.spawn(move || save_changes(path.clone(), changes))
+                })
+                .collect();
+
+            for (path, handle) in chunk.iter().zip(handles) {
+                let result = match handle.join() {
+                    Ok(Ok(result)) => result,
+                    Ok(Err(e)) => failed_save_result(path, e.to_string()),
+                    Err(_) => failed_save_result(path, "save thread panicked".to_string()),
+                };
+                results.push(BatchSaveResult {
+                    path: path.clone(),
+                    result,
+                });
+            }
+        });
     }
 
-    #[test]
-    fn test_load_savegame_complete() {
-        let data = load_savegame(complete_fixture_path()).unwrap();
-        assert_eq!(data.career.savegame_name, "Test Complete");
-        assert_eq!(data.career.map_title, "Riverbend Springs");
-        assert!((data.career.money - 1_000_000.0).abs() < 0.01);
-        assert_eq!(data.career.growth_mode, 1);
-        assert_eq!(data.career.planned_days_per_period, 3);
-        assert!(data.career.plowing_required);
-        assert!(data.career.weeds_enabled);
-        assert_eq!(data.career.fuel_usage, 2);
+    results
+}
 
-        assert_eq!(data.farms.len(), 1);
-        assert_eq!(data.vehicles.len(), 3);
-        assert_eq!(data.sales.len(), 2);
-        assert_eq!(data.fields.len(), 4);
-        assert_eq!(data.farmlands.len(), 5);
-        assert_eq!(data.placeables.len(), 4);
-        assert_eq!(data.missions.len(), 3);
-        assert_eq!(data.collectibles.len(), 25);
-        assert!(data.contract_settings.is_some());
-        assert!(data.environment.is_some());
-        let env = data.environment.unwrap();
-        assert_eq!(env.current_day, 54);
-        assert_eq!(env.weather_forecast.len(), 4);
-        assert!(data.economy.is_some());
-        let eco = data.economy.unwrap();
-        assert_eq!(eco.great_demands.len(), 2);
-        assert_eq!(eco.fill_types.len(), 2);
-        assert!(data.warnings.is_empty());
+fn failed_save_result(path: &str, details: String) -> SaveResult {
+    SaveResult {
+        success: false,
+        backup_path: None,
+        files_modified: Vec::new(),
+        errors: vec![LocalizedMessage::new("errors.batchSaveFailed")
+            .with_param("path", path)
+            .with_param("details", details)],
+        unmatched_changes: Vec::new(),
     }
+}
 
-    #[test]
-    fn test_load_savegame_missing_sales() {
-        // Use savegame1 fixture which has career but no sales
-        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("tests")
-            .join("fixtures")
-            .join("test_saves")
-            .join("savegame1");
+/// Rename a savegame (the name shown in the game's load menu) and return its refreshed summary.
+#[tauri::command]
+pub fn rename_savegame(path: String, name: String) -> Result<SavegameSummary, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
 
-        // Add a minimal farms.xml and vehicles.xml for this test
-        let farms_content = r#"<?xml version="1.0" encoding="utf-8"?>
-<farms>
-  <farm farmId="1" name="Test" color="1" loan="0" money="100">
-    <players></players>
-    <finances></finances>
-  </farm>
-</farms>"#;
-        let vehicles_content = r#"<?xml version="1.0" encoding="utf-8"?>
-<vehicles></vehicles>"#;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Generic("Savegame name cannot be empty".to_string()));
+    }
 
-        std::fs::write(path.join("farms.xml"), farms_content).unwrap();
-        std::fs::write(path.join("vehicles.xml"), vehicles_content).unwrap();
+    backup_manager::create_backup(&save_path)?;
+    writers::career::write_career_name(&save_path, name)?;
 
-        let data = load_savegame(path.display().to_string()).unwrap();
-        assert_eq!(data.career.savegame_name, "Ma partie");
-        assert!(data.sales.is_empty());
-        assert!(data.warnings.iter().any(|w| w.code == "errors.fileUnreadable" && w.params.get("file").map(|f| f.as_str()) == Some("sales.xml")));
+    parse_career_summary(&save_path)
+}
 
-        // Cleanup
-        let _ = std::fs::remove_file(path.join("farms.xml"));
-        let _ = std::fs::remove_file(path.join("vehicles.xml"));
-    }
+/// Mark the guided tour completed so the game stops prompting for it, or reset it
+/// so it plays again on next load.
+#[tauri::command]
+pub fn set_guided_tour_completed(path: String, completed: bool) -> Result<(), AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
 
-    #[test]
-    fn test_load_savegame_invalid_path() {
-        let result = load_savegame("/nonexistent/path".to_string());
-        assert!(matches!(result, Err(AppError::SavegameNotFound { .. })));
+    backup_manager::create_backup(&save_path)?;
+    writers::career::write_guided_tour(&save_path, completed)
+}
+
+/// Set the clock to `hour`:`minute` of the current day, keeping the day itself.
+#[tauri::command]
+pub fn set_time_of_day(path: String, hour: u32, minute: u32) -> Result<(), AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    if hour > 23 || minute > 59 {
+        return Err(AppError::Generic(format!(
+            "Invalid time of day: {:02}:{:02}",
+            hour, minute
+        )));
     }
 
-    fn setup_writable_fixture(name: &str) -> String {
-        let src = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
-            .join("tests")
-            .join("fixtures")
+    let changes = EnvironmentChanges {
+        // dayTime is in seconds since midnight
+        day_time: Some(f64::from(hour * 3600 + minute * 60)),
+        current_day: None,
+        snow_height: None,
+        ground_wetness: None,
+        weather_forecast: None,
+    };
+
+    backup_manager::create_backup(&save_path)?;
+    writers::environment::write_environment_changes(&save_path, &changes)
+}
+
+/// Largest balance the game handles: money goes through 32-bit integers in
+/// places, and larger amounts wrap around to negative in-game.
+pub const MAX_MONEY: f64 = i32::MAX as f64;
+
+/// Add `amount` (negative to remove) to a farm's money, clamped to
+/// `±MAX_MONEY`. Career money follows farm 1. Returns the new balance.
+#[tauri::command]
+pub fn add_money(path: String, farm_id: u8, amount: f64) -> Result<f64, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let farm = parse_farms(&save_path)?
+        .into_iter()
+        .find(|f| f.farm_id == farm_id)
+        .ok_or_else(|| AppError::Generic(format!("Farm not found: {}", farm_id)))?;
+    let money = (farm.money + amount).clamp(-MAX_MONEY, MAX_MONEY);
+
+    backup_manager::create_backup(&save_path)?;
+    writers::farm::write_farm_finances(&save_path, farm_id, Some(money), None, None)?;
+    if farm_id == 1 {
+        writers::career::write_career_money(&save_path, money)?;
+    }
+
+    Ok(money)
+}
+
+/// Pay off the loan of every farm. The loan is deducted from the farm's money
+/// unless `keep_money` is set; a farm that cannot afford it keeps its loan unless
+/// `allow_negative` lets its balance go below zero. Career money follows farm 1.
+/// Returns one entry per farm that had a loan.
+#[tauri::command]
+pub fn pay_off_loans(
+    path: String,
+    keep_money: bool,
+    allow_negative: bool,
+) -> Result<Vec<LoanPayoff>, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let payoffs: Vec<LoanPayoff> = parse_farms(&save_path)?
+        .into_iter()
+        .filter(|farm| farm.loan > 0.0)
+        .map(|farm| {
+            let money_after = if keep_money {
+                farm.money
+            } else {
+                farm.money - farm.loan
+            };
+            let paid = money_after >= 0.0 || allow_negative;
+            LoanPayoff {
+                farm_id: farm.farm_id,
+                loan_before: farm.loan,
+                loan_after: if paid { 0.0 } else { farm.loan },
+                money_before: farm.money,
+                money_after: if paid { money_after } else { farm.money },
+                paid,
+            }
+        })
+        .collect();
+
+    let changes: HashMap<u8, FinanceChanges> = payoffs
+        .iter()
+        .filter(|p| p.paid)
+        .map(|p| {
+            (
+                p.farm_id,
+                FinanceChanges {
+                    money: Some(p.money_after),
+                    loan: Some(0.0),
+                    loan_interest_rate: None,
+                },
+            )
+        })
+        .collect();
+    if changes.is_empty() {
+        return Ok(payoffs);
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::farm::write_farms_finances(&save_path, &changes)?;
+    if let Some(change) = changes.get(&1) {
+        writers::career::write_career_money(&save_path, change.money.unwrap_or_default())?;
+    }
+
+    Ok(payoffs)
+}
+
+/// Restart the career of the player's farm (farm 1) on the same map: money set to
+/// the starting amount of the save's difficulty with no loan, and its vehicles,
+/// land and statistics cleared unless `keep` says otherwise. The changes go through
+/// `save_and_verify`, which backs the save up first and reports what did not land.
+#[tauri::command]
+pub fn reset_to_fresh(path: String, keep: FreshStartKeep) -> Result<FreshStartResult, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    if keep.starting_money.is_some_and(|m| !m.is_finite() || m < 0.0) {
+        return Err(AppError::Generic("Invalid starting money".to_string()));
+    }
+    let money = match keep.starting_money {
+        Some(money) => money,
+        None => starting_money(&parse_career(&save_path)?.economic_difficulty),
+    };
+
+    let vehicles: Vec<VehicleChange> = if keep.vehicles {
+        Vec::new()
+    } else {
+        parse_vehicles(&save_path)?
+            .into_iter()
+            .filter(|v| v.farm_id == 1)
+            .map(|v| VehicleChange {
+                unique_id: v.unique_id,
+                delete: true,
+                ..Default::default()
+            })
+            .collect()
+    };
+    let farmlands: Vec<FarmlandChange> = if keep.land {
+        Vec::new()
+    } else {
+        parse_farmlands(&save_path)?
+            .into_iter()
+            .filter(|f| f.farm_id == 1)
+            .map(|f| FarmlandChange { id: f.id, farm_id: 0 })
+            .collect()
+    };
+    let vehicles_removed = vehicles.len();
+    let farmlands_released = farmlands.len();
+
+    let mut save = save_and_verify(
+        path,
+        SavegameChanges {
+            finance: Some(FinanceChanges {
+                money: Some(money),
+                loan: Some(0.0),
+                loan_interest_rate: None,
+            }),
+            vehicles: (!vehicles.is_empty()).then_some(vehicles),
+            farmlands: (!farmlands.is_empty()).then_some(farmlands),
+            ..Default::default()
+        },
+    )?;
+
+    let mut statistics_reset = false;
+    if !keep.statistics {
+        match writers::farm::reset_farm_statistics(&save_path, 1) {
+            Ok(reset) => statistics_reset = reset,
+            Err(e) => {
+                save.result.errors.push(
+                    LocalizedMessage::new("errors.fileWriteError")
+                        .with_param("file", FARMS)
+                        .with_param("details", e),
+                );
+                save.result.success = false;
+            }
+        }
+    }
+
+    Ok(FreshStartResult {
+        save,
+        starting_money: money,
+        vehicles_removed,
+        farmlands_released,
+        statistics_reset,
+    })
+}
+
+/// Grant or revoke manager status and permissions of the farm player identified
+/// by `user_id`, leaving the flags `permissions` does not set as they are.
+#[tauri::command]
+pub fn set_player_permissions(
+    path: String,
+    user_id: String,
+    permissions: PlayerPermissionChanges,
+) -> Result<(), AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let known = parse_farms(&save_path)?
+        .iter()
+        .any(|farm| farm.players.iter().any(|p| p.unique_user_id == user_id));
+    if !known {
+        return Err(AppError::Generic(format!("Player not found: {}", user_id)));
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::farm::write_player_permissions(&save_path, &user_id, &permissions)?;
+
+    Ok(())
+}
+
+/// Make the player `user_id` of farm `farm_id` farm manager and grant them every
+/// permission. Other players are left untouched.
+#[tauri::command]
+pub fn make_farm_manager(path: String, farm_id: u8, user_id: String) -> Result<(), AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let farm = parse_farms(&save_path)?
+        .into_iter()
+        .find(|f| f.farm_id == farm_id)
+        .ok_or_else(|| AppError::Generic(format!("Farm not found: {}", farm_id)))?;
+    if !farm.players.iter().any(|p| p.unique_user_id == user_id) {
+        return Err(AppError::Generic(format!(
+            "Player {} not found on farm {}",
+            user_id, farm_id
+        )));
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::farm::write_player_permissions(
+        &save_path,
+        &user_id,
+        &PlayerPermissionChanges::grant_all(),
+    )?;
+
+    Ok(())
+}
+
+/// Rename the farm player identified by `user_id`: the nickname the save last
+/// saw them with. The game may replace it with the name of their profile when
+/// they next join.
+#[tauri::command]
+pub fn rename_player(path: String, user_id: String, name: String) -> Result<(), AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(AppError::Generic("Player name cannot be empty".to_string()));
+    }
+
+    let known = parse_farms(&save_path)?
+        .iter()
+        .any(|farm| farm.players.iter().any(|p| p.unique_user_id == user_id));
+    if !known {
+        return Err(AppError::Generic(format!("Player not found: {}", user_id)));
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::farm::write_player_nickname(&save_path, &user_id, name)?;
+
+    Ok(())
+}
+
+/// Set one statistic of farm `farm_id`, named as in farms.xml (`workedHectares`,
+/// `baleCount`...), to satisfy content gated on it. Counters only take whole
+/// numbers; no statistic takes a negative value.
+#[tauri::command]
+pub fn set_statistic(
+    path: String,
+    farm_id: u8,
+    stat_name: String,
+    value: f64,
+) -> Result<(), AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    if !value.is_finite() || value < 0.0 {
+        return Err(AppError::Generic(format!(
+            "Invalid value for {}: {}",
+            stat_name, value
+        )));
+    }
+    let text = if COUNT_STATISTICS.contains(&stat_name.as_str()) {
+        if value.fract() != 0.0 {
+            return Err(AppError::Generic(format!(
+                "{} only takes whole numbers: {}",
+                stat_name, value
+            )));
+        }
+        format!("{}", value as u64)
+    } else if VALUE_STATISTICS.contains(&stat_name.as_str()) {
+        format!("{:.6}", value)
+    } else {
+        return Err(AppError::Generic(format!(
+            "Unknown statistic: {}",
+            stat_name
+        )));
+    };
+
+    let farms = parse_farms(&save_path)?;
+    if !farms.iter().any(|f| f.farm_id == farm_id) {
+        return Err(AppError::Generic(format!("Farm not found: {}", farm_id)));
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    if !writers::farm::write_farm_statistic(&save_path, farm_id, &stat_name, &text)? {
+        return Err(AppError::Generic(format!(
+            "Farm {} has no statistics",
+            farm_id
+        )));
+    }
+
+    Ok(())
+}
+
+/// Switch the economic difficulty (EASY, NORMAL or HARD). Returns a note with the
+/// income and expense factors now in effect and what happens to the loan.
+#[tauri::command]
+pub fn set_difficulty(path: String, difficulty: String) -> Result<LocalizedMessage, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let code = economic_difficulty_code(&difficulty)
+        .ok_or_else(|| AppError::Generic(format!("Unknown difficulty: {}", difficulty)))?;
+
+    backup_manager::create_backup(&save_path)?;
+    writers::career::write_economic_difficulty(&save_path, code)?;
+
+    let career = parse_career(&save_path)?;
+    Ok(LocalizedMessage::new("difficulty.changeNote")
+        .with_param("difficulty", &career.economic_difficulty)
+        .with_param("income", career.economic_multipliers.income)
+        .with_param("expenses", career.economic_multipliers.expenses))
+}
+
+/// Dismiss every hired worker of a farm by removing the active job node of its
+/// vehicles; the rest of each vehicle is left untouched. Returns the number of
+/// workers stopped.
+#[tauri::command]
+pub fn stop_all_workers(path: String, farm_id: u8) -> Result<usize, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let changes: Vec<VehicleChange> = parse_vehicles(&save_path)?
+        .into_iter()
+        .filter(|v| v.farm_id == farm_id && v.worker_task.is_some())
+        .map(|v| VehicleChange {
+            unique_id: v.unique_id,
+            stop_worker: true,
+            ..Default::default()
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(0);
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::vehicle::write_vehicle_changes(&save_path, &changes)?;
+
+    Ok(changes.len())
+}
+
+/// Move every vehicle of `from_farm` to `to_farm`, e.g. when consolidating a
+/// co-op farm. Rented and leased vehicles stay with their farm unless
+/// `include_rented` is set. Returns the number of vehicles moved.
+#[tauri::command]
+pub fn transfer_all_vehicles(
+    path: String,
+    from_farm: u8,
+    to_farm: u8,
+    include_rented: bool,
+) -> Result<usize, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    if !parse_farms(&save_path)?.iter().any(|f| f.farm_id == to_farm) {
+        return Err(AppError::Generic(format!("Farm not found: {}", to_farm)));
+    }
+
+    let changes: Vec<VehicleChange> = parse_vehicles(&save_path)?
+        .into_iter()
+        .filter(|v| v.farm_id == from_farm && from_farm != to_farm)
+        .filter(|v| include_rented || v.property_state != PropertyState::Rented)
+        .map(|v| VehicleChange {
+            unique_id: v.unique_id,
+            farm_id: Some(to_farm),
+            ..Default::default()
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(0);
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::vehicle::write_vehicle_changes(&save_path, &changes)?;
+
+    Ok(changes.len())
+}
+
+/// Multiply the operating time, age, damage and wear of every vehicle by `factor`
+/// (between 0 and 1), for a fleet that looks less used rather than brand new.
+/// Damage and wear are kept between 0 and 1. Returns the fleet usage before and after.
+#[tauri::command]
+pub fn scale_fleet_wear(path: String, factor: f64) -> Result<FleetWearScaling, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    if !(0.0..=1.0).contains(&factor) {
+        return Err(AppError::Generic(format!("Invalid wear factor: {}", factor)));
+    }
+
+    let vehicles = parse_vehicles(&save_path)?;
+    let before = FleetWear::of(&vehicles);
+
+    let scaled: Vec<Vehicle> = vehicles
+        .into_iter()
+        .map(|mut v| {
+            v.operating_time = (v.operating_time * factor).max(0.0);
+            v.age = (v.age * factor).max(0.0);
+            v.damage = (v.damage * factor).clamp(0.0, 1.0);
+            v.wear = (v.wear * factor).clamp(0.0, 1.0);
+            v
+        })
+        .collect();
+    let changes: Vec<VehicleChange> = scaled
+        .iter()
+        .map(|v| VehicleChange {
+            unique_id: v.unique_id.clone(),
+            age: Some(v.age),
+            operating_time: Some(v.operating_time),
+            damage: Some(v.damage),
+            wear: Some(v.wear),
+            ..Default::default()
+        })
+        .collect();
+
+    if !changes.is_empty() {
+        backup_manager::create_backup(&save_path)?;
+        writers::vehicle::write_vehicle_changes(&save_path, &changes)?;
+    }
+
+    Ok(FleetWearScaling {
+        before,
+        after: FleetWear::of(&scaled),
+    })
+}
+
+/// Switch every field growing or planning `from_fruit` to `to_fruit`. The current
+/// and planned crops are replaced independently. Returns the number of fields changed.
+#[tauri::command]
+pub fn replace_crop(path: String, from_fruit: String, to_fruit: String) -> Result<usize, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let fields = parse_fields(&save_path)?;
+    let economy = parse_economy(&save_path).ok();
+    let to_fruit = to_fruit.to_uppercase();
+    if !valid_fruit_types(&fields, economy.as_ref()).contains(&to_fruit) {
+        return Err(AppError::Generic(format!("Unknown crop: {}", to_fruit)));
+    }
+
+    let changes: Vec<FieldChange> = fields
+        .into_iter()
+        .filter_map(|f| {
+            let replace = |fruit: &str| {
+                fruit
+                    .eq_ignore_ascii_case(&from_fruit)
+                    .then(|| to_fruit.clone())
+            };
+            let fruit_type = replace(&f.fruit_type);
+            let planned_fruit = replace(&f.planned_fruit);
+            if fruit_type.is_none() && planned_fruit.is_none() {
+                return None;
+            }
+            Some(FieldChange {
+                id: f.id,
+                fruit_type,
+                planned_fruit,
+                growth_state: None,
+                ground_type: None,
+                weed_state: None,
+                stone_level: None,
+                spray_level: None,
+                spray_type: None,
+                lime_level: None,
+                plow_level: None,
+                roller_level: None,
+                stubble_shred_level: None,
+                water_level: None,
+            })
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(0);
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::field::write_field_changes(&save_path, &changes)?;
+
+    Ok(changes.len())
+}
+
+/// Put field `field_id` in the state left by `operations` (plow, cultivate,
+/// lime, fertilize), without setting each level by hand.
+#[tauri::command]
+pub fn prepare_field(
+    path: String,
+    field_id: u32,
+    operations: Vec<FieldOperation>,
+) -> Result<(), AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    if operations.is_empty() {
+        return Err(AppError::Generic("No field operation given".to_string()));
+    }
+    if !parse_fields(&save_path)?.iter().any(|f| f.id == field_id) {
+        return Err(AppError::Generic(format!("Field not found: {}", field_id)));
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::field::write_field_changes(
+        &save_path,
+        &[FieldChange::prepared(field_id, &operations)],
+    )?;
+
+    Ok(())
+}
+
+/// Reset the fields flagged by the `fieldGrowthStates` check to freshly cut
+/// stubble with no crop, a state the game always accepts. Returns the ids of the
+/// fields repaired; nothing is written, nor backed up, when there are none.
+#[tauri::command]
+pub fn repair_field_states(path: String) -> Result<Vec<u32>, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let changes: Vec<FieldChange> = parse_fields(&save_path)?
+        .iter()
+        .filter(|f| has_incoherent_growth_state(f))
+        .map(|f| FieldChange {
+            id: f.id,
+            fruit_type: Some("UNKNOWN".to_string()),
+            growth_state: Some(0),
+            ground_type: Some("STUBBLE_TILLAGE".to_string()),
+            ..Default::default()
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::field::write_field_changes(&save_path, &changes)?;
+
+    Ok(changes.iter().map(|c| c.id).collect())
+}
+
+/// Days a sold vehicle stays listed at the dealer, as when adding a sale item by hand.
+const SOLD_VEHICLE_TIME_LEFT: u32 = 30;
+
+/// Sell a vehicle together with everything attached to it: each vehicle of the
+/// group is removed from vehicles.xml and listed in sales.xml, at its price in
+/// `price_map` or its current value. Attachments of the remaining vehicles that
+/// pointed into the group are dropped. Everything is written after a single backup.
+#[tauri::command]
+pub fn sell_vehicle_group(
+    path: String,
+    root_unique_id: String,
+    price_map: HashMap<String, u32>,
+) -> Result<SaveResult, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let vehicles = parse_vehicles(&save_path)?;
+    let tree = build_attachment_tree(&vehicles);
+    let group = AttachmentNode::find(&tree, &root_unique_id)
+        .ok_or_else(|| AppError::Generic(format!("Vehicle not found: {}", root_unique_id)))?
+        .unique_ids();
+
+    let mut deletions = Vec::new();
+    let mut additions = Vec::new();
+    for unique_id in group {
+        let Some(vehicle) = vehicles.iter().find(|v| v.unique_id == unique_id) else {
+            continue;
+        };
+        if vehicle.property_state != PropertyState::Owned {
+            return Err(AppError::Generic(format!(
+                "Vehicle is not owned: {}",
+                vehicle.unique_id
+            )));
+        }
+
+        deletions.push(VehicleChange {
+            unique_id: vehicle.unique_id.clone(),
+            delete: true,
+            ..Default::default()
+        });
+        additions.push(SaleAddition {
+            xml_filename: vehicle.filename.clone(),
+            price: price_map
+                .get(&vehicle.unique_id)
+                .copied()
+                .unwrap_or(vehicle.price.round() as u32),
+            damage: vehicle.damage,
+            wear: vehicle.wear,
+            age: vehicle.age.round() as u32,
+            operating_time: vehicle.operating_time,
+            time_left: SOLD_VEHICLE_TIME_LEFT,
+            bought_configurations: Some(
+                vehicle
+                    .configurations
+                    .iter()
+                    .map(|c| BoughtConfiguration {
+                        name: c.name.clone(),
+                        id: c.id.clone(),
+                    })
+                    .collect(),
+            ),
+        });
+    }
+
+    save_changes(
+        path,
+        SavegameChanges {
+            vehicles: Some(deletions),
+            sale_additions: Some(additions),
+            ..Default::default()
+        },
+    )
+}
+
+/// Reset the sales statistics of the selling station placeable at `index`, or of
+/// every selling station when `None`. Returns the number of fill type entries cleared.
+#[tauri::command]
+pub fn reset_selling_station_stats(path: String, index: Option<usize>) -> Result<usize, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    backup_manager::create_backup(&save_path)?;
+    writers::placeable::clear_selling_station_stats(&save_path, index)
+}
+
+/// Remove the loose bales and/or pallets lying around the map, which weigh on
+/// performance in long-running saves. Pallets loaded on a trailer or put away in
+/// a storage are kept. Returns the number of objects removed per kind.
+#[tauri::command]
+pub fn clear_loose_items(
+    path: String,
+    kinds: Vec<LooseItemKind>,
+) -> Result<ClearedItems, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    backup_manager::create_backup(&save_path)?;
+
+    let mut cleared = ClearedItems::default();
+    if kinds.contains(&LooseItemKind::Bale) {
+        cleared.bales = writers::item::clear_bales(&save_path)?;
+    }
+    if kinds.contains(&LooseItemKind::Pallet) {
+        cleared.pallets = writers::item::clear_pallets(&save_path)?;
+    }
+    Ok(cleared)
+}
+
+/// Reset every used item of the dealer's sales to like-new: no damage, no wear,
+/// age and operating time back to zero. Prices are kept, unless `game_path` is given,
+/// in which case items found in the vehicle catalog get their new price back.
+/// Returns the number of items changed.
+#[tauri::command]
+pub fn normalize_dealer(path: String, game_path: Option<String>) -> Result<usize, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let catalog_prices: HashMap<String, u32> = match game_path {
+        Some(ref game_path) => {
+            let game_path = validate_game_path(game_path)?;
+            scan_vehicle_catalog(&game_path, &get_mods_dir())
+                .into_iter()
+                .filter(|v| v.price > 0)
+                .map(|v| (v.xml_filename.to_lowercase(), v.price))
+                .collect()
+        }
+        None => HashMap::new(),
+    };
+
+    let changes: Vec<SaleChange> = parse_sales(&save_path)?
+        .into_iter()
+        .filter_map(|item| {
+            let price = catalog_prices
+                .get(&item.xml_filename.to_lowercase())
+                .copied()
+                .filter(|p| *p != item.price);
+            let is_used = item.damage > 0.0
+                || item.wear > 0.0
+                || item.age > 0
+                || item.operating_time > 0.0;
+            if !is_used && price.is_none() {
+                return None;
+            }
+            Some(SaleChange {
+                index: item.index,
+                delete: false,
+                price,
+                damage: Some(0.0),
+                wear: Some(0.0),
+                age: Some(0),
+                operating_time: Some(0.0),
+                time_left: None,
+            })
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(0);
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::sale::write_sale_changes(&save_path, &changes)?;
+
+    Ok(changes.len())
+}
+
+/// `timeLeft` given to dealer items by `freeze_dealer`, long enough never to run out.
+const FROZEN_DEALER_TIME_LEFT: u32 = 9999;
+
+/// `timeLeft` given to dealer items by `expire_dealer`, so they leave the dealer at
+/// its next update and new offers replace them.
+const EXPIRING_DEALER_TIME_LEFT: u32 = 1;
+
+/// Keep every item of the dealer's sales on offer by giving them a very long
+/// `timeLeft`. Returns the number of items changed.
+#[tauri::command]
+pub fn freeze_dealer(path: String) -> Result<usize, AppError> {
+    set_dealer_time_left(path, FROZEN_DEALER_TIME_LEFT)
+}
+
+/// Make every item of the dealer's sales expire at the next update, to get new
+/// offers. Returns the number of items changed.
+#[tauri::command]
+pub fn expire_dealer(path: String) -> Result<usize, AppError> {
+    set_dealer_time_left(path, EXPIRING_DEALER_TIME_LEFT)
+}
+
+fn set_dealer_time_left(path: String, time_left: u32) -> Result<usize, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let changes: Vec<SaleChange> = parse_sales(&save_path)?
+        .into_iter()
+        .filter(|item| item.time_left != time_left)
+        .map(|item| SaleChange {
+            index: item.index,
+            delete: false,
+            price: None,
+            damage: None,
+            wear: None,
+            age: None,
+            operating_time: None,
+            time_left: Some(time_left),
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(0);
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::sale::write_sale_changes(&save_path, &changes)?;
+
+    Ok(changes.len())
+}
+
+/// Move every great demand to `start_day` for `duration` hours, to keep lucrative
+/// demands coming. With `expired_only`, only the demands that ended before the
+/// current day of environment.xml are restarted, those the `staleGreatDemands`
+/// validator reports. Empty demand slots are left alone. Returns the number of
+/// demands changed.
+#[tauri::command]
+pub fn refresh_all_demands(
+    path: String,
+    start_day: u32,
+    duration: u32,
+    expired_only: bool,
+) -> Result<usize, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    if duration == 0 {
+        return Err(AppError::Generic("Demand duration must be positive".to_string()));
+    }
+
+    let current_day = if expired_only {
+        parse_environment(&save_path)?.current_day
+    } else {
+        0
+    };
+
+    let changes: Vec<GreatDemandChange> = parse_economy(&save_path)?
+        .great_demands
+        .into_iter()
+        .filter(|d| !d.unique_id.is_empty())
+        .filter(|d| !expired_only || d.end_day() < current_day)
+        .map(|d| GreatDemandChange {
+            index: d.index,
+            unique_id: Some(d.unique_id),
+            fill_type_name: None,
+            demand_multiplier: None,
+            demand_start_day: Some(start_day),
+            demand_start_hour: None,
+            demand_duration: Some(duration),
+            is_running: None,
+            is_valid: Some(true),
+        })
+        .collect();
+
+    if changes.is_empty() {
+        return Ok(0);
+    }
+
+    let count = changes.len();
+    backup_manager::create_backup(&save_path)?;
+    writers::economy::write_economy_changes(
+        &save_path,
+        &EconomyChanges {
+            great_demand_changes: Some(changes),
+            great_demand_additions: None,
+            great_demand_deletions: None,
+        },
+    )?;
+
+    Ok(count)
+}
+
+/// Insert a well-formed XML declaration at the top of `file` in the savegame when it
+/// is missing or broken, which makes the game reject the save. Meant to run before
+/// other edits. A backup is made only when the file needs repairing.
+/// Returns whether the file was changed.
+#[tauri::command]
+pub fn ensure_xml_header(path: String, file: String) -> Result<bool, AppError> {
+    let save_path = resolve_writable_save_path(&path)?;
+
+    let is_plain_xml_name = Path::new(&file).file_name().is_some_and(|n| n == file.as_str())
+        && file.to_lowercase().ends_with(".xml");
+    let xml_path = savegame_file(&save_path, &file);
+    if !is_plain_xml_name || !xml_path.is_file() {
+        return Err(AppError::Generic(format!("Not an XML file of the savegame: {}", file)));
+    }
+
+    if has_xml_declaration(&read_xml_file(&xml_path)?) {
+        return Ok(false);
+    }
+
+    backup_manager::create_backup(&save_path)?;
+    writers::header::ensure_xml_header(&save_path, &file)
+}
+
+#[tauri::command]
+pub fn open_savegame_folder(app: AppHandle, path: String) -> Result<(), AppError> {
+    let save_path = resolve_save_path(&path)?;
+    app.opener()
+        .open_path(save_path.to_string_lossy(), None::<&str>)
+        .map_err(|e| AppError::IoError {
+            message: e.to_string(),
+        })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::changes::{CollectibleChange, ContractSettingsChange, TreeChanges};
+
+    fn fixtures_path() -> String {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("test_saves")
+            .display()
+            .to_string()
+    }
+
+    fn complete_fixture_path() -> String {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_complete")
+            .display()
+            .to_string()
+    }
+
+    #[test]
+    fn test_list_savegames_with_fixtures() {
+        let result = list_savegames(Some(fixtures_path())).unwrap();
+        assert_eq!(result.len(), 2);
+        let names: Vec<&str> = result.iter().map(|s| s.name.as_str()).collect();
+        assert!(names.contains(&"Ma partie"));
+        assert!(names.contains(&"Partie 2"));
+    }
+
+    #[test]
+    fn test_list_savegames_empty_dir() {
+        let dir = std::env::temp_dir().join("fs25_test_empty_saves");
+        let _ = std::fs::create_dir_all(&dir);
+
+        let result = list_savegames(Some(dir.display().to_string())).unwrap();
+        assert!(result.is_empty());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_list_savegames_nonexistent_path() {
+        let result = list_savegames(Some("/nonexistent/path/nowhere".to_string())).unwrap();
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_list_savegames_multi_tags_and_dedupes() {
+        let other = std::env::temp_dir().join("fs25_test_multi_saves");
+        let _ = std::fs::remove_dir_all(&other);
+        std::fs::create_dir_all(other.join("savegame7")).unwrap();
+        std::fs::copy(
+            PathBuf::from(complete_fixture_path()).join("careerSavegame.xml"),
+            other.join("savegame7").join("careerSavegame.xml"),
+        )
+        .unwrap();
+        let other_base = other.display().to_string();
+
+        // The fixtures folder is listed twice, in two spellings
+        let fixtures_again = PathBuf::from(fixtures_path()).join(".").display().to_string();
+        let result =
+            list_savegames_multi(vec![fixtures_path(), other_base.clone(), fixtures_again])
+                .unwrap();
+
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.iter().filter(|l| l.base_path == fixtures_path()).count(), 2);
+        let added = result.iter().find(|l| l.base_path == other_base).unwrap();
+        assert_eq!(added.summary.name, "Test Complete");
+
+        let _ = std::fs::remove_dir_all(&other);
+    }
+
+    #[test]
+    fn test_load_savegame_complete() {
+        let data = load_savegame(complete_fixture_path()).unwrap();
+        assert_eq!(data.career.savegame_name, "Test Complete");
+        assert_eq!(data.career.map_title, "Riverbend Springs");
+        assert!((data.career.money - 1_000_000.0).abs() < 0.01);
+        assert_eq!(data.career.growth_mode, 1);
+        assert_eq!(data.career.planned_days_per_period, 3);
+        assert!(data.career.plowing_required);
+        assert!(data.career.weeds_enabled);
+        assert_eq!(data.career.fuel_usage, 2);
+
+        assert_eq!(data.farms.len(), 1);
+        assert_eq!(data.vehicles.len(), 3);
+        assert_eq!(data.sales.len(), 2);
+        assert_eq!(data.fields.len(), 4);
+        assert_eq!(data.farmlands.len(), 5);
+        assert_eq!(data.placeables.len(), 4);
+        assert_eq!(data.missions.len(), 3);
+        assert_eq!(data.collectibles.len(), 25);
+        assert_eq!(data.trees.len(), 4);
+        assert_eq!(data.tree_counts.len(), 3);
+        assert!(data.contract_settings.is_some());
+        assert!(data.environment.is_some());
+        let env = data.environment.unwrap();
+        assert_eq!(env.current_day, 54);
+        assert_eq!(env.weather_forecast.len(), 4);
+        assert!(data.economy.is_some());
+        let eco = data.economy.unwrap();
+        assert_eq!(eco.great_demands.len(), 2);
+        assert_eq!(eco.fill_types.len(), 2);
+        assert!(data.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_load_savegame_repairs_invalid_utf8_name() {
+        let path = setup_writable_fixture("invalid_utf8_name");
+        let save_path = PathBuf::from(&path);
+        let career_xml = save_path.join("careerSavegame.xml");
+        let content = std::fs::read_to_string(&career_xml).unwrap();
+        // A Latin-1 é next to the UTF-8 en dash of a valid title
+        let content = content.replace("Riverbend Springs", "Riverbend \u{2013} Springs");
+        let (before, after) = content.split_once("Test Complete").unwrap();
+        let mut bytes = before.as_bytes().to_vec();
+        bytes.extend_from_slice(b"Ferme Dupr\xE9");
+        bytes.extend_from_slice(after.as_bytes());
+        std::fs::write(&career_xml, bytes).unwrap();
+
+        let data = load_savegame(path.clone()).unwrap();
+        assert_eq!(data.career.savegame_name, "Ferme Dupré");
+        assert_eq!(data.career.map_title, "Riverbend \u{2013} Springs");
+        assert!(data
+            .warnings
+            .iter()
+            .any(|w| w.code == "errors.invalidUtf8Repaired"));
+
+        // Saving rewrites the file as valid UTF-8
+        let changes: SavegameChanges = serde_json::from_value(serde_json::json!({
+            "finance": { "money": 5000.0 }
+        }))
+        .unwrap();
+        assert!(save_changes(path.clone(), changes).unwrap().success);
+        let content = std::fs::read_to_string(&career_xml).unwrap();
+        assert!(content.contains("Ferme Dupré"));
+        let data = load_savegame(path.clone()).unwrap();
+        assert!(data.warnings.is_empty());
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_load_savegame_repairs_ascii_name_with_stray_byte() {
+        let path = setup_writable_fixture("invalid_utf8_ascii_name");
+        let save_path = PathBuf::from(&path);
+        let career_xml = save_path.join("careerSavegame.xml");
+        let content = std::fs::read_to_string(&career_xml).unwrap();
+        assert!(content.is_ascii());
+        let (before, after) = content.split_once("Test Complete").unwrap();
+        let mut bytes = before.as_bytes().to_vec();
+        bytes.extend_from_slice(b"Dupr\xE9");
+        bytes.extend_from_slice(after.as_bytes());
+        std::fs::write(&career_xml, bytes).unwrap();
+
+        let data = load_savegame(path.clone()).unwrap();
+        assert_eq!(data.career.savegame_name, "Dupré");
+        assert!(data
+            .warnings
+            .iter()
+            .any(|w| w.code == "errors.invalidUtf8Repaired"));
+
+        rename_savegame(path.clone(), "Dupré – Étang".to_string()).unwrap();
+        let content = std::fs::read_to_string(&career_xml).unwrap();
+        assert!(content.contains("<savegameName>Dupré – Étang</savegameName>"));
+        assert!(load_savegame(path.clone()).unwrap().warnings.is_empty());
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_load_savegame_missing_sales() {
+        // Use savegame1 fixture which has career but no sales
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("test_saves")
+            .join("savegame1");
+
+        // Add a minimal farms.xml and vehicles.xml for this test
+        let farms_content = r#"<?xml version="1.0" encoding="utf-8"?>
+<farms>
+  <farm farmId="1" name="Test" color="1" loan="0" money="100">
+    <players></players>
+    <finances></finances>
+  </farm>
+</farms>"#;
+        let vehicles_content = r#"<?xml version="1.0" encoding="utf-8"?>
+<vehicles></vehicles>"#;
+
+        std::fs::write(path.join("farms.xml"), farms_content).unwrap();
+        std::fs::write(path.join("vehicles.xml"), vehicles_content).unwrap();
+
+        let data = load_savegame(path.display().to_string()).unwrap();
+        assert_eq!(data.career.savegame_name, "Ma partie");
+        assert!(data.sales.is_empty());
+        assert!(data.warnings.iter().any(|w| w.code == "errors.fileUnreadable" && w.params.get("file").map(|f| f.as_str()) == Some("sales.xml")));
+
+        // Cleanup
+        let _ = std::fs::remove_file(path.join("farms.xml"));
+        let _ = std::fs::remove_file(path.join("vehicles.xml"));
+    }
+
+    #[test]
+    fn test_load_savegame_refuses_fs22() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_fs22");
+        let result = load_savegame(path.display().to_string());
+        assert!(matches!(
+            result,
+            Err(AppError::UnsupportedSaveVersion { ref detected, .. }) if detected == "FS22"
+        ));
+    }
+
+    #[test]
+    fn test_write_commands_refuse_fs22() {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_fs22")
+            .display()
+            .to_string();
+        let career = std::fs::read(PathBuf::from(&path).join("careerSavegame.xml")).unwrap();
+        let refused = |result: Result<(), AppError>| {
+            matches!(result, Err(AppError::UnsupportedSaveVersion { .. }))
+        };
+
+        assert!(refused(add_money(path.clone(), 1, 1000.0).map(|_| ())));
+        assert!(refused(expire_dealer(path.clone()).map(|_| ())));
+        assert!(refused(replace_crop(path.clone(), "WHEAT".into(), "BARLEY".into()).map(|_| ())));
+        assert!(refused(set_time_of_day(path.clone(), 6, 0)));
+        assert!(refused(save_changes(path.clone(), finance_changes(1000.0)).map(|_| ())));
+        assert_eq!(
+            std::fs::read(PathBuf::from(&path).join("careerSavegame.xml")).unwrap(),
+            career
+        );
+    }
+
+    #[test]
+    fn test_load_savegame_truncated_vehicles() {
+        let path = setup_writable_fixture("truncated_vehicles");
+        let vehicles_xml = PathBuf::from(&path).join("vehicles.xml");
+        let content = std::fs::read_to_string(&vehicles_xml).unwrap();
+        let cut = content.find("kroneBigX1180").unwrap();
+        std::fs::write(&vehicles_xml, &content[..cut]).unwrap();
+
+        let data = load_savegame(path.clone()).unwrap();
+        assert_eq!(data.vehicles.len(), 1);
+        assert!(data
+            .warnings
+            .iter()
+            .any(|w| w.code == "errors.truncatedFileRecovered"));
+
+        // The truncated file is never written back
+        let result = stop_all_workers(path.clone(), 1);
+        assert!(matches!(result, Err(AppError::TruncatedFile { .. })));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_load_savegame_invalid_path() {
+        let result = load_savegame("/nonexistent/path".to_string());
+        assert!(matches!(result, Err(AppError::SavegameNotFound { .. })));
+    }
+
+    fn setup_writable_fixture(name: &str) -> String {
+        let src = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
             .join("savegame_complete");
         let dst = std::env::temp_dir().join(format!("fs25_test_save_{}", name));
         let _ = std::fs::remove_dir_all(&dst);
@@ -679,18 +2189,9 @@ mod tests {
             finance: Some(crate::models::changes::FinanceChanges {
                 money: Some(999.0),
                 loan: None,
+                loan_interest_rate: None,
             }),
-            vehicles: None,
-            sales: None,
-            sale_additions: None,
-            fields: None,
-            farmlands: None,
-            placeables: None,
-            missions: None,
-            collectibles: None,
-            contract_settings: None,
-            environment: None,
-            economy: None,
+            ..Default::default()
         };
         let result = save_changes(path.clone(), changes).unwrap();
         assert!(result.success);
@@ -706,18 +2207,9 @@ mod tests {
             finance: Some(crate::models::changes::FinanceChanges {
                 money: Some(555555.0),
                 loan: None,
+                loan_interest_rate: None,
             }),
-            vehicles: None,
-            sales: None,
-            sale_additions: None,
-            fields: None,
-            farmlands: None,
-            placeables: None,
-            missions: None,
-            collectibles: None,
-            contract_settings: None,
-            environment: None,
-            economy: None,
+            ..Default::default()
         };
         save_changes(path.clone(), changes).unwrap();
 
@@ -729,26 +2221,92 @@ mod tests {
     }
 
     #[test]
-    fn test_save_changes_empty_changes() {
-        let path = setup_writable_fixture("empty_changes");
+    fn test_save_changes_empty_changes() {
+        let path = setup_writable_fixture("empty_changes");
+        let changes = SavegameChanges::default();
+        let result = save_changes(path.clone(), changes).unwrap();
+        assert!(result.success);
+        assert!(result.backup_path.is_none());
+        assert!(result.files_modified.is_empty());
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_save_changes_reports_unmatched_changes() {
+        let path = setup_writable_fixture("unmatched_changes");
+        let changes = SavegameChanges {
+            vehicles: Some(vec![VehicleChange {
+                unique_id: "vehicle9999".to_string(),
+                price: Some(1000.0),
+                ..Default::default()
+            }]),
+            fields: Some(vec![FieldChange {
+                id: 1,
+                growth_state: Some(3),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+
+        let result = save_changes(path.clone(), changes).unwrap();
+        assert!(result.success);
+        assert_eq!(
+            result.unmatched_changes,
+            vec![UnmatchedChange {
+                file: VEHICLES.to_string(),
+                key: "vehicle9999".to_string(),
+            }]
+        );
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_save_and_verify_reports_unmatched_vehicle() {
+        let path = setup_writable_fixture("save_and_verify");
+        let vehicle_change = |unique_id: &str| VehicleChange {
+            unique_id: unique_id.to_string(),
+            age: Some(3.0),
+            ..Default::default()
+        };
+        let changes = SavegameChanges {
+            vehicles: Some(vec![vehicle_change("vehicle0001"), vehicle_change("vehicle9999")]),
+            ..Default::default()
+        };
+
+        let verified = save_and_verify(path.clone(), changes).unwrap();
+        assert!(!verified.result.success);
+        assert!(verified.result.errors.is_empty());
+        assert_eq!(verified.unapplied.len(), 1);
+        assert_eq!(verified.unapplied[0].file, VEHICLES);
+        assert_eq!(verified.unapplied[0].target, "vehicle9999");
+        assert!(verified.unapplied[0].property.is_none());
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_save_and_verify_checks_collectibles_and_lists_unverified() {
+        let path = setup_writable_fixture("save_and_verify_sections");
         let changes = SavegameChanges {
-            finance: None,
-            vehicles: None,
-            sales: None,
-            sale_additions: None,
-            fields: None,
-            farmlands: None,
-            placeables: None,
-            missions: None,
-            collectibles: None,
-            contract_settings: None,
-            environment: None,
-            economy: None,
+            collectibles: Some(vec![CollectibleChange {
+                index: 0,
+                collected: false,
+                marked: None,
+            }]),
+            contract_settings: Some(ContractSettingsChange {
+                lease_vehicle: Some(5.0),
+                mission_per_farm: None,
+                allow_clear_add: None,
+            }),
+            trees: Some(TreeChanges {
+                mature_indices: None,
+            }),
+            ..Default::default()
         };
-        let result = save_changes(path.clone(), changes).unwrap();
-        assert!(result.success);
-        assert!(result.backup_path.is_none());
-        assert!(result.files_modified.is_empty());
+
+        let verified = save_and_verify(path.clone(), changes).unwrap();
+        assert!(verified.result.success, "{:?}", verified.unapplied);
+        assert!(verified.unapplied.is_empty());
+        assert_eq!(verified.unverified, vec!["trees".to_string()]);
         cleanup_writable_fixture(&path);
     }
 
@@ -766,18 +2324,9 @@ mod tests {
             finance: Some(crate::models::changes::FinanceChanges {
                 money: Some(777777.0),
                 loan: Some(25000.0),
+                loan_interest_rate: None,
             }),
-            vehicles: None,
-            sales: None,
-            sale_additions: None,
-            fields: None,
-            farmlands: None,
-            placeables: None,
-            missions: None,
-            collectibles: None,
-            contract_settings: None,
-            environment: None,
-            economy: None,
+            ..Default::default()
         };
         let result = save_changes(path.clone(), changes).unwrap();
         assert!(result.success);
@@ -803,18 +2352,9 @@ mod tests {
             finance: Some(crate::models::changes::FinanceChanges {
                 money: Some(123456.0),
                 loan: None,
+                loan_interest_rate: None,
             }),
-            vehicles: None,
-            sales: None,
-            sale_additions: None,
-            fields: None,
-            farmlands: None,
-            placeables: None,
-            missions: None,
-            collectibles: None,
-            contract_settings: None,
-            environment: None,
-            economy: None,
+            ..Default::default()
         };
         save_changes(path.clone(), changes).unwrap();
 
@@ -846,18 +2386,9 @@ mod tests {
             finance: Some(crate::models::changes::FinanceChanges {
                 money: Some(42.0),
                 loan: None,
+                loan_interest_rate: None,
             }),
-            vehicles: None,
-            sales: None,
-            sale_additions: None,
-            fields: None,
-            farmlands: None,
-            placeables: None,
-            missions: None,
-            collectibles: None,
-            contract_settings: None,
-            environment: None,
-            economy: None,
+            ..Default::default()
         };
         save_changes(path.clone(), changes).unwrap();
 
@@ -879,6 +2410,31 @@ mod tests {
         cleanup_writable_fixture(&path);
     }
 
+    #[test]
+    fn test_load_savegame_safe() {
+        let path = setup_writable_fixture("load_safe");
+        let save_path = PathBuf::from(&path);
+
+        let result = load_savegame_safe(path.clone()).unwrap();
+        assert!(result.errors.is_empty());
+        assert_eq!(result.data.career.savegame_name, "Test Complete");
+
+        // Required files broken: the rest still loads
+        let broken = "<careerSavegame></settings>";
+        std::fs::write(save_path.join("careerSavegame.xml"), broken).unwrap();
+        std::fs::write(save_path.join("farms.xml"), "<farms></farm>").unwrap();
+        assert!(load_savegame(path.clone()).is_err());
+
+        let result = load_savegame_safe(path.clone()).unwrap();
+        let failed: Vec<&str> = result.errors.iter().map(|e| e.file.as_str()).collect();
+        assert_eq!(failed, vec!["careerSavegame.xml", "farms.xml"]);
+        assert!(result.data.farms.is_empty());
+        assert_eq!(result.data.career.savegame_name, "");
+        assert_eq!(result.data.fields.len(), 4);
+        assert!(!result.data.vehicles.is_empty());
+        cleanup_writable_fixture(&path);
+    }
+
     fn modded_fixture_path() -> String {
         PathBuf::from(env!("CARGO_MANIFEST_DIR"))
             .join("tests")
@@ -931,18 +2487,9 @@ mod tests {
             finance: Some(crate::models::changes::FinanceChanges {
                 money: Some(999999.0),
                 loan: None,
+                loan_interest_rate: None,
             }),
-            vehicles: None,
-            sales: None,
-            sale_additions: None,
-            fields: None,
-            farmlands: None,
-            placeables: None,
-            missions: None,
-            collectibles: None,
-            contract_settings: None,
-            environment: None,
-            economy: None,
+            ..Default::default()
         };
         save_changes(path.clone(), changes).unwrap();
 
@@ -958,4 +2505,910 @@ mod tests {
 
         cleanup_writable_fixture(&path);
     }
+
+    #[test]
+    fn test_rename_savegame() {
+        let path = setup_writable_fixture("rename");
+        let summary = rename_savegame(path.clone(), "  Ferme & Forêt  ".to_string()).unwrap();
+        assert_eq!(summary.name, "Ferme & Forêt");
+
+        let data = load_savegame(path.clone()).unwrap();
+        assert_eq!(data.career.savegame_name, "Ferme & Forêt");
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_rename_savegame_rejects_empty_name() {
+        let path = setup_writable_fixture("rename_empty");
+        let result = rename_savegame(path.clone(), "   ".to_string());
+        assert!(matches!(result, Err(AppError::Generic(_))));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_apply_changes_to_saves_reports_per_path() {
+        let first = setup_writable_fixture("batch_a");
+        let second = setup_writable_fixture("batch_b");
+        let missing = "/nonexistent/batch/save".to_string();
+
+        let changes = SavegameChanges {
+            finance: Some(crate::models::changes::FinanceChanges {
+                money: Some(4242.0),
+                loan: None,
+                loan_interest_rate: None,
+            }),
+            ..Default::default()
+        };
+        let results = apply_changes_to_saves(
+            vec![first.clone(), missing.clone(), second.clone(), first.clone()],
+            changes,
+        );
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].path, first);
+        assert!(results[0].result.success);
+        assert!(results[0].result.backup_path.is_some());
+        assert_eq!(results[1].path, missing);
+        assert!(!results[1].result.success);
+        assert_eq!(results[1].result.errors[0].code, "errors.batchSaveFailed");
+        assert!(results[2].result.success);
+
+        for path in [&first, &second] {
+            let data = load_savegame(path.clone()).unwrap();
+            assert!((data.career.money - 4242.0).abs() < 0.01);
+        }
+
+        cleanup_writable_fixture(&first);
+        cleanup_writable_fixture(&second);
+    }
+
+    fn finance_changes(money: f64) -> SavegameChanges {
+        SavegameChanges {
+            finance: Some(crate::models::changes::FinanceChanges {
+                money: Some(money),
+                loan: None,
+                loan_interest_rate: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_has_unsaved_changes_same_value_is_clean() {
+        let result = has_unsaved_changes(complete_fixture_path(), finance_changes(1000000.0)).unwrap();
+        assert!(!result.has_changes);
+        assert!(result.files.is_empty());
+    }
+
+    #[test]
+    fn test_has_unsaved_changes_lists_files() {
+        let path = setup_writable_fixture("dirty_check");
+        let before = std::fs::read_to_string(PathBuf::from(&path).join("careerSavegame.xml")).unwrap();
+
+        let result = has_unsaved_changes(path.clone(), finance_changes(1234.0)).unwrap();
+        assert!(result.has_changes);
+        assert!(result.files.contains(&"careerSavegame.xml".to_string()));
+        assert!(result.files.contains(&"farms.xml".to_string()));
+
+        // The savegame itself is never written, nor backed up
+        let after = std::fs::read_to_string(PathBuf::from(&path).join("careerSavegame.xml")).unwrap();
+        assert_eq!(before, after);
+        assert!(backup_manager::list_backups(&PathBuf::from(&path)).unwrap().is_empty());
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_has_unsaved_changes_resolves_file_variants() {
+        let path = setup_writable_fixture("dirty_check_variant");
+        let dir = PathBuf::from(&path);
+        std::fs::rename(dir.join(ECONOMY), dir.join("Economy.XML")).unwrap();
+        let demand_changes = |multiplier: f64| SavegameChanges {
+            economy: Some(EconomyChanges {
+                great_demand_changes: Some(vec![GreatDemandChange {
+                    index: 0,
+                    unique_id: None,
+                    fill_type_name: None,
+                    demand_multiplier: Some(multiplier),
+                    demand_start_day: None,
+                    demand_start_hour: None,
+                    demand_duration: None,
+                    is_running: None,
+                    is_valid: None,
+                }]),
+                great_demand_additions: None,
+                great_demand_deletions: None,
+            }),
+            ..Default::default()
+        };
+
+        let result = has_unsaved_changes(path.clone(), demand_changes(1.1)).unwrap();
+        assert!(!result.has_changes);
+        assert!(result.errors.is_empty());
+
+        let result = has_unsaved_changes(path.clone(), demand_changes(2.0)).unwrap();
+        assert!(result.has_changes);
+        assert_eq!(result.files, vec![ECONOMY.to_string()]);
+        assert!(result.errors.is_empty());
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_has_unsaved_changes_counts_failed_writer() {
+        let path = setup_writable_fixture("dirty_check_failed");
+        std::fs::remove_file(PathBuf::from(&path).join(FARMS)).unwrap();
+
+        // Same money as on disk, but farms.xml cannot be written to compare it
+        let result = has_unsaved_changes(path.clone(), finance_changes(1000000.0)).unwrap();
+        assert!(result.has_changes);
+        assert!(result.files.is_empty());
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.errors[0].code, "errors.fileWriteError");
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_freeze_and_expire_dealer() {
+        let path = setup_writable_fixture("freeze_dealer");
+
+        assert_eq!(freeze_dealer(path.clone()).unwrap(), 2);
+        let sales = parse_sales(Path::new(&path)).unwrap();
+        assert!(sales.iter().all(|s| s.time_left == FROZEN_DEALER_TIME_LEFT));
+        // Already frozen: nothing left to change
+        assert_eq!(freeze_dealer(path.clone()).unwrap(), 0);
+
+        assert_eq!(expire_dealer(path.clone()).unwrap(), 2);
+        let sales = parse_sales(Path::new(&path)).unwrap();
+        assert!(sales.iter().all(|s| s.time_left == EXPIRING_DEALER_TIME_LEFT));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_normalize_dealer_resets_used_items() {
+        let path = setup_writable_fixture("normalize_dealer");
+        let before = parse_sales(&PathBuf::from(&path)).unwrap();
+
+        let count = normalize_dealer(path.clone(), None).unwrap();
+        assert_eq!(count, before.len());
+
+        let after = parse_sales(&PathBuf::from(&path)).unwrap();
+        assert_eq!(after.len(), before.len());
+        for (old, new) in before.iter().zip(after.iter()) {
+            assert_eq!(new.damage, 0.0);
+            assert_eq!(new.wear, 0.0);
+            assert_eq!(new.age, 0);
+            assert_eq!(new.operating_time, 0.0);
+            assert_eq!(new.price, old.price);
+            assert_eq!(new.bought_configurations.len(), old.bought_configurations.len());
+        }
+
+        // Nothing left to normalize
+        assert_eq!(normalize_dealer(path.clone(), None).unwrap(), 0);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_ensure_xml_header_repairs_file() {
+        let path = setup_writable_fixture("ensure_xml_header");
+        let farms = PathBuf::from(&path).join("farms.xml");
+        let content = std::fs::read_to_string(&farms).unwrap();
+        std::fs::write(&farms, &content[content.find("<farms>").unwrap()..]).unwrap();
+
+        assert!(ensure_xml_header(path.clone(), "farms.xml".to_string()).unwrap());
+        assert!(std::fs::read_to_string(&farms).unwrap().starts_with("<?xml version=\"1.0\""));
+        assert!(!ensure_xml_header(path.clone(), "farms.xml".to_string()).unwrap());
+
+        let result = ensure_xml_header(path.clone(), "../farms.xml".to_string());
+        assert!(matches!(result, Err(AppError::Generic(_))));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_set_time_of_day() {
+        let path = setup_writable_fixture("set_time_of_day");
+        let save_path = PathBuf::from(&path);
+        let day_before = parse_environment(&save_path).unwrap().current_day;
+
+        assert!(matches!(
+            set_time_of_day(path.clone(), 24, 0),
+            Err(AppError::Generic(_))
+        ));
+        assert!(matches!(
+            set_time_of_day(path.clone(), 12, 60),
+            Err(AppError::Generic(_))
+        ));
+
+        set_time_of_day(path.clone(), 14, 30).unwrap();
+        let env = parse_environment(&save_path).unwrap();
+        assert!((env.day_time - 52200.0).abs() < 0.01);
+        assert_eq!(env.current_day, day_before);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_add_money() {
+        let path = setup_writable_fixture("add_money");
+        let save_path = PathBuf::from(&path);
+
+        assert_eq!(add_money(path.clone(), 1, 250000.0).unwrap(), 1250000.0);
+        assert_eq!(parse_farms(&save_path).unwrap()[0].money, 1250000.0);
+        assert_eq!(parse_career(&save_path).unwrap().money, 1250000.0);
+
+        assert_eq!(add_money(path.clone(), 1, 1e12).unwrap(), MAX_MONEY);
+        assert!(matches!(
+            add_money(path.clone(), 9, 1.0),
+            Err(AppError::Generic(_))
+        ));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_rename_player() {
+        let path = setup_writable_fixture("rename_player");
+        let save_path = PathBuf::from(&path);
+
+        rename_player(
+            path.clone(),
+            "player1-uid".to_string(),
+            "  Ferme l'Étang & Fils  ".to_string(),
+        )
+        .unwrap();
+        let farm = &parse_farms(&save_path).unwrap()[0];
+        assert_eq!(farm.players[0].last_nickname, "Ferme l'Étang & Fils");
+        assert_eq!(farm.name, "My Farm");
+
+        for (user_id, name) in [("player1-uid", "  "), ("nobody", "Name")] {
+            assert!(matches!(
+                rename_player(path.clone(), user_id.to_string(), name.to_string()),
+                Err(AppError::Generic(_))
+            ));
+        }
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_set_statistic() {
+        let path = setup_writable_fixture("set_statistic");
+        let save_path = PathBuf::from(&path);
+        let before = parse_farms(&save_path).unwrap()[0].statistics.clone();
+
+        set_statistic(path.clone(), 1, "workedHectares".to_string(), 1500.0).unwrap();
+        set_statistic(path.clone(), 1, "baleCount".to_string(), 1000.0).unwrap();
+
+        let after = &parse_farms(&save_path).unwrap()[0].statistics;
+        assert_eq!(after.worked_hectares, 1500.0);
+        assert_eq!(after.bale_count, 1000);
+        assert_eq!(after.threshed_hectares, before.threshed_hectares);
+        assert_eq!(after.mission_count, before.mission_count);
+        assert_eq!(after.play_time, before.play_time);
+
+        for (name, value) in [
+            ("unknownStat", 1.0),
+            ("baleCount", 1.5),
+            ("workedHectares", -1.0),
+            ("workedHectares", f64::NAN),
+        ] {
+            assert!(matches!(
+                set_statistic(path.clone(), 1, name.to_string(), value),
+                Err(AppError::Generic(_))
+            ));
+        }
+        assert!(matches!(
+            set_statistic(path.clone(), 9, "baleCount".to_string(), 1.0),
+            Err(AppError::Generic(_))
+        ));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_make_farm_manager() {
+        let path = setup_writable_fixture("make_farm_manager");
+        let save_path = PathBuf::from(&path);
+        let farms_xml = save_path.join("farms.xml");
+        let content = std::fs::read_to_string(&farms_xml).unwrap().replace(
+            "</players>",
+            r#"  <player uniqueUserId="helper-uid" farmManager="false" lastNickname="Helper" buyVehicle="false" sellVehicle="false" manageContracts="false" />
+      <player uniqueUserId="guest-uid" farmManager="false" lastNickname="Guest" buyVehicle="false" manageContracts="false" />
+    </players>"#,
+        );
+        std::fs::write(&farms_xml, &content).unwrap();
+
+        make_farm_manager(path.clone(), 1, "helper-uid".to_string()).unwrap();
+
+        let players = &parse_farms(&save_path).unwrap()[0].players;
+        let helper = &players[1];
+        assert!(helper.farm_manager && helper.buy_vehicle && helper.sell_vehicle);
+        assert!(helper.manage_contracts && helper.manage_rights && helper.transfer_money);
+        let guest = &players[2];
+        assert!(!guest.farm_manager && !guest.buy_vehicle && !guest.manage_contracts);
+        assert!(players[0].farm_manager);
+
+        assert!(matches!(
+            make_farm_manager(path.clone(), 1, "nobody".to_string()),
+            Err(AppError::Generic(_))
+        ));
+        assert!(matches!(
+            make_farm_manager(path.clone(), 2, "helper-uid".to_string()),
+            Err(AppError::Generic(_))
+        ));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_pay_off_loans() {
+        let path = setup_writable_fixture("pay_off_loans");
+        let save_path = PathBuf::from(&path);
+        let farms_xml = save_path.join("farms.xml");
+        let content = std::fs::read_to_string(&farms_xml).unwrap();
+        std::fs::write(
+            &farms_xml,
+            content.replace(
+                "</farms>",
+                r#"<farm farmId="2" name="Co-op" color="2" loan="80000" money="30000"></farm></farms>"#,
+            ),
+        )
+        .unwrap();
+
+        let payoffs = pay_off_loans(path.clone(), false, false).unwrap();
+        assert_eq!(payoffs.len(), 2);
+        assert!(payoffs[0].paid);
+        assert_eq!(payoffs[0].money_after, 1000000.0 - 50000.0);
+        // Farm 2 cannot afford its loan
+        assert!(!payoffs[1].paid);
+        assert_eq!(payoffs[1].loan_after, 80000.0);
+
+        let farms = parse_farms(&save_path).unwrap();
+        assert_eq!(farms[0].loan, 0.0);
+        assert_eq!(farms[1].loan, 80000.0);
+        assert_eq!(parse_career(&save_path).unwrap().money, 950000.0);
+
+        let payoffs = pay_off_loans(path.clone(), false, true).unwrap();
+        assert_eq!(payoffs.len(), 1);
+        assert_eq!(payoffs[0].money_after, -50000.0);
+        assert_eq!(parse_farms(&save_path).unwrap()[1].money, -50000.0);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_set_difficulty() {
+        let path = setup_writable_fixture("set_difficulty");
+        let save_path = PathBuf::from(&path);
+
+        let note = set_difficulty(path.clone(), "hard".to_string()).unwrap();
+        assert_eq!(note.code, "difficulty.changeNote");
+        assert_eq!(parse_career(&save_path).unwrap().economic_difficulty, "HARD");
+
+        set_difficulty(path.clone(), "EASY".to_string()).unwrap();
+        assert_eq!(parse_career(&save_path).unwrap().economic_difficulty, "EASY");
+
+        let result = set_difficulty(path.clone(), "NIGHTMARE".to_string());
+        assert!(matches!(result, Err(AppError::Generic(_))));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_stop_all_workers() {
+        let path = setup_writable_fixture("stop_all_workers");
+
+        assert_eq!(stop_all_workers(path.clone(), 2).unwrap(), 0);
+        assert_eq!(stop_all_workers(path.clone(), 1).unwrap(), 1);
+
+        let vehicles = parse_vehicles(&PathBuf::from(&path)).unwrap();
+        assert!(vehicles.iter().all(|v| v.worker_task.is_none()));
+        assert_eq!(stop_all_workers(path.clone(), 1).unwrap(), 0);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_transfer_all_vehicles() {
+        let path = setup_writable_fixture("transfer_all_vehicles");
+        let save_path = PathBuf::from(&path);
+        let farms_xml = save_path.join("farms.xml");
+        let content = std::fs::read_to_string(&farms_xml).unwrap();
+        std::fs::write(
+            &farms_xml,
+            content.replace(
+                "</farms>",
+                r#"<farm farmId="2" name="Co-op" color="2" loan="0" money="0"></farm></farms>"#,
+            ),
+        )
+        .unwrap();
+
+        assert!(matches!(
+            transfer_all_vehicles(path.clone(), 1, 7, false),
+            Err(AppError::Generic(_))
+        ));
+        // The rented trailer stays behind
+        assert_eq!(transfer_all_vehicles(path.clone(), 1, 2, false).unwrap(), 2);
+        let vehicles = parse_vehicles(&save_path).unwrap();
+        let farm_of = |id: &str| vehicles.iter().find(|v| v.unique_id == id).unwrap().farm_id;
+        assert_eq!(farm_of("vehicle0001"), 2);
+        assert_eq!(farm_of("vehicle0002"), 2);
+        assert_eq!(farm_of("vehicle0003"), 1);
+
+        assert_eq!(transfer_all_vehicles(path.clone(), 1, 2, true).unwrap(), 1);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_reset_to_fresh() {
+        let path = setup_writable_fixture("reset_to_fresh");
+        let save_path = PathBuf::from(&path);
+        let keep = FreshStartKeep {
+            vehicles: false,
+            land: true,
+            statistics: false,
+            starting_money: None,
+        };
+
+        let fresh = reset_to_fresh(path.clone(), keep).unwrap();
+        assert!(fresh.save.result.success);
+        assert!(fresh.save.result.backup_path.is_some());
+        assert_eq!(fresh.starting_money, 1_000_000.0);
+        assert_eq!(fresh.vehicles_removed, 3);
+        assert_eq!(fresh.farmlands_released, 0);
+        assert!(fresh.statistics_reset);
+
+        let farm = &parse_farms(&save_path).unwrap()[0];
+        assert!((farm.money - 1_000_000.0).abs() < 0.01);
+        assert_eq!(farm.loan, 0.0);
+        assert_eq!(farm.statistics.revenue, 0.0);
+        assert!(parse_vehicles(&save_path).unwrap().is_empty());
+        let owned = parse_farmlands(&save_path).unwrap().iter().filter(|f| f.farm_id == 1).count();
+        assert_eq!(owned, 3);
+
+        let keep_all_but_land = FreshStartKeep {
+            vehicles: true,
+            land: false,
+            statistics: true,
+            starting_money: Some(250_000.0),
+        };
+        let fresh = reset_to_fresh(path.clone(), keep_all_but_land).unwrap();
+        assert_eq!(fresh.farmlands_released, 3);
+        assert!(!fresh.statistics_reset);
+        assert!(parse_farmlands(&save_path).unwrap().iter().all(|f| f.farm_id != 1));
+        assert!((parse_farms(&save_path).unwrap()[0].money - 250_000.0).abs() < 0.01);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_scale_fleet_wear() {
+        let path = setup_writable_fixture("scale_fleet_wear");
+        let save_path = PathBuf::from(&path);
+
+        assert!(matches!(
+            scale_fleet_wear(path.clone(), 1.5),
+            Err(AppError::Generic(_))
+        ));
+        let scaling = scale_fleet_wear(path.clone(), 0.5).unwrap();
+        assert_eq!(scaling.before.vehicle_count, 3);
+        assert!(
+            (scaling.after.total_operating_time - scaling.before.total_operating_time / 2.0).abs()
+                < 1e-6
+        );
+        assert!((scaling.after.average_age - scaling.before.average_age / 2.0).abs() < 1e-6);
+
+        let vehicles = parse_vehicles(&save_path).unwrap();
+        let tractor = vehicles.iter().find(|v| v.unique_id == "vehicle0001").unwrap();
+        assert!((tractor.age - 12.5).abs() < 1e-6);
+        assert!((tractor.operating_time - 150.5 / 3600.0 / 2.0).abs() < 1e-6);
+        assert!((tractor.damage - 0.025).abs() < 1e-6);
+        assert!((tractor.wear - 0.06).abs() < 1e-6);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_refresh_all_demands() {
+        let path = setup_writable_fixture("refresh_all_demands");
+        let save_path = PathBuf::from(&path);
+        let economy_xml = save_path.join("economy.xml");
+        let content = std::fs::read_to_string(&economy_xml).unwrap();
+        // The FLOUR demand ended long before the current day 54
+        std::fs::write(
+            &economy_xml,
+            content.replace(r#"demandStartDay="54""#, r#"demandStartDay="30""#),
+        )
+        .unwrap();
+        let data = load_savegame(path.clone()).unwrap();
+        assert!(data.warnings.iter().any(|w| w.code == "errors.validation.staleGreatDemand"));
+
+        assert!(matches!(
+            refresh_all_demands(path.clone(), 56, 0, true),
+            Err(AppError::Generic(_))
+        ));
+        assert_eq!(refresh_all_demands(path.clone(), 56, 48, true).unwrap(), 1);
+        let demands = parse_economy(&save_path).unwrap().great_demands;
+        let flour = demands.iter().find(|d| d.fill_type_name == "FLOUR").unwrap();
+        assert_eq!((flour.demand_start_day, flour.demand_duration), (56, 48));
+        let seeds = demands.iter().find(|d| d.fill_type_name == "SEEDS").unwrap();
+        assert_eq!(seeds.demand_start_day, 55);
+        let data = load_savegame(path.clone()).unwrap();
+        assert!(data.warnings.is_empty());
+
+        // Both demands, the empty slots left alone
+        assert_eq!(refresh_all_demands(path.clone(), 60, 24, false).unwrap(), 2);
+        let content = std::fs::read_to_string(&economy_xml).unwrap();
+        assert_eq!(content.matches("<greatDemand/>").count(), 2);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_prepare_field() {
+        let path = setup_writable_fixture("prepare_field");
+        let save_path = PathBuf::from(&path);
+
+        assert!(matches!(
+            prepare_field(path.clone(), 99, vec![FieldOperation::Plow]),
+            Err(AppError::Generic(_))
+        ));
+        assert!(matches!(
+            prepare_field(path.clone(), 3, vec![]),
+            Err(AppError::Generic(_))
+        ));
+        prepare_field(
+            path.clone(),
+            3,
+            vec![FieldOperation::Plow, FieldOperation::Lime],
+        )
+        .unwrap();
+
+        let fields = parse_fields(&save_path).unwrap();
+        let field = fields.iter().find(|f| f.id == 3).unwrap();
+        assert_eq!(field.ground_type, "PLOWED");
+        assert_eq!(field.fruit_type, "UNKNOWN");
+        assert_eq!(field.plow_level, 1);
+        assert_eq!(field.lime_level, 3);
+        // Not fertilized
+        assert_eq!(field.spray_level, 0);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_repair_field_states() {
+        let path = setup_writable_fixture("repair_field_states");
+        let save_path = PathBuf::from(&path);
+        assert!(repair_field_states(path.clone()).unwrap().is_empty());
+
+        // Harvest-ready wheat stuck at growth state 0
+        let fields_xml = save_path.join("fields.xml");
+        let content = std::fs::read_to_string(&fields_xml).unwrap().replace(
+            r#"fruitType="WHEAT" growthState="10""#,
+            r#"fruitType="WHEAT" growthState="0""#,
+        );
+        std::fs::write(&fields_xml, content).unwrap();
+
+        assert_eq!(repair_field_states(path.clone()).unwrap(), vec![1]);
+        let fields = parse_fields(&save_path).unwrap();
+        let field = fields.iter().find(|f| f.id == 1).unwrap();
+        assert_eq!(field.ground_type, "STUBBLE_TILLAGE");
+        assert_eq!(field.fruit_type, "UNKNOWN");
+        assert!(!has_incoherent_growth_state(field));
+        // Coherent fields are left alone
+        let canola = fields.iter().find(|f| f.id == 3).unwrap();
+        assert_eq!(canola.fruit_type, "CANOLA");
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_replace_crop() {
+        let path = setup_writable_fixture("replace_crop");
+        let save_path = PathBuf::from(&path);
+
+        assert!(matches!(
+            replace_crop(path.clone(), "CANOLA".to_string(), "BOGUS".to_string()),
+            Err(AppError::Generic(_))
+        ));
+        // Priced in economy.xml, but a product rather than a crop
+        let economy_xml = save_path.join(ECONOMY);
+        let content = std::fs::read_to_string(&economy_xml).unwrap();
+        let milk = r#"<fillType fillType="MILK" totalAmount="500"></fillType>"#;
+        std::fs::write(&economy_xml, content.replace(r#"<fillType fillType="UNKNOWN"/>"#, milk))
+            .unwrap();
+        let economy = parse_economy(&save_path).unwrap();
+        assert!(economy.fill_types.iter().any(|ft| ft.fill_type == "MILK"));
+        assert!(matches!(
+            replace_crop(path.clone(), "CANOLA".to_string(), "MILK".to_string()),
+            Err(AppError::Generic(_))
+        ));
+        assert_eq!(
+            replace_crop(path.clone(), "canola".to_string(), "soybean".to_string()).unwrap(),
+            1
+        );
+        // Field 2 only plans barley
+        assert_eq!(
+            replace_crop(path.clone(), "BARLEY".to_string(), "OAT".to_string()).unwrap(),
+            1
+        );
+        let fields = parse_fields(&save_path).unwrap();
+        let field = |id: u32| fields.iter().find(|f| f.id == id).unwrap();
+        assert_eq!(field(3).fruit_type, "SOYBEAN");
+        assert_eq!(field(2).planned_fruit, "OAT");
+        assert_eq!(field(2).fruit_type, "UNKNOWN");
+
+        assert_eq!(
+            replace_crop(path.clone(), "RYE".to_string(), "OAT".to_string()).unwrap(),
+            0
+        );
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_sell_vehicle_group() {
+        let path = setup_writable_fixture("sell_vehicle_group");
+        let save_path = PathBuf::from(&path);
+        // The trailer behind the tractor is rented in the fixture
+        let vehicles_xml = save_path.join("vehicles.xml");
+        let content = std::fs::read_to_string(&vehicles_xml).unwrap();
+        std::fs::write(
+            &vehicles_xml,
+            content.replace(r#"propertyState="RENTED""#, r#"propertyState="OWNED""#),
+        )
+        .unwrap();
+        let sales_before = parse_sales(&save_path).unwrap().len();
+
+        let price_map = HashMap::from([("vehicle0003".to_string(), 12000)]);
+        let result =
+            sell_vehicle_group(path.clone(), "vehicle0001".to_string(), price_map).unwrap();
+        assert!(result.success, "{:?}", result.errors);
+        assert!(result.backup_path.is_some());
+
+        let vehicles = parse_vehicles(&save_path).unwrap();
+        assert_eq!(vehicles.len(), 1);
+        assert_eq!(vehicles[0].unique_id, "vehicle0002");
+
+        let sales = parse_sales(&save_path).unwrap();
+        assert_eq!(sales.len(), sales_before + 2);
+        let tractor = sales
+            .iter()
+            .find(|s| s.xml_filename.contains("fendt942Vario"))
+            .unwrap();
+        assert_eq!(tractor.price, 348000);
+        assert_eq!(tractor.age, 25);
+        assert!((tractor.operating_time - 150.5 / 3600.0).abs() < 1e-9);
+        let trailer = sales
+            .iter()
+            .find(|s| s.xml_filename.contains("krampeBandit750"))
+            .unwrap();
+        assert_eq!(trailer.price, 12000);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_sell_vehicle_group_rejects_rented() {
+        let path = setup_writable_fixture("sell_vehicle_group_rented");
+        let save_path = PathBuf::from(&path);
+
+        let result = sell_vehicle_group(path.clone(), "vehicle0001".to_string(), HashMap::new());
+        assert!(matches!(result, Err(AppError::Generic(_))));
+        assert_eq!(parse_vehicles(&save_path).unwrap().len(), 3);
+
+        let result = sell_vehicle_group(path.clone(), "vehicle9999".to_string(), HashMap::new());
+        assert!(matches!(result, Err(AppError::Generic(_))));
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_clear_loose_items() {
+        let path = setup_writable_fixture("clear_loose_items");
+        let save_path = PathBuf::from(&path);
+        let vehicles_xml = save_path.join("vehicles.xml");
+        let content = std::fs::read_to_string(&vehicles_xml).unwrap();
+        std::fs::write(
+            &vehicles_xml,
+            content.replace(
+                "</vehicles>",
+                r#"  <vehicle filename="data/objects/pallets/pioneer/pioneerPallet.xml" uniqueId="vehicle0004" farmId="1" />
+</vehicles>"#,
+            ),
+        )
+        .unwrap();
+        std::fs::write(
+            save_path.join("items.xml"),
+            r#"<items><item className="Bale" position="1 0 1" /></items>"#,
+        )
+        .unwrap();
+
+        let cleared = clear_loose_items(path.clone(), vec![LooseItemKind::Pallet]).unwrap();
+        assert_eq!(cleared, ClearedItems { bales: 0, pallets: 1 });
+        assert_eq!(parse_vehicles(&save_path).unwrap().len(), 3);
+
+        let cleared =
+            clear_loose_items(path.clone(), vec![LooseItemKind::Bale, LooseItemKind::Pallet])
+                .unwrap();
+        assert_eq!(cleared, ClearedItems { bales: 1, pallets: 0 });
+        cleanup_writable_fixture(&path);
+    }
+
+    /// A change set touching every writer, with index-based sale deletions left out.
+    fn representative_changes() -> SavegameChanges {
+        use crate::models::changes::{
+            CollectibleChange, ContractSettingsChange, EconomyChanges, EnvironmentChanges,
+            FarmChange, FarmlandChange, FieldChange, FillUnitChange, FinanceChanges, GreatDemandAddition,
+            GreatDemandChange, MissionChange, PlaceableChange, ProductionStockChange, SaleAddition,
+            TreeChanges,
+        };
+
+        SavegameChanges {
+            finance: Some(FinanceChanges {
+                money: Some(2500000.0),
+                loan: Some(0.0),
+                loan_interest_rate: None,
+            }),
+            farms: Some(vec![FarmChange {
+                farm_id: 1,
+                color: Some(5),
+            }]),
+            vehicles: Some(vec![VehicleChange {
+                unique_id: "vehicle0001".to_string(),
+                age: Some(0.0),
+                price: Some(300000.0),
+                operating_time: Some(10.0),
+                damage: Some(0.0),
+                wear: Some(0.0),
+                fill_units: Some(vec![FillUnitChange { index: 0, fill_level: 500.0 }]),
+                stop_worker: true,
+                ..Default::default()
+            }]),
+            sales: Some(vec![SaleChange {
+                index: 0,
+                delete: false,
+                price: Some(1000),
+                damage: Some(0.0),
+                wear: Some(0.0),
+                age: Some(0),
+                operating_time: Some(0.0),
+                time_left: None,
+            }]),
+            sale_additions: Some(vec![SaleAddition {
+                xml_filename: "data/vehicles/fendt/vario900/vario900.xml".to_string(),
+                price: 250000,
+                damage: 0.0,
+                wear: 0.0,
+                age: 0,
+                operating_time: 0.0,
+                time_left: 30,
+                bought_configurations: None,
+            }]),
+            fields: Some(vec![FieldChange {
+                id: 1,
+                fruit_type: Some("CANOLA".to_string()),
+                planned_fruit: None,
+                growth_state: Some(3),
+                ground_type: None,
+                weed_state: Some(0),
+                stone_level: None,
+                spray_level: Some(2),
+                spray_type: None,
+                lime_level: None,
+                plow_level: None,
+                roller_level: None,
+                stubble_shred_level: None,
+                water_level: None,
+            }]),
+            farmlands: Some(vec![FarmlandChange { id: 3, farm_id: 1 }]),
+            placeables: Some(vec![PlaceableChange {
+                index: 2,
+                farm_id: None,
+                price: Some(150000.0),
+                complete_construction: false,
+                production_inputs: Some(vec![ProductionStockChange {
+                    fill_type: "WHEAT".to_string(),
+                    amount: 3000.0,
+                }]),
+                production_outputs: None,
+                allow_overfill: false,
+            }]),
+            missions: Some(vec![MissionChange {
+                unique_id: "mission_harvest_001".to_string(),
+                reward: Some(9000.0),
+                completion: None,
+                status: None,
+                reimbursement: None,
+                deposited_liters: None,
+            }]),
+            collectibles: Some(vec![CollectibleChange { index: 1, collected: true, marked: None }]),
+            contract_settings: Some(ContractSettingsChange {
+                lease_vehicle: Some(1.0),
+                mission_per_farm: None,
+                allow_clear_add: None,
+            }),
+            environment: Some(EnvironmentChanges {
+                day_time: Some(21600.0),
+                current_day: Some(12),
+                snow_height: None,
+                ground_wetness: None,
+                weather_forecast: None,
+            }),
+            economy: Some(EconomyChanges {
+                great_demand_changes: Some(vec![GreatDemandChange {
+                    index: 0,
+                    unique_id: None,
+                    fill_type_name: None,
+                    demand_multiplier: Some(2.0),
+                    demand_start_day: None,
+                    demand_start_hour: None,
+                    demand_duration: None,
+                    is_running: None,
+                    is_valid: None,
+                }]),
+                great_demand_additions: Some(vec![GreatDemandAddition {
+                    unique_id: "sellingStationNew01".to_string(),
+                    fill_type_name: "WHEAT".to_string(),
+                    demand_multiplier: 1.25,
+                    demand_start_day: 10,
+                    demand_start_hour: 6,
+                    demand_duration: 48,
+                }]),
+                great_demand_deletions: None,
+            }),
+            trees: Some(TreeChanges { mature_indices: None }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_save_changes_only_files() {
+        let path = setup_writable_fixture("only_files");
+        let save_path = PathBuf::from(&path);
+        let environment_before =
+            std::fs::read_to_string(save_path.join("environment.xml")).unwrap();
+        let career_before = std::fs::read_to_string(save_path.join("careerSavegame.xml")).unwrap();
+
+        let mut changes = representative_changes();
+        changes.only_files = Some(vec!["vehicles.xml".to_string(), "farms.xml".to_string()]);
+        let result = save_changes(path.clone(), changes).unwrap();
+        assert!(result.success, "{:?}", result.errors);
+
+        let mut files = result.files_modified.clone();
+        files.sort();
+        assert_eq!(files, vec!["farms.xml", "vehicles.xml"]);
+        assert_eq!(
+            std::fs::read_to_string(save_path.join("environment.xml")).unwrap(),
+            environment_before
+        );
+        // Money is synced to farms.xml only
+        assert_eq!(
+            std::fs::read_to_string(save_path.join("careerSavegame.xml")).unwrap(),
+            career_before
+        );
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_apply_changes_twice_is_noop() {
+        let path = setup_writable_fixture("idempotent");
+
+        let first = save_changes(path.clone(), representative_changes()).unwrap();
+        assert!(first.success, "{:?}", first.errors);
+        assert!(first.files_modified.len() >= 10);
+
+        // Everything is already applied: a second run would write nothing new
+        let pending = has_unsaved_changes(path.clone(), representative_changes()).unwrap();
+        assert!(!pending.has_changes, "Second run changed {:?}", pending.files);
+        cleanup_writable_fixture(&path);
+    }
+
+    #[test]
+    fn test_apply_changes_twice_same_files() {
+        let path = setup_writable_fixture("idempotent_files");
+        let save_path = PathBuf::from(&path);
+
+        save_changes(path.clone(), representative_changes()).unwrap();
+        let snapshot: Vec<(PathBuf, String)> = std::fs::read_dir(&save_path)
+            .unwrap()
+            .flatten()
+            .map(|e| e.path())
+            .filter(|p| p.extension().is_some_and(|e| e == "xml"))
+            .map(|p| {
+                let content = std::fs::read_to_string(&p).unwrap();
+                (p, content)
+            })
+            .collect();
+
+        save_changes(path.clone(), representative_changes()).unwrap();
+        for (file, before) in snapshot {
+            let after = std::fs::read_to_string(&file).unwrap();
+            assert!(xml_equivalent(&before, &after), "{} changed on second run", file.display());
+        }
+        cleanup_writable_fixture(&path);
+    }
 }
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use crate::error::AppError;
+use crate::models::settings::{AppSettings, GamePathCheck};
+use crate::parsers::files::set_name_overrides;
+use crate::services::settings::{
+    export_settings as write_export, read_settings_export, settings_from_entries,
+    settings_to_entries, SETTINGS_STORE_FILE,
+};
+use crate::validators::path::check_game_path;
+
+fn store_error(e: tauri_plugin_store::Error) -> AppError {
+    AppError::SettingsError {
+        message: e.to_string(),
+    }
+}
+
+/// Export the app settings (not savegame data) to a JSON file.
+#[tauri::command]
+pub fn export_settings(app: AppHandle, destination: String) -> Result<(), AppError> {
+    let store = app.store(SETTINGS_STORE_FILE).map_err(store_error)?;
+    let settings = settings_from_entries(store.entries())?;
+    write_export(&PathBuf::from(destination), &settings)
+}
+
+/// Import settings from an exported JSON file. Nothing is written unless the whole file is valid.
+/// Returns the imported settings so the frontend can reload its state.
+#[tauri::command]
+pub fn import_settings(app: AppHandle, source: String) -> Result<AppSettings, AppError> {
+    let settings = read_settings_export(&PathBuf::from(source))?;
+    let store = app.store(SETTINGS_STORE_FILE).map_err(store_error)?;
+    for (key, value) in settings_to_entries(&settings)? {
+        store.set(key, value);
+    }
+    store.save().map_err(store_error)?;
+    Ok(settings)
+}
+
+/// Check a game installation path before saving it, listing what is missing so
+/// the settings page can explain why vehicle images and the catalog would fail.
+#[tauri::command]
+pub fn validate_game_path(path: String) -> GamePathCheck {
+    check_game_path(&path)
+}
+
+/// Use `names` (expected name → name to use) for the savegame files from now on,
+/// e.g. the `saveFileNames` setting at startup. An empty map restores the defaults.
+#[tauri::command]
+pub fn set_save_file_names(names: HashMap<String, String>) -> Result<(), AppError> {
+    set_name_overrides(names)
+}
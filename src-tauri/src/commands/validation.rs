@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use crate::error::AppError;
+use crate::models::validation::{ValidatorInfo, ValidatorReport};
+use crate::services::density_map::map_farmland_ids;
+use crate::services::production::read_production_recipes;
+use crate::validators::path::validate_game_path;
+use crate::validators::savegame::{
+    list_checks, run_checks, validate_field_farmland_links_with_map,
+    validate_production_fill_types_with_recipes,
+};
+
+use super::savegame::read_savegame;
+
+#[tauri::command]
+pub fn list_validators() -> Vec<ValidatorInfo> {
+    list_checks()
+}
+
+/// Parse the savegame and run only the selected validators, each once. When
+/// `game_path` is given, fields are also checked against the farmlands of the map
+/// data and production storages against the recipes of base game placeables.
+#[tauri::command]
+pub fn run_validators(
+    path: String,
+    ids: Vec<String>,
+    game_path: Option<String>,
+) -> Result<Vec<ValidatorReport>, AppError> {
+    let data = read_savegame(path)?;
+    let mut reports = run_checks(&data, &ids)?;
+
+    if let Some(game_path) = game_path {
+        if let Some(report) = reports.iter_mut().find(|r| r.id == "fieldFarmlandLinks") {
+            let map_ids = validate_game_path(&game_path)
+                .and_then(|game_path| map_farmland_ids(&game_path, &data.career.map_id))
+                .ok();
+            report.diagnostics.clear();
+            validate_field_farmland_links_with_map(&data, map_ids.as_ref(), &mut report.diagnostics);
+        }
+
+        let game_dir = validate_game_path(&game_path).ok();
+        if let (Some(game_dir), Some(report)) = (
+            game_dir,
+            reports.iter_mut().find(|r| r.id == "productionFillTypes"),
+        ) {
+            let mut recipes = HashMap::new();
+            for placeable in &data.placeables {
+                if recipes.contains_key(&placeable.filename) {
+                    continue;
+                }
+                if let Some(recipe) = read_production_recipes(&game_dir, &placeable.filename) {
+                    recipes.insert(placeable.filename.clone(), recipe);
+                }
+            }
+            report.diagnostics.clear();
+            validate_production_fill_types_with_recipes(&data, &recipes, &mut report.diagnostics);
+        }
+    }
+
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn complete_fixture_path() -> String {
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_complete")
+            .display()
+            .to_string()
+    }
+
+    #[test]
+    fn test_run_all_validators_on_fixture() {
+        let ids: Vec<String> = list_validators().into_iter().map(|v| v.id).collect();
+        let reports = run_validators(complete_fixture_path(), ids.clone(), None).unwrap();
+        assert_eq!(reports.len(), ids.len());
+        assert!(reports.iter().all(|r| r.diagnostics.is_empty()));
+    }
+
+    #[test]
+    fn test_run_validators_without_map_data() {
+        let ids = vec!["fieldFarmlandLinks".to_string()];
+        let reports =
+            run_validators(complete_fixture_path(), ids, Some("/nonexistent".to_string())).unwrap();
+        assert_eq!(reports[0].diagnostics.len(), 1);
+        assert_eq!(
+            reports[0].diagnostics[0].code,
+            "errors.validation.mapFarmlandsUnavailable"
+        );
+    }
+}
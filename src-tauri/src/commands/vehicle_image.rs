@@ -4,7 +4,7 @@ use serde::Serialize;
 use tauri::State;
 
 use crate::error::AppError;
-use crate::services::vehicle_image::VehicleImageService;
+use crate::services::vehicle_image::{ImageDiagnosis, VehicleImageService};
 use crate::validators::path::validate_game_path;
 
 /// Get the FS25 user profile mods directory.
@@ -100,6 +100,27 @@ pub async fn get_vehicle_images_batch(
     })?
 }
 
+/// Explain how the image of one vehicle resolves, or why it does not. `mods_dir`
+/// defaults to the profile mods directory.
+#[tauri::command]
+pub async fn diagnose_vehicle_image(
+    game_path: String,
+    mods_dir: Option<String>,
+    filename: String,
+    state: State<'_, VehicleImageService>,
+) -> Result<ImageDiagnosis, AppError> {
+    let validated_path = validate_game_path(&game_path)?;
+    let service = state.inner().clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        let mods_dir = mods_dir.map(PathBuf::from).unwrap_or_else(get_mods_dir);
+        service.diagnose_image(&validated_path, &mods_dir, &filename)
+    })
+    .await
+    .map_err(|e| AppError::ImageError {
+        message: e.to_string(),
+    })
+}
+
 /// Clear the vehicle image cache and return bytes freed.
 #[tauri::command]
 pub async fn clear_image_cache(
@@ -113,6 +134,13 @@ pub async fn clear_image_cache(
         })?
 }
 
+/// Clear the in-memory image index only, keeping the converted images on disk.
+/// Returns the number of entries dropped.
+#[tauri::command]
+pub fn reset_image_index(state: State<'_, VehicleImageService>) -> Result<usize, AppError> {
+    Ok(state.reset_index())
+}
+
 /// Get the total size of the vehicle image cache in bytes.
 #[tauri::command]
 pub async fn get_image_cache_size(state: State<'_, VehicleImageService>) -> Result<u64, AppError> {
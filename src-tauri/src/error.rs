@@ -21,6 +21,15 @@ pub enum AppError {
     #[error("Density map error: {message}")]
     DensityMapError { message: String },
 
+    #[error("Settings error: {message}")]
+    SettingsError { message: String },
+
+    #[error("Truncated file: {file}")]
+    TruncatedFile { file: String },
+
+    #[error("Unsupported savegame version: {path} ({detected})")]
+    UnsupportedSaveVersion { path: String, detected: String },
+
     #[error("{0}")]
     Generic(String),
 }
@@ -77,6 +86,30 @@ impl Serialize for AppError {
                     &std::collections::HashMap::from([("message", message.as_str())]),
                 )?;
             }
+            AppError::SettingsError { message } => {
+                state.serialize_field("code", "errors.settingsError")?;
+                state.serialize_field(
+                    "params",
+                    &std::collections::HashMap::from([("message", message.as_str())]),
+                )?;
+            }
+            AppError::TruncatedFile { file } => {
+                state.serialize_field("code", "errors.truncatedFile")?;
+                state.serialize_field(
+                    "params",
+                    &std::collections::HashMap::from([("file", file.as_str())]),
+                )?;
+            }
+            AppError::UnsupportedSaveVersion { path, detected } => {
+                state.serialize_field("code", "errors.unsupportedSaveVersion")?;
+                state.serialize_field(
+                    "params",
+                    &std::collections::HashMap::from([
+                        ("path", path.as_str()),
+                        ("detected", detected.as_str()),
+                    ]),
+                )?;
+            }
             AppError::Generic(message) => {
                 state.serialize_field("code", "errors.unknown")?;
                 state.serialize_field(
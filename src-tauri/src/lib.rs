@@ -7,6 +7,14 @@ mod services;
 mod validators;
 mod writers;
 
+/// Parser entry points used by the benchmarks in `benches/`.
+#[cfg(feature = "bench")]
+pub mod bench {
+    pub use crate::parsers::economy::parse_economy;
+    pub use crate::parsers::placeable::parse_placeables;
+    pub use crate::parsers::vehicle::parse_vehicles;
+}
+
 use tauri::Manager;
 
 use commands::catalog::CatalogState;
@@ -34,21 +42,84 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             commands::savegame::list_savegames,
+            commands::savegame::list_savegames_multi,
             commands::savegame::load_savegame,
+            commands::savegame::load_savegame_safe,
             commands::savegame::save_changes,
+            commands::savegame::save_and_verify,
+            commands::savegame::apply_changes_to_saves,
+            commands::savegame::rename_savegame,
+            commands::savegame::set_guided_tour_completed,
+            commands::savegame::set_difficulty,
+            commands::savegame::set_time_of_day,
+            commands::savegame::add_money,
+            commands::savegame::pay_off_loans,
+            commands::savegame::reset_to_fresh,
+            commands::savegame::set_player_permissions,
+            commands::savegame::make_farm_manager,
+            commands::savegame::rename_player,
+            commands::savegame::set_statistic,
+            commands::savegame::open_savegame_folder,
+            commands::savegame::has_unsaved_changes,
+            commands::savegame::normalize_dealer,
+            commands::savegame::freeze_dealer,
+            commands::savegame::expire_dealer,
+            commands::savegame::refresh_all_demands,
+            commands::savegame::stop_all_workers,
+            commands::savegame::transfer_all_vehicles,
+            commands::savegame::scale_fleet_wear,
+            commands::savegame::replace_crop,
+            commands::savegame::prepare_field,
+            commands::savegame::repair_field_states,
+            commands::savegame::sell_vehicle_group,
+            commands::savegame::clear_loose_items,
+            commands::savegame::reset_selling_station_stats,
+            commands::savegame::ensure_xml_header,
             commands::backup::list_backups,
+            commands::backup::list_all_backups,
+            commands::backup::prune_all_backups,
             commands::backup::create_backup,
             commands::backup::restore_backup,
+            commands::backup::restore_game_backup,
             commands::backup::delete_backup,
+            commands::backup::export_backup_index,
+            commands::backup::import_backup_index,
             commands::backup::open_backups_folder,
             commands::vehicle_image::detect_game_path,
             commands::vehicle_image::get_vehicle_images_batch,
             commands::vehicle_image::clear_image_cache,
+            commands::vehicle_image::reset_image_index,
+            commands::vehicle_image::diagnose_vehicle_image,
             commands::vehicle_image::get_image_cache_size,
             commands::catalog::get_vehicle_catalog,
+            commands::catalog::stream_vehicle_catalog,
             commands::density::load_field_density_data,
             commands::density::save_density_edits,
             commands::update::check_for_updates,
+            commands::settings::export_settings,
+            commands::settings::import_settings,
+            commands::settings::validate_game_path,
+            commands::settings::set_save_file_names,
+            commands::validation::list_validators,
+            commands::validation::run_validators,
+            commands::analysis::compute_net_worth,
+            commands::analysis::project_finances,
+            commands::analysis::compute_vehicle_value,
+            commands::analysis::valid_fruit_types,
+            commands::analysis::list_active_workers,
+            commands::analysis::fuel_report,
+            commands::analysis::progress_summary,
+            commands::analysis::list_players,
+            commands::analysis::list_fields_detailed,
+            commands::analysis::field_ownership_summary,
+            commands::analysis::list_selling_stations,
+            commands::analysis::list_used_mods,
+            commands::analysis::list_missing_vehicle_mods,
+            commands::analysis::get_attachment_tree,
+            commands::analysis::analyze_save_size,
+            commands::analysis::analyze_landscaping,
+            commands::analysis::export_report,
+            commands::analysis::diff_against_latest_backup,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
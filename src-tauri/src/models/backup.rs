@@ -7,4 +7,68 @@ pub struct BackupInfo {
     pub path: String,
     pub created_at: String,
     pub size_bytes: u64,
+    /// True for backups written by the game itself into `savegameBackup/`.
+    /// These are read-only for the editor: they can be restored but never deleted.
+    #[serde(default)]
+    pub game_created: bool,
+}
+
+/// Exported list of the editor backups of a savegame, to carry their metadata
+/// along with the backup folders to another machine.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupIndex {
+    pub version: u32,
+    pub exported_at: String,
+    pub backups: Vec<BackupInfo>,
+}
+
+/// Backups of one savegame in the overview of a savegames folder. The savegame
+/// folder itself may be gone while its backups remain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavegameBackups {
+    pub savegame_path: String,
+    pub savegame_name: String,
+    pub savegame_exists: bool,
+    pub backups: Vec<BackupInfo>,
+    pub total_bytes: u64,
+}
+
+/// Backups of every savegame of a savegames folder, with their disk usage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupOverview {
+    pub savegames: Vec<SavegameBackups>,
+    pub total_bytes: u64,
+}
+
+/// Retention policy applied to the editor backups of every savegame at once.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupRetentionPolicy {
+    /// Disk space all the backups of the savegames folder may take, in bytes.
+    pub max_total_bytes: u64,
+    /// Allow deleting the last backup left for a savegame.
+    #[serde(default)]
+    pub allow_deleting_last: bool,
+}
+
+/// An editor backup deleted by a retention policy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrunedBackup {
+    pub savegame_path: String,
+    pub name: String,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PruneResult {
+    pub pruned: Vec<PrunedBackup>,
+    pub reclaimed_bytes: u64,
+    /// Size of the backups left, which stays above the budget when too few
+    /// backups could be deleted.
+    pub remaining_bytes: u64,
 }
@@ -5,20 +5,39 @@ use serde::{Deserialize, Serialize};
 pub struct SavegameSummary {
     pub path: String,
     pub name: String,
+    pub map_id: String,
     pub map_title: String,
+    /// Name to show for the map, see `map_display_name`
+    pub map_display: String,
+    pub is_mod_map: bool,
     pub money: f64,
     pub play_time: f64,
     pub save_date: String,
     pub economic_difficulty: String,
+    /// More than one player is registered across the farms (co-op save).
+    pub is_multiplayer: bool,
 }
 
+/// A savegame found while scanning several save folders, with the folder it came from.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct SavegameListing {
+    pub base_path: String,
+    pub summary: SavegameSummary,
+}
+
+/// The `Default` career is a blank placeholder, standing in for an unreadable
+/// careerSavegame.xml in `load_savegame_safe`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CareerSavegame {
     pub savegame_name: String,
     pub creation_date: String,
     pub map_id: String,
     pub map_title: String,
+    /// Name to show for the map, see `map_display_name`
+    pub map_display: String,
+    pub is_mod_map: bool,
     pub save_date: String,
     pub economic_difficulty: String,
     pub money: f64,
@@ -32,4 +51,106 @@ pub struct CareerSavegame {
     pub snow_enabled: bool,
     pub fuel_usage: u8,
     pub traffic_enabled: bool,
+    /// Whether the guided tour was completed or skipped. `None` when the save
+    /// has no `<guidedTour>` section.
+    pub guided_tour_completed: Option<bool>,
+    /// Income and expense factors implied by `economic_difficulty`.
+    pub economic_multipliers: EconomicMultipliers,
+}
+
+/// Factors the economic difficulty applies to the farm's income and expenses.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct EconomicMultipliers {
+    /// Applied to the price of crops and goods sold
+    pub income: f64,
+    /// Applied to purchase prices, wages and upkeep
+    pub expenses: f64,
+    /// True when the save stores its own factors instead of a difficulty preset
+    pub custom: bool,
+}
+
+impl EconomicMultipliers {
+    /// Preset factors of a difficulty level; unknown levels use NORMAL.
+    pub fn for_difficulty(difficulty: &str) -> Self {
+        let (income, expenses) = match difficulty.to_uppercase().as_str() {
+            "EASY" => (3.0, 0.6),
+            "HARD" => (1.0, 1.0),
+            _ => (1.8, 0.8),
+        };
+        EconomicMultipliers {
+            income,
+            expenses,
+            custom: false,
+        }
+    }
+}
+
+/// Names of the base game maps, by `mapId`.
+const KNOWN_MAPS: &[(&str, &str)] = &[
+    ("MapUS", "Riverbend Springs"),
+    ("MapEU", "Zielonka"),
+    ("MapAS", "Hutan Pantai"),
+];
+
+/// Whether `map_id` designates a mod map, stored as `<modName>.<mapId>`.
+pub fn is_mod_map(map_id: &str) -> bool {
+    map_id.contains('.')
+}
+
+/// Name of a base game map from its id, falling back to the `mapTitle` the save
+/// recorded for mod and unknown maps, then to the id itself.
+pub fn map_display_name(map_id: &str, map_title: &str) -> String {
+    KNOWN_MAPS
+        .iter()
+        .find(|(id, _)| id.eq_ignore_ascii_case(map_id))
+        .map(|(_, name)| name.to_string())
+        .or_else(|| (!map_title.is_empty()).then(|| map_title.to_string()))
+        .unwrap_or_else(|| map_id.to_string())
+}
+
+impl Default for EconomicMultipliers {
+    fn default() -> Self {
+        EconomicMultipliers::for_difficulty("NORMAL")
+    }
+}
+
+/// Money a restarted career begins with at a difficulty level, loan-free. Unknown
+/// levels use NORMAL.
+pub fn starting_money(difficulty: &str) -> f64 {
+    match difficulty.to_uppercase().as_str() {
+        "EASY" => 1_500_000.0,
+        "HARD" => 500_000.0,
+        _ => 1_000_000.0,
+    }
+}
+
+/// Numeric code the game stores in `<economicDifficulty>` for a difficulty name.
+pub fn economic_difficulty_code(difficulty: &str) -> Option<u8> {
+    match difficulty.to_uppercase().as_str() {
+        "EASY" => Some(1),
+        "NORMAL" => Some(2),
+        "HARD" => Some(3),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_display_name() {
+        assert_eq!(map_display_name("MapEU", "Whatever"), "Zielonka");
+        assert_eq!(
+            map_display_name("FS25_Pallegney.Pallegney", "Pallegney"),
+            "Pallegney"
+        );
+        assert_eq!(
+            map_display_name("FS25_Pallegney.Pallegney", ""),
+            "FS25_Pallegney.Pallegney"
+        );
+        assert!(is_mod_map("FS25_Pallegney.Pallegney"));
+        assert!(!is_mod_map("MapUS"));
+    }
 }
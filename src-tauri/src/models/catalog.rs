@@ -1,5 +1,9 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
 use serde::{Deserialize, Serialize};
 
+use super::vehicle::Vehicle;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CatalogVehicle {
@@ -17,3 +21,165 @@ pub enum VehicleSource {
     BaseGame,
     Mod(String),
 }
+
+/// A mod the savegame was last played with, as listed in careerSavegame.xml.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavegameMod {
+    pub mod_name: String,
+    pub title: String,
+    pub version: String,
+    /// The save needs the mod to load (it holds items of the mod)
+    pub required: bool,
+}
+
+/// An installed mod the savegame uses.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsedMod {
+    pub mod_name: String,
+    pub title: String,
+    pub required: bool,
+    /// Version the save was last played with
+    pub save_version: String,
+    /// Version of the mods folder, empty when its modDesc.xml has none
+    pub installed_version: String,
+    pub version_mismatch: bool,
+}
+
+/// The mods of the save that are installed, with `installed` mapping each mod
+/// name of the mods folder to its version. Unknown versions never mismatch.
+pub fn used_mods(save_mods: &[SavegameMod], installed: &HashMap<String, String>) -> Vec<UsedMod> {
+    save_mods
+        .iter()
+        .filter_map(|m| {
+            let installed_version = installed.get(&m.mod_name)?;
+            Some(UsedMod {
+                mod_name: m.mod_name.clone(),
+                title: m.title.clone(),
+                required: m.required,
+                save_version: m.version.clone(),
+                installed_version: installed_version.clone(),
+                version_mismatch: !m.version.is_empty()
+                    && !installed_version.is_empty()
+                    && m.version.trim() != installed_version.trim(),
+            })
+        })
+        .collect()
+}
+
+/// A vehicle of the save that comes from a mod.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffectedVehicle {
+    pub unique_id: String,
+    pub display_name: String,
+    pub farm_id: u8,
+}
+
+/// A mod farm vehicles of the save come from, whose vehicles the catalog lacks:
+/// the game drops them when loading the save.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissingVehicleMod {
+    pub mod_name: String,
+    /// The mod is installed, but no longer has these vehicles
+    pub installed: bool,
+    pub vehicles: Vec<AffectedVehicle>,
+}
+
+/// `filename` of a mod vehicle as `$moddir$ModName/path.xml`, the form of the
+/// catalog. Sale items store an absolute path into the mods folder instead.
+pub fn mod_vehicle_filename(filename: &str) -> Option<String> {
+    let normalized = filename.replace('\\', "/");
+    if let Some(index) = normalized.find("$moddir$") {
+        return Some(normalized[index..].to_string());
+    }
+    let (_, in_mods) = normalized.split_once("/mods/")?;
+    in_mods
+        .contains('/')
+        .then(|| format!("$moddir${}", in_mods))
+}
+
+/// The mods of the farm vehicles of the save that `catalog` does not have, by
+/// mod name, each with the vehicles lost with it.
+pub fn missing_vehicle_mods(
+    vehicles: &[Vehicle],
+    catalog: &[CatalogVehicle],
+) -> Vec<MissingVehicleMod> {
+    let known: HashSet<String> = catalog
+        .iter()
+        .map(|v| v.xml_filename.to_lowercase())
+        .collect();
+    let installed: HashSet<&str> = catalog
+        .iter()
+        .filter_map(|v| match v.source {
+            VehicleSource::Mod(ref name) => Some(name.as_str()),
+            VehicleSource::BaseGame => None,
+        })
+        .collect();
+
+    let mut missing: BTreeMap<String, Vec<AffectedVehicle>> = BTreeMap::new();
+    for vehicle in vehicles.iter().filter(|v| v.farm_id != 0) {
+        let Some(filename) = mod_vehicle_filename(&vehicle.filename) else {
+            continue;
+        };
+        if known.contains(&filename.to_lowercase()) {
+            continue;
+        }
+        let mod_name = filename["$moddir$".len()..]
+            .split('/')
+            .next()
+            .unwrap_or_default();
+        missing
+            .entry(mod_name.to_string())
+            .or_default()
+            .push(AffectedVehicle {
+                unique_id: vehicle.unique_id.clone(),
+                display_name: vehicle.display_name.clone(),
+                farm_id: vehicle.farm_id,
+            });
+    }
+
+    missing
+        .into_iter()
+        .map(|(mod_name, vehicles)| MissingVehicleMod {
+            installed: installed.contains(mod_name.as_str()),
+            mod_name,
+            vehicles,
+        })
+        .collect()
+}
+
+/// What a catalog entry is, for scoping the catalog to what is being added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CatalogKind {
+    /// Drivable: tractors, harvesters, trucks, loaders...
+    Vehicle,
+    /// Attached or trailed: tools, trailers, headers...
+    Implement,
+    Pallet,
+}
+
+impl CatalogKind {
+    /// Classify a store category (`tractorsL`, `sprayerVehicles`, `pallets`...).
+    /// Anything not recognized as drivable or a pallet is an implement.
+    pub fn from_category(category: &str) -> Self {
+        let category = category.to_lowercase();
+        if category.starts_with("pallet") || category.starts_with("bigbag") {
+            CatalogKind::Pallet
+        } else if category.starts_with("tractors")
+            || category.ends_with("harvesters")
+            || category.ends_with("vehicles")
+            || matches!(
+                category.as_str(),
+                "cars" | "trucks" | "wheelloaders" | "forklifts" | "forestryforwarders"
+            )
+        {
+            CatalogKind::Vehicle
+        } else {
+            CatalogKind::Implement
+        }
+    }
+}
@@ -1,11 +1,18 @@
 use serde::{Deserialize, Serialize};
 
 use super::common::LocalizedMessage;
+use super::sale::BoughtConfiguration;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Edits to apply to a savegame. Writers patch values in place, so applying the
+/// same changes twice leaves the files as the first run did. Additions are skipped
+/// when already listed (an identical sale item, a great demand with the same
+/// uniqueId). Sale deletions address items by position, so a second run deletes
+/// a different item.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SavegameChanges {
     pub finance: Option<FinanceChanges>,
+    pub farms: Option<Vec<FarmChange>>,
     pub vehicles: Option<Vec<VehicleChange>>,
     pub sales: Option<Vec<SaleChange>>,
     pub sale_additions: Option<Vec<SaleAddition>>,
@@ -17,6 +24,49 @@ pub struct SavegameChanges {
     pub contract_settings: Option<ContractSettingsChange>,
     pub environment: Option<EnvironmentChanges>,
     pub economy: Option<EconomyChanges>,
+    pub trees: Option<TreeChanges>,
+    /// Restrict the write to these files (`vehicles.xml`, `environment.xml`...);
+    /// changes for other files are left unwritten. `None` writes everything.
+    #[serde(default)]
+    pub only_files: Option<Vec<String>>,
+}
+
+impl SavegameChanges {
+    /// Whether `file` may be written, according to `only_files`.
+    pub fn is_selected(&self, file: &str) -> bool {
+        self.only_files
+            .as_ref()
+            .is_none_or(|files| files.iter().any(|f| f == file))
+    }
+
+    /// These changes without the ones targeting files left out of `only_files`.
+    /// Finance changes span careerSavegame.xml and farms.xml and are kept when
+    /// either is selected.
+    pub fn selected_only(&self) -> SavegameChanges {
+        fn keep<T: Clone>(changes: &SavegameChanges, value: &Option<T>, file: &str) -> Option<T> {
+            value.clone().filter(|_| changes.is_selected(file))
+        }
+
+        SavegameChanges {
+            finance: self.finance.clone().filter(|_| {
+                self.is_selected("careerSavegame.xml") || self.is_selected("farms.xml")
+            }),
+            farms: keep(self, &self.farms, "farms.xml"),
+            vehicles: keep(self, &self.vehicles, "vehicles.xml"),
+            sales: keep(self, &self.sales, "sales.xml"),
+            sale_additions: keep(self, &self.sale_additions, "sales.xml"),
+            fields: keep(self, &self.fields, "fields.xml"),
+            farmlands: keep(self, &self.farmlands, "farmland.xml"),
+            placeables: keep(self, &self.placeables, "placeables.xml"),
+            missions: keep(self, &self.missions, "missions.xml"),
+            collectibles: keep(self, &self.collectibles, "collectibles.xml"),
+            contract_settings: keep(self, &self.contract_settings, "r_contracts.xml"),
+            environment: keep(self, &self.environment, "environment.xml"),
+            economy: keep(self, &self.economy, "economy.xml"),
+            trees: keep(self, &self.trees, "treePlant.xml"),
+            only_files: self.only_files.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,10 +74,115 @@ pub struct SavegameChanges {
 pub struct FinanceChanges {
     pub money: Option<f64>,
     pub loan: Option<f64>,
+    pub loan_interest_rate: Option<f64>,
 }
 
+/// Edit of one farm's attributes in farms.xml, addressed by farm id.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct FarmChange {
+    pub farm_id: u8,
+    /// Index into the game's farm color palette, see `FARM_COLORS`
+    pub color: Option<u8>,
+}
+
+/// Manager status and permission flags to set on a farm player; `None` leaves
+/// the flag as it is. Field names match the `<player>` attributes of farms.xml.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerPermissionChanges {
+    pub farm_manager: Option<bool>,
+    pub buy_vehicle: Option<bool>,
+    pub sell_vehicle: Option<bool>,
+    pub buy_placeable: Option<bool>,
+    pub sell_placeable: Option<bool>,
+    pub manage_contracts: Option<bool>,
+    pub trade_animals: Option<bool>,
+    pub create_fields: Option<bool>,
+    pub landscaping: Option<bool>,
+    pub hire_assistant: Option<bool>,
+    pub reset_vehicle: Option<bool>,
+    pub manage_productions: Option<bool>,
+    pub cut_trees: Option<bool>,
+    pub manage_rights: Option<bool>,
+    pub transfer_money: Option<bool>,
+    pub update_farm: Option<bool>,
+    pub manage_contracting: Option<bool>,
+}
+
+impl PlayerPermissionChanges {
+    /// `<player>` attributes covered by these changes, in farms.xml order.
+    pub const ATTRIBUTES: [&'static str; 17] = [
+        "farmManager",
+        "buyVehicle",
+        "sellVehicle",
+        "buyPlaceable",
+        "sellPlaceable",
+        "manageContracts",
+        "tradeAnimals",
+        "createFields",
+        "landscaping",
+        "hireAssistant",
+        "resetVehicle",
+        "manageProductions",
+        "cutTrees",
+        "manageRights",
+        "transferMoney",
+        "updateFarm",
+        "manageContracting",
+    ];
+
+    /// Changes making a player farm manager with every permission.
+    pub fn grant_all() -> Self {
+        let all = Some(true);
+        PlayerPermissionChanges {
+            farm_manager: all,
+            buy_vehicle: all,
+            sell_vehicle: all,
+            buy_placeable: all,
+            sell_placeable: all,
+            manage_contracts: all,
+            trade_animals: all,
+            create_fields: all,
+            landscaping: all,
+            hire_assistant: all,
+            reset_vehicle: all,
+            manage_productions: all,
+            cut_trees: all,
+            manage_rights: all,
+            transfer_money: all,
+            update_farm: all,
+            manage_contracting: all,
+        }
+    }
+
+    /// New value of the `<player>` attribute `key`, if these changes set it.
+    pub fn value_for(&self, key: &str) -> Option<bool> {
+        match key {
+            "farmManager" => self.farm_manager,
+            "buyVehicle" => self.buy_vehicle,
+            "sellVehicle" => self.sell_vehicle,
+            "buyPlaceable" => self.buy_placeable,
+            "sellPlaceable" => self.sell_placeable,
+            "manageContracts" => self.manage_contracts,
+            "tradeAnimals" => self.trade_animals,
+            "createFields" => self.create_fields,
+            "landscaping" => self.landscaping,
+            "hireAssistant" => self.hire_assistant,
+            "resetVehicle" => self.reset_vehicle,
+            "manageProductions" => self.manage_productions,
+            "cutTrees" => self.cut_trees,
+            "manageRights" => self.manage_rights,
+            "transferMoney" => self.transfer_money,
+            "updateFarm" => self.update_farm,
+            "manageContracting" => self.manage_contracting,
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct VehicleChange {
     pub unique_id: String,
     pub delete: bool,
@@ -39,6 +194,12 @@ pub struct VehicleChange {
     pub damage: Option<f64>,
     pub wear: Option<f64>,
     pub fill_units: Option<Vec<FillUnitChange>>,
+    /// Dismiss the hired worker by removing the active `<job>` node
+    #[serde(default)]
+    pub stop_worker: bool,
+    /// With `delete`, also delete every implement attached below this vehicle
+    #[serde(default)]
+    pub cascade: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,9 +232,10 @@ pub struct SaleAddition {
     pub age: u32,
     pub operating_time: f64,
     pub time_left: u32,
+    pub bought_configurations: Option<Vec<BoughtConfiguration>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FieldChange {
     pub id: u32,
@@ -92,6 +254,51 @@ pub struct FieldChange {
     pub water_level: Option<u8>,
 }
 
+/// Field work applied by `prepare_field`, as if the field had just been worked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FieldOperation {
+    /// Plowed ground: `groundType` PLOWED, `plowLevel` 1, and the crop and weeds
+    /// destroyed (`fruitType` UNKNOWN, `growthState` 0, `weedState` 0).
+    Plow,
+    /// Cultivated ground: `groundType` CULTIVATED, and the crop and weeds
+    /// destroyed. Wins over `Plow` for the ground type, as the last pass done.
+    Cultivate,
+    /// Fully limed: `limeLevel` 3.
+    Lime,
+    /// Fully fertilized: `sprayLevel` 2. The `sprayType` is left as it is.
+    Fertilize,
+}
+
+impl FieldChange {
+    /// Changes putting field `id` in the state left by `operations`. Attributes
+    /// no operation touches stay unset.
+    pub fn prepared(id: u32, operations: &[FieldOperation]) -> Self {
+        let mut change = FieldChange {
+            id,
+            ..Default::default()
+        };
+        let plow = operations.contains(&FieldOperation::Plow);
+        let cultivate = operations.contains(&FieldOperation::Cultivate);
+        if plow || cultivate {
+            change.fruit_type = Some("UNKNOWN".to_string());
+            change.growth_state = Some(0);
+            change.weed_state = Some(0);
+            change.ground_type = Some(if cultivate { "CULTIVATED" } else { "PLOWED" }.to_string());
+        }
+        if plow {
+            change.plow_level = Some(1);
+        }
+        if operations.contains(&FieldOperation::Lime) {
+            change.lime_level = Some(3);
+        }
+        if operations.contains(&FieldOperation::Fertilize) {
+            change.spray_level = Some(2);
+        }
+        change
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FarmlandChange {
@@ -118,6 +325,9 @@ pub struct PlaceableChange {
     pub complete_construction: bool,
     pub production_inputs: Option<Vec<ProductionStockChange>>,
     pub production_outputs: Option<Vec<ProductionStockChange>>,
+    /// Write production fill levels as-is instead of clamping them to the storage capacity
+    #[serde(default)]
+    pub allow_overfill: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -143,6 +353,9 @@ pub struct MissionChange {
 pub struct CollectibleChange {
     pub index: u32,
     pub collected: bool,
+    /// New map marker state. Only written to saves that already store it.
+    #[serde(default)]
+    pub marked: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -157,6 +370,10 @@ pub struct ContractSettingsChange {
 #[serde(rename_all = "camelCase")]
 pub struct GreatDemandChange {
     pub index: usize,
+    /// Target the demand by its selling station `uniqueId` instead of its slot
+    /// index, which shifts when empty slots are added or removed
+    #[serde(default)]
+    pub unique_id: Option<String>,
     pub fill_type_name: Option<String>,
     pub demand_multiplier: Option<f64>,
     pub demand_start_day: Option<u32>,
@@ -185,6 +402,14 @@ pub struct EconomyChanges {
     pub great_demand_deletions: Option<Vec<usize>>,
 }
 
+/// Grow planted trees to their mature stage. `mature_indices` selects trees by
+/// their position in treePlant.xml; `None` matures every tree.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeChanges {
+    pub mature_indices: Option<Vec<usize>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SaveResult {
@@ -192,4 +417,83 @@ pub struct SaveResult {
     pub backup_path: Option<String>,
     pub files_modified: Vec<String>,
     pub errors: Vec<LocalizedMessage>,
+    /// Changes whose target was not found, and so were not applied
+    #[serde(default)]
+    pub unmatched_changes: Vec<UnmatchedChange>,
+}
+
+/// A change addressing an element its file does not have: `key` is the
+/// uniqueId, id or index the change was given.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct UnmatchedChange {
+    pub file: String,
+    pub key: String,
+}
+
+/// A requested value the savegame does not hold once saved. `property` is `None`
+/// when `target` itself was not found, e.g. a vehicle whose uniqueId matches nothing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnappliedChange {
+    pub file: String,
+    pub target: String,
+    pub property: Option<String>,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
+
+/// Outcome of a save followed by a reload of the written files. `result.success`
+/// is false as soon as one change did not land.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifiedSaveResult {
+    pub result: SaveResult,
+    pub unapplied: Vec<UnappliedChange>,
+    /// Sections of the changes the reload did not check, by their key in
+    /// `SavegameChanges` (e.g. `trees`)
+    #[serde(default)]
+    pub unverified: Vec<String>,
+}
+
+/// What a fresh start leaves in place. Money and the loan are always reset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreshStartKeep {
+    pub vehicles: bool,
+    pub land: bool,
+    pub statistics: bool,
+    /// Money to start with instead of the difficulty's starting amount
+    #[serde(default)]
+    pub starting_money: Option<f64>,
+}
+
+/// Outcome of a fresh start on the player's farm.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FreshStartResult {
+    pub save: VerifiedSaveResult,
+    pub starting_money: f64,
+    pub vehicles_removed: usize,
+    pub farmlands_released: usize,
+    pub statistics_reset: bool,
+}
+
+/// Files that pending changes would actually alter on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsavedChanges {
+    pub has_changes: bool,
+    pub files: Vec<String>,
+    /// Writers that failed on the scratch copy; their changes count as unsaved
+    #[serde(default)]
+    pub errors: Vec<LocalizedMessage>,
+}
+
+/// Outcome of applying the same changes to one savegame of a batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchSaveResult {
+    pub path: String,
+    pub result: SaveResult,
 }
@@ -5,4 +5,20 @@ use serde::{Deserialize, Serialize};
 pub struct Collectible {
     pub index: u32,
     pub collected: bool,
+    /// Display name from the metadata override, the built-in base game names, or
+    /// the raw index when unknown
+    pub name: String,
+    pub region: Option<String>,
+    /// Whether the collectible's hint is shown on the map; `None` when the save
+    /// does not store the marker state.
+    pub marked: Option<bool>,
+}
+
+/// Display metadata for a collectible, built in or keyed by its index in the
+/// override file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollectibleMetadata {
+    pub name: String,
+    pub region: Option<String>,
 }
@@ -21,6 +21,13 @@ pub struct GreatDemand {
     pub is_valid: bool,
 }
 
+impl GreatDemand {
+    /// Day the demand ends on, `demand_duration` being in hours.
+    pub fn end_day(&self) -> u32 {
+        self.demand_start_day + (self.demand_start_hour + self.demand_duration) / 24
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FillTypePrice {
@@ -10,6 +10,13 @@ pub struct Environment {
     pub weather_forecast: Vec<WeatherEvent>,
     pub snow_height: f64,
     pub ground_wetness: f64,
+    /// Past weather, oldest first, for the versions of the game that keep it.
+    /// `None` when environment.xml has no history.
+    #[serde(default)]
+    pub weather_history: Option<Vec<WeatherEvent>>,
+    /// `timeSinceLastRain` of the weather, in the unit the game stores it in.
+    #[serde(default)]
+    pub time_since_last_rain: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -7,12 +7,17 @@ pub struct Farm {
     pub name: String,
     pub color: u8,
     pub loan: f64,
+    /// Annual loan interest rate in percent. Only some saves store it.
+    pub loan_interest_rate: Option<f64>,
     pub money: f64,
     pub players: Vec<FarmPlayer>,
     pub statistics: FarmStatistics,
     pub daily_finances: Vec<DailyFinance>,
 }
 
+/// Color indices of the game's farm palette, as stored in the `color` of farms.xml.
+pub const FARM_COLORS: std::ops::RangeInclusive<u8> = 1..=16;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FarmPlayer {
@@ -38,6 +43,15 @@ pub struct FarmPlayer {
     pub manage_contracting: bool,
 }
 
+/// A player with the farm they belong to, for a permissions overview across farms.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerEntry {
+    pub farm_id: u8,
+    pub farm_name: String,
+    pub player: FarmPlayer,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct FarmStatistics {
@@ -68,25 +82,25 @@ pub struct FarmStatistics {
     pub threshed_time: f64,
     pub plowed_time: f64,
     // Counts
-    pub bale_count: u32,
-    pub wrapped_bales: u32,
-    pub sold_cotton_bales: u32,
-    pub mission_count: u32,
-    pub repair_vehicle_count: u32,
-    pub repaint_vehicle_count: u32,
+    pub bale_count: u64,
+    pub wrapped_bales: u64,
+    pub sold_cotton_bales: u64,
+    pub mission_count: u64,
+    pub repair_vehicle_count: u64,
+    pub repaint_vehicle_count: u64,
     // Animals
-    pub breed_cows_count: u32,
-    pub breed_sheep_count: u32,
-    pub breed_pigs_count: u32,
-    pub breed_chicken_count: u32,
-    pub breed_horses_count: u32,
-    pub breed_goats_count: u32,
-    pub breed_water_buffalo_count: u32,
-    pub pet_dog_count: u32,
-    pub horse_jump_count: u32,
+    pub breed_cows_count: u64,
+    pub breed_sheep_count: u64,
+    pub breed_pigs_count: u64,
+    pub breed_chicken_count: u64,
+    pub breed_horses_count: u64,
+    pub breed_goats_count: u64,
+    pub breed_water_buffalo_count: u64,
+    pub pet_dog_count: u64,
+    pub horse_jump_count: u64,
     // Trees & wood
-    pub planted_tree_count: u32,
-    pub cut_tree_count: u32,
+    pub planted_tree_count: u64,
+    pub cut_tree_count: u64,
     pub wood_tons_sold: f64,
     // Finance (game internal - not reliable totals)
     pub revenue: f64,
@@ -95,6 +109,57 @@ pub struct FarmStatistics {
     pub play_time: f64,
 }
 
+/// XML names of the `<statistics>` counters kept as whole numbers.
+pub const COUNT_STATISTICS: &[&str] = &[
+    "baleCount",
+    "wrappedBales",
+    "soldCottonBales",
+    "missionCount",
+    "repairVehicleCount",
+    "repaintVehicleCount",
+    "breedCowsCount",
+    "breedSheepCount",
+    "breedPigsCount",
+    "breedChickenCount",
+    "breedHorsesCount",
+    "breedGoatsCount",
+    "breedWaterBuffaloCount",
+    "petDogCount",
+    "horseJumpCount",
+    "plantedTreeCount",
+    "cutTreeCount",
+];
+
+/// XML names of the `<statistics>` values written with decimals.
+pub const VALUE_STATISTICS: &[&str] = &[
+    "traveledDistance",
+    "tractorDistance",
+    "carDistance",
+    "truckDistance",
+    "horseDistance",
+    "fuelUsage",
+    "seedUsage",
+    "sprayUsage",
+    "workedHectares",
+    "cultivatedHectares",
+    "sownHectares",
+    "sprayedHectares",
+    "threshedHectares",
+    "plowedHectares",
+    "harvestedGrapes",
+    "harvestedOlives",
+    "workedTime",
+    "cultivatedTime",
+    "sownTime",
+    "sprayedTime",
+    "threshedTime",
+    "plowedTime",
+    "woodTonsSold",
+    "revenue",
+    "expenses",
+    "playTime",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DailyFinance {
@@ -120,3 +185,95 @@ pub struct DailyFinance {
     pub other_income: f64,
     pub other_expenses: f64,
 }
+
+impl DailyFinance {
+    /// Net result of the day: income is stored positive and expenses negative.
+    pub fn net(&self) -> f64 {
+        self.new_vehicles_cost
+            + self.sold_vehicles
+            + self.new_animals_cost
+            + self.sold_animals
+            + self.construction_cost
+            + self.sold_buildings
+            + self.field_purchase
+            + self.sold_fields
+            + self.vehicle_running_cost
+            + self.vehicle_leasing_cost
+            + self.property_maintenance
+            + self.property_income
+            + self.production_costs
+            + self.sold_products
+            + self.harvest_income
+            + self.mission_income
+            + self.wage_payment
+            + self.loan_interest
+            + self.other_income
+            + self.other_expenses
+    }
+}
+
+/// Estimated balance of a farm over the next in-game days, extrapolated from the
+/// average net result of its finance history. An estimate only: purchases,
+/// seasons and price changes to come are not foreseen.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinanceProjection {
+    pub farm_id: u8,
+    pub starting_money: f64,
+    pub average_daily_net: f64,
+    /// Number of history days the average is based on
+    pub days_sampled: usize,
+    /// Projected balance at the end of day 1, 2... from now
+    pub balances: Vec<ProjectedBalance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectedBalance {
+    pub day: u32,
+    pub money: f64,
+}
+
+/// Project the money of `farm` over `days` days at the average net of its
+/// completed history days. Day 0 (today, still running) is only used when the
+/// history holds nothing else.
+pub fn project_finances(farm: &Farm, days: u32) -> FinanceProjection {
+    let completed: Vec<&DailyFinance> =
+        farm.daily_finances.iter().filter(|df| df.day > 0).collect();
+    let sample = if completed.is_empty() {
+        farm.daily_finances.iter().collect()
+    } else {
+        completed
+    };
+    let average_daily_net = if sample.is_empty() {
+        0.0
+    } else {
+        sample.iter().map(|df| df.net()).sum::<f64>() / sample.len() as f64
+    };
+
+    FinanceProjection {
+        farm_id: farm.farm_id,
+        starting_money: farm.money,
+        average_daily_net,
+        days_sampled: sample.len(),
+        balances: (1..=days)
+            .map(|day| ProjectedBalance {
+                day,
+                money: farm.money + average_daily_net * day as f64,
+            })
+            .collect(),
+    }
+}
+
+/// Outcome of paying off the loan of one farm.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanPayoff {
+    pub farm_id: u8,
+    pub loan_before: f64,
+    pub loan_after: f64,
+    pub money_before: f64,
+    pub money_after: f64,
+    /// False when the farm could not afford its loan and negative money was not allowed
+    pub paid: bool,
+}
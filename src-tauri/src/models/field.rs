@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use super::economy::Economy;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Field {
@@ -26,3 +28,181 @@ pub struct Farmland {
     pub id: u32,
     pub farm_id: u8,
 }
+
+/// A field with its owner and size, for listing and sorting fields.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldDetail {
+    pub id: u32,
+    /// Owner of the field's farmland, 0 when nobody owns it
+    pub farm_id: u8,
+    pub fruit_type: String,
+    pub growth_state: u8,
+    /// Area of the field's farmland in hectares, when the map data could be read
+    pub area_hectares: Option<f64>,
+    /// Purchase price of the field's farmland, known along with its area
+    pub value: Option<f64>,
+}
+
+/// How many of the map's fields are owned and under contract, to track
+/// progress toward owning the whole map.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldOwnershipSummary {
+    pub total_fields: usize,
+    /// Fields whose farmland belongs to any farm
+    pub owned_fields: usize,
+    /// Fields with a running mission
+    pub contracted_fields: usize,
+    pub owned_percent: f64,
+    /// Owned fields of each farm owning at least one, by farm id
+    pub farms: Vec<FarmFieldOwnership>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FarmFieldOwnership {
+    pub farm_id: u8,
+    pub owned_fields: usize,
+    pub owned_percent: f64,
+}
+
+/// Fruit types of the base game maps, as offered by the field editor. `UNKNOWN`
+/// and `FALLOW` are the values of a field with nothing planted.
+pub const BASE_FRUIT_TYPES: &[&str] = &[
+    "WHEAT",
+    "BARLEY",
+    "CANOLA",
+    "OAT",
+    "CORN",
+    "SUNFLOWER",
+    "SOYBEAN",
+    "POTATO",
+    "SUGARBEET",
+    "COTTON",
+    "SORGHUM",
+    "RICE",
+    "ONION",
+    "CARROT",
+    "PARSNIP",
+    "BEETROOT",
+    "GRASS",
+    "SPINACH",
+    "GREENBEAN",
+    "PEA",
+    "MAIZE",
+    "UNKNOWN",
+    "FALLOW",
+];
+
+/// Spray types of the base game, as stored in the `sprayType` of fields.xml.
+pub const BASE_SPRAY_TYPES: &[&str] = &[
+    "NONE",
+    "FERTILIZER",
+    "LIQUIDFERTILIZER",
+    "MANURE",
+    "LIQUIDMANURE",
+    "DIGESTATE",
+    "LIME",
+    "HERBICIDE",
+];
+
+/// Base game fill types that are not crops: fuels, farm inputs, animal products,
+/// forage and production goods. Other fill types priced in economy.xml are taken
+/// for the crops of a modded map.
+pub const NON_CROP_FILL_TYPES: &[&str] = &[
+    "DIESEL",
+    "DEF",
+    "ELECTRICCHARGE",
+    "METHANE",
+    "WATER",
+    "SEEDS",
+    "TREESAPLINGS",
+    "MILK",
+    "GOATMILK",
+    "BUFFALOMILK",
+    "EGG",
+    "WOOL",
+    "HONEY",
+    "STRAW",
+    "GRASS_WINDROW",
+    "DRYGRASS_WINDROW",
+    "SILAGE",
+    "CHAFF",
+    "FORAGE",
+    "MINERAL_FEED",
+    "PIGFOOD",
+    "WOOD",
+    "WOODCHIPS",
+    "STONE",
+    "ROADSALT",
+    "SNOW",
+    "FLOUR",
+    "BREAD",
+    "CAKE",
+    "CEREAL",
+    "BUTTER",
+    "CHEESE",
+    "GOATCHEESE",
+    "BUFFALOMOZZARELLA",
+    "CHOCOLATE",
+    "SUGAR",
+    "RAISINS",
+    "GRAPEJUICE",
+    "SUNFLOWER_OIL",
+    "CANOLA_OIL",
+    "OLIVE_OIL",
+    "FABRIC",
+    "CLOTHES",
+    "BOARDS",
+    "PLANKS",
+    "FURNITURE",
+    "BARREL",
+    "BATHTUB",
+    "BUCKET",
+    "CARTONROLL",
+    "PAPERROLL",
+    "ROPE",
+    "PREFABWALL",
+];
+
+/// Fill types priced in economy.xml that are neither a known non-crop type nor a
+/// spray type, uppercased.
+fn economy_crops(economy: Option<&Economy>) -> impl Iterator<Item = String> + '_ {
+    economy
+        .into_iter()
+        .flat_map(|e| &e.fill_types)
+        .map(|ft| ft.fill_type.to_uppercase())
+        .filter(|fill_type| {
+            !NON_CROP_FILL_TYPES.contains(&fill_type.as_str())
+                && !BASE_SPRAY_TYPES.contains(&fill_type.as_str())
+        })
+}
+
+/// The base fruit types followed by any other crop the fields already grow or
+/// plan, or economy.xml prices (modded map crops), sorted by name.
+pub fn valid_fruit_types(fields: &[Field], economy: Option<&Economy>) -> Vec<String> {
+    let mut extra: Vec<String> = fields
+        .iter()
+        .flat_map(|f| [&f.fruit_type, &f.planned_fruit])
+        .filter(|fruit| !fruit.is_empty())
+        .map(|fruit| fruit.to_uppercase())
+        .chain(economy_crops(economy))
+        .filter(|fruit| !BASE_FRUIT_TYPES.contains(&fruit.as_str()))
+        .collect();
+    extra.sort();
+    extra.dedup();
+
+    BASE_FRUIT_TYPES
+        .iter()
+        .map(|fruit| fruit.to_string())
+        .chain(extra)
+        .collect()
+}
+
+/// Whether `fruit` is a base game crop or, on a modded map, a crop priced in
+/// economy.xml (mod crops always get a price entry).
+pub fn is_known_fruit_type(fruit: &str, economy: Option<&Economy>) -> bool {
+    let fruit = fruit.to_uppercase();
+    BASE_FRUIT_TYPES.contains(&fruit.as_str()) || economy_crops(economy).any(|crop| crop == fruit)
+}
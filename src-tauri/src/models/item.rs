@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+/// Kind of loose object lying around the map.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum LooseItemKind {
+    Bale,
+    Pallet,
+}
+
+/// Number of loose objects removed, per kind.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClearedItems {
+    pub bales: usize,
+    pub pallets: usize,
+}
+
+/// Whether a vehicles.xml entry is a pallet or big bag rather than a real vehicle.
+/// The game stores them as vehicles loaded from its `objects` folder.
+pub fn is_pallet_filename(filename: &str) -> bool {
+    let filename = filename.to_lowercase();
+    filename.contains("objects/pallets/") || filename.contains("objects/bigbag")
+}
@@ -10,10 +10,20 @@ pub mod economy;
 pub mod environment;
 pub mod farm;
 pub mod field;
+pub mod item;
 pub mod mission;
+pub mod net_worth;
 pub mod placeable;
+pub mod progress;
+pub mod save_diff;
+pub mod save_size;
+#[cfg(test)]
+mod schema;
 pub mod sale;
+pub mod settings;
+pub mod tree;
 pub mod update;
+pub mod validation;
 pub mod vehicle;
 
 use serde::{Deserialize, Serialize};
@@ -29,6 +39,7 @@ use field::{Farmland, Field};
 use mission::Mission;
 use placeable::Placeable;
 use sale::SaleItem;
+use tree::{PlantedTree, TreeTypeCount};
 use vehicle::Vehicle;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,8 +55,31 @@ pub struct SavegameData {
     pub placeables: Vec<Placeable>,
     pub missions: Vec<Mission>,
     pub collectibles: Vec<Collectible>,
+    pub trees: Vec<PlantedTree>,
+    pub tree_counts: Vec<TreeTypeCount>,
     pub contract_settings: Option<ContractSettings>,
     pub environment: Option<Environment>,
     pub economy: Option<Economy>,
+    /// Savegame files, by name on disk, not starting with an XML declaration
+    #[serde(default)]
+    pub files_without_declaration: Vec<String>,
     pub warnings: Vec<LocalizedMessage>,
 }
+
+/// A savegame file `load_savegame_safe` could not parse.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileLoadError {
+    pub file: String,
+    pub message: String,
+}
+
+/// What `load_savegame_safe` could read of a savegame. Entities of the files in
+/// `errors` are left empty, and the career is blank when careerSavegame.xml is
+/// one of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeLoadResult {
+    pub data: SavegameData,
+    pub errors: Vec<FileLoadError>,
+}
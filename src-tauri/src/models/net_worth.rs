@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::career::EconomicMultipliers;
+use super::economy::Economy;
+use super::farm::Farm;
+use super::placeable::Placeable;
+use super::vehicle::{build_attachment_tree, AttachmentNode, PropertyState, Vehicle};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetWorth {
+    pub farm_id: u8,
+    pub money: f64,
+    pub vehicles_value: f64,
+    pub placeables_value: f64,
+    pub stored_crops_value: f64,
+    pub loan: f64,
+    pub total: f64,
+}
+
+/// Value of a vehicle together with the implements attached to it and the
+/// goods they carry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleValue {
+    pub unique_id: String,
+    pub price: f64,
+    pub implements_price: f64,
+    pub fill_value: f64,
+    pub total: f64,
+    /// Fill types carried without an economy price (fuel, modded goods), left
+    /// out of `fill_value`.
+    pub unpriced_fill_types: Vec<String>,
+}
+
+/// Average price per liter of each fill type. `economy.xml` stores prices
+/// per 1000 liters for every period of the year.
+fn price_per_liter(economy: Option<&Economy>) -> HashMap<String, f64> {
+    let mut prices = HashMap::new();
+    let Some(economy) = economy else {
+        return prices;
+    };
+
+    for fill_type in &economy.fill_types {
+        if fill_type.price_history.is_empty() {
+            continue;
+        }
+        let sum: f64 = fill_type.price_history.iter().map(|p| p.price as f64).sum();
+        let average = sum / fill_type.price_history.len() as f64;
+        prices.insert(fill_type.fill_type.to_uppercase(), average / 1000.0);
+    }
+
+    prices
+}
+
+/// Net worth of a farm: money, owned vehicles and placeables, and the value
+/// of the goods stored in its productions, minus the loan. Rented and
+/// mission vehicles are not counted. Fill types without an economy price
+/// are ignored.
+pub fn calculate_net_worth(
+    farm: &Farm,
+    vehicles: &[Vehicle],
+    placeables: &[Placeable],
+    economy: Option<&Economy>,
+) -> NetWorth {
+    let vehicles_value: f64 = vehicles
+        .iter()
+        .filter(|v| v.farm_id == farm.farm_id && v.property_state == PropertyState::Owned)
+        .map(|v| v.price)
+        .sum();
+
+    let owned_placeables: Vec<&Placeable> = placeables
+        .iter()
+        .filter(|p| p.farm_id == farm.farm_id)
+        .collect();
+
+    let placeables_value: f64 = owned_placeables.iter().map(|p| p.price).sum();
+
+    let prices = price_per_liter(economy);
+    let stored_crops_value: f64 = owned_placeables
+        .iter()
+        .flat_map(|p| p.production_inputs.iter().chain(p.production_outputs.iter()))
+        .filter_map(|stock| {
+            prices
+                .get(&stock.fill_type.to_uppercase())
+                .map(|price| stock.amount * price)
+        })
+        .sum();
+
+    let total = farm.money + vehicles_value + placeables_value + stored_crops_value - farm.loan;
+
+    NetWorth {
+        farm_id: farm.farm_id,
+        money: farm.money,
+        vehicles_value,
+        placeables_value,
+        stored_crops_value,
+        loan: farm.loan,
+        total,
+    }
+}
+
+/// Base price of a hectare of farmland. Mod maps setting their own price per
+/// hectare are valued at it too.
+pub const FARMLAND_PRICE_PER_HECTARE: f64 = 60_000.0;
+
+/// Value of `area_hectares` of farmland: its purchase price at the save's
+/// economic difficulty.
+pub fn farmland_value(area_hectares: f64, multipliers: &EconomicMultipliers) -> f64 {
+    area_hectares * FARMLAND_PRICE_PER_HECTARE * multipliers.expenses
+}
+
+/// Value of the vehicle `unique_id`: its price, the prices of everything attached
+/// below it, and the market value of the contents of all their fill units.
+/// Returns `None` when the vehicle does not exist.
+pub fn calculate_vehicle_value(
+    unique_id: &str,
+    vehicles: &[Vehicle],
+    economy: Option<&Economy>,
+) -> Option<VehicleValue> {
+    let tree = build_attachment_tree(vehicles);
+    let group: Vec<&Vehicle> = AttachmentNode::find(&tree, unique_id)?
+        .unique_ids()
+        .into_iter()
+        .filter_map(|id| vehicles.iter().find(|v| v.unique_id == id))
+        .collect();
+    let (vehicle, implements) = group.split_first()?;
+
+    let prices = price_per_liter(economy);
+    let mut fill_value = 0.0;
+    let mut unpriced_fill_types: Vec<String> = Vec::new();
+    for unit in group.iter().flat_map(|v| v.fill_units.iter()) {
+        if unit.fill_level <= 0.0 {
+            continue;
+        }
+        match prices.get(&unit.fill_type.to_uppercase()) {
+            Some(price) => fill_value += unit.fill_level * price,
+            None => unpriced_fill_types.push(unit.fill_type.clone()),
+        }
+    }
+    unpriced_fill_types.sort();
+    unpriced_fill_types.dedup();
+
+    let implements_price: f64 = implements.iter().map(|v| v.price).sum();
+
+    Some(VehicleValue {
+        unique_id: vehicle.unique_id.clone(),
+        price: vehicle.price,
+        implements_price,
+        fill_value,
+        total: vehicle.price + implements_price + fill_value,
+        unpriced_fill_types,
+    })
+}
@@ -42,6 +42,25 @@ pub struct ProductionStock {
     pub capacity: f64,
 }
 
+/// Sales statistics a selling station placeable keeps, when the save stores them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SellingStation {
+    /// Position of the placeable in placeables.xml, as `Placeable::index`.
+    pub placeable_index: usize,
+    pub display_name: String,
+    pub stats: Vec<SellingStationStat>,
+}
+
+/// Amount received and money paid out by a selling station for one fill type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SellingStationStat {
+    pub fill_type: String,
+    pub received: f64,
+    pub paid: f64,
+}
+
 pub fn placeable_display_name(filename: &str) -> String {
     let name = filename
         .rsplit('/')
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+use super::farm::{Farm, FarmStatistics};
+
+/// What a milestone measures, for grouping them in the UI.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum MilestoneCategory {
+    Fields,
+    Animals,
+    Money,
+    Farming,
+}
+
+/// An achievement-style goal on a farm's statistics or money.
+pub struct Milestone {
+    /// Stable id, from which the frontend picks the milestone's label
+    pub id: &'static str,
+    pub category: MilestoneCategory,
+    pub target: f64,
+    pub value: fn(&Farm) -> f64,
+}
+
+fn animals_bred(stats: &FarmStatistics) -> u64 {
+    stats.breed_cows_count
+        + stats.breed_sheep_count
+        + stats.breed_pigs_count
+        + stats.breed_chicken_count
+        + stats.breed_horses_count
+        + stats.breed_goats_count
+        + stats.breed_water_buffalo_count
+}
+
+/// Every milestone of `progress_summary`, grouped by category and by target.
+pub const MILESTONES: &[Milestone] = &[
+    Milestone {
+        id: "workedHectares100",
+        category: MilestoneCategory::Fields,
+        target: 100.0,
+        value: |f| f.statistics.worked_hectares,
+    },
+    Milestone {
+        id: "workedHectares1000",
+        category: MilestoneCategory::Fields,
+        target: 1000.0,
+        value: |f| f.statistics.worked_hectares,
+    },
+    Milestone {
+        id: "threshedHectares500",
+        category: MilestoneCategory::Fields,
+        target: 500.0,
+        value: |f| f.statistics.threshed_hectares,
+    },
+    Milestone {
+        id: "animalsBred100",
+        category: MilestoneCategory::Animals,
+        target: 100.0,
+        value: |f| animals_bred(&f.statistics) as f64,
+    },
+    Milestone {
+        id: "animalsBred1000",
+        category: MilestoneCategory::Animals,
+        target: 1000.0,
+        value: |f| animals_bred(&f.statistics) as f64,
+    },
+    Milestone {
+        id: "firstMillion",
+        category: MilestoneCategory::Money,
+        target: 1_000_000.0,
+        value: |f| f.money,
+    },
+    Milestone {
+        id: "tenMillion",
+        category: MilestoneCategory::Money,
+        target: 10_000_000.0,
+        value: |f| f.money,
+    },
+    Milestone {
+        id: "bales1000",
+        category: MilestoneCategory::Farming,
+        target: 1000.0,
+        value: |f| f.statistics.bale_count as f64,
+    },
+    Milestone {
+        id: "missions100",
+        category: MilestoneCategory::Farming,
+        target: 100.0,
+        value: |f| f.statistics.mission_count as f64,
+    },
+    Milestone {
+        id: "treesPlanted100",
+        category: MilestoneCategory::Farming,
+        target: 100.0,
+        value: |f| f.statistics.planted_tree_count as f64,
+    },
+];
+
+/// Where a farm stands on one milestone.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneProgress {
+    pub id: String,
+    pub category: MilestoneCategory,
+    pub current: f64,
+    pub target: f64,
+    /// Share of the target reached, capped at 100
+    pub percent: f64,
+    pub achieved: bool,
+}
+
+impl Milestone {
+    pub fn progress(&self, farm: &Farm) -> MilestoneProgress {
+        let current = (self.value)(farm);
+        MilestoneProgress {
+            id: self.id.to_string(),
+            category: self.category,
+            current,
+            target: self.target,
+            percent: (current / self.target * 100.0).clamp(0.0, 100.0),
+            achieved: current >= self.target,
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FileChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// One savegame file that differs between two savegame folders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileDiff {
+    pub file: String,
+    pub change: FileChange,
+}
+
+/// Files that differ from `before_path` to `after_path`, sorted by file name.
+/// XML files are compared by content, so reformatting alone is not a change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavegameDiff {
+    pub before_path: String,
+    pub after_path: String,
+    pub files: Vec<FileDiff>,
+}
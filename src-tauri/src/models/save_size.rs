@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// Size of one XML file of a savegame, with the number of entities parsed from it
+/// when the editor knows how to count them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveFileSize {
+    pub file: String,
+    pub size_bytes: u64,
+    pub entity_count: Option<usize>,
+}
+
+/// Files where the game stores terrain sculpting and painted or dumped material
+/// heaps. They are absent until the terrain is modified and grow with every edit.
+pub const LANDSCAPING_FILES: &[&str] = &["terrain.heightmap.png", "densityMap_height.gdm"];
+
+/// How much of the savegame landscaping and decorations account for.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LandscapingReport {
+    /// The `LANDSCAPING_FILES` present in the savegame
+    pub files: Vec<SaveFileSize>,
+    pub total_bytes: u64,
+    /// Placed decoration placeables (fences, lights, paths...)
+    pub decoration_count: usize,
+}
@@ -0,0 +1,231 @@
+//! Guards on the JSON representation of the models, which the frontend and the
+//! settings import/export rely on.
+//!
+//! Each sample is serialized, read back into its type and serialized again: both
+//! JSON documents must be equal. The shape of every sample (keys and value kinds)
+//! is then compared with `tests/fixtures/ipc/schema.json`, so adding, removing or
+//! renaming a field fails until that file is updated. Run the tests with
+//! `UPDATE_IPC_SCHEMA=1` to rewrite it after an intended change.
+
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::commands::savegame::load_savegame;
+use crate::models::changes::{SaveResult, SavegameChanges, UnmatchedChange};
+use crate::models::common::LocalizedMessage;
+use crate::models::settings::{AppSettings, SettingsExport};
+use crate::parsers::career::parse_career_summary;
+
+/// Keys holding a map with free-form keys, whose entries are not part of the schema.
+const MAP_KEYS: &[&str] = &["params", "saveFileNames"];
+
+fn ipc_fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("ipc")
+}
+
+fn complete_fixture() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("savegame_complete")
+}
+
+/// Serialize `sample`, deserialize it back and check nothing was lost on the way.
+fn round_trip<T: Serialize + DeserializeOwned>(name: &str, sample: &T) -> Value {
+    let json = serde_json::to_value(sample).unwrap();
+    let back: T = serde_json::from_value(json.clone())
+        .unwrap_or_else(|e| panic!("{} does not deserialize from its own JSON: {}", name, e));
+    assert_eq!(
+        serde_json::to_value(&back).unwrap(),
+        json,
+        "{} changes through a JSON round trip",
+        name
+    );
+    json
+}
+
+/// Keys and value kinds of `value`. Arrays are described by their first element.
+fn shape(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::from("null"),
+        Value::Bool(_) => Value::from("boolean"),
+        Value::Number(_) => Value::from("number"),
+        Value::String(_) => Value::from("string"),
+        Value::Array(items) => Value::Array(items.first().map(shape).into_iter().collect()),
+        Value::Object(entries) => Value::Object(
+            entries
+                .iter()
+                .map(|(key, value)| {
+                    let value_shape = if MAP_KEYS.contains(&key.as_str()) {
+                        Value::from("map")
+                    } else {
+                        shape(value)
+                    };
+                    (key.clone(), value_shape)
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Paths of the keys of `shape` that are not camelCase.
+fn non_camel_case_keys(shape: &Value, path: &str, found: &mut Vec<String>) {
+    match shape {
+        Value::Object(entries) => {
+            for (key, value) in entries {
+                let is_camel_case = key.starts_with(|c: char| c.is_ascii_lowercase())
+                    && key.chars().all(|c| c.is_ascii_alphanumeric());
+                if !is_camel_case {
+                    found.push(format!("{}.{}", path, key));
+                }
+                non_camel_case_keys(value, &format!("{}.{}", path, key), found);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                non_camel_case_keys(item, &format!("{}[]", path), found);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn samples() -> Vec<(&'static str, Value)> {
+    let save_path = complete_fixture();
+    let data = load_savegame(save_path.display().to_string()).unwrap();
+    let summary = parse_career_summary(&save_path).unwrap();
+
+    let changes_json = std::fs::read_to_string(ipc_fixtures().join("changes.json")).unwrap();
+    let changes: SavegameChanges = serde_json::from_str(&changes_json).unwrap();
+
+    let save_result = SaveResult {
+        success: false,
+        backup_path: Some("backups/save_2025-01-01".to_string()),
+        files_modified: vec!["vehicles.xml".to_string()],
+        errors: vec![LocalizedMessage::new("errors.fileWriteError").with_param("file", "sales.xml")],
+        unmatched_changes: vec![UnmatchedChange {
+            file: "vehicles.xml".to_string(),
+            key: "vehicle9999".to_string(),
+        }],
+    };
+
+    let settings_export = SettingsExport {
+        version: 1,
+        exported_at: "2025-01-01T00:00:00Z".to_string(),
+        settings: AppSettings {
+            locale: Some("fr".to_string()),
+            theme: Some("dark".to_string()),
+            advanced_mode: Some(true),
+            disclaimer_accepted: Some(true),
+            default_path: Some("/saves".to_string()),
+            max_backups: Some(10),
+            game_path: Some("/game".to_string()),
+            check_for_updates_on_startup: Some(false),
+            save_file_names: Some(
+                [("r_contracts.xml".to_string(), "contracts.xml".to_string())].into(),
+            ),
+        },
+    };
+
+    vec![
+        ("SavegameData", round_trip("SavegameData", &data)),
+        ("SavegameSummary", round_trip("SavegameSummary", &summary)),
+        ("SavegameChanges", round_trip("SavegameChanges", &changes)),
+        ("SaveResult", round_trip("SaveResult", &save_result)),
+        (
+            "SettingsExport",
+            round_trip("SettingsExport", &settings_export),
+        ),
+    ]
+}
+
+/// Paths of the `null` values of `value`.
+fn null_paths(value: &Value, path: &str, found: &mut Vec<String>) {
+    match value {
+        Value::Null => found.push(path.to_string()),
+        Value::Array(items) => {
+            for item in items {
+                null_paths(item, &format!("{}[]", path), found);
+            }
+        }
+        Value::Object(entries) => {
+            for (key, value) in entries {
+                null_paths(value, &format!("{}.{}", path, key), found);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[test]
+fn test_changes_sample_is_complete() {
+    // Every change field is set in the sample, so none is pinned as a mere `null`
+    let json = std::fs::read_to_string(ipc_fixtures().join("changes.json")).unwrap();
+    let changes: SavegameChanges = serde_json::from_str(&json).unwrap();
+    let mut found = Vec::new();
+    null_paths(
+        &serde_json::to_value(&changes).unwrap(),
+        "SavegameChanges",
+        &mut found,
+    );
+    assert!(
+        found.is_empty(),
+        "fields missing from changes.json: {:?}",
+        found
+    );
+}
+
+#[test]
+fn test_ipc_keys_are_camel_case() {
+    let mut found = Vec::new();
+    for (name, json) in samples() {
+        non_camel_case_keys(&shape(&json), name, &mut found);
+    }
+    assert!(found.is_empty(), "keys not in camelCase: {:?}", found);
+}
+
+#[test]
+fn test_ipc_schema_is_stable() {
+    let schema = Value::Object(
+        samples()
+            .into_iter()
+            .map(|(name, json)| (name.to_string(), shape(&json)))
+            .collect::<Map<String, Value>>(),
+    );
+    let golden_path = ipc_fixtures().join("schema.json");
+
+    if std::env::var_os("UPDATE_IPC_SCHEMA").is_some() {
+        let pretty = serde_json::to_string_pretty(&schema).unwrap();
+        std::fs::write(&golden_path, pretty + "\n").unwrap();
+        return;
+    }
+
+    let golden: Value = read_golden(&golden_path);
+    for (name, expected) in golden.as_object().unwrap() {
+        assert_eq!(
+            schema.get(name),
+            Some(expected),
+            "JSON shape of {} no longer matches {}; if the change is intended, \
+             run the tests with UPDATE_IPC_SCHEMA=1 and update src/lib/types.ts",
+            name,
+            golden_path.display()
+        );
+    }
+    assert_eq!(
+        schema.as_object().unwrap().len(),
+        golden.as_object().unwrap().len(),
+        "schema.json does not cover the same types as the samples"
+    );
+}
+
+fn read_golden(path: &Path) -> Value {
+    let content = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+    serde_json::from_str(&content).unwrap()
+}
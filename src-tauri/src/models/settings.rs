@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::common::LocalizedMessage;
+
+/// User preferences persisted by the frontend in the plugin-store `settings.json`.
+/// Field names are the store keys and must stay stable across versions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppSettings {
+    pub locale: Option<String>,
+    pub theme: Option<String>,
+    pub advanced_mode: Option<bool>,
+    pub disclaimer_accepted: Option<bool>,
+    pub default_path: Option<String>,
+    pub max_backups: Option<u32>,
+    pub game_path: Option<String>,
+    pub check_for_updates_on_startup: Option<bool>,
+    /// Savegame file names to use instead of the expected ones, by expected name
+    pub save_file_names: Option<HashMap<String, String>>,
+}
+
+/// Exported settings file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SettingsExport {
+    pub version: u32,
+    pub exported_at: String,
+    pub settings: AppSettings,
+}
+
+/// Result of checking a game installation path before it is saved in the settings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GamePathCheck {
+    pub valid: bool,
+    /// Steam build id, when the path is a Steam library install
+    pub version: Option<String>,
+    /// Expected folders missing under the path, e.g. `data/vehicles`
+    pub missing_markers: Vec<String>,
+    /// One message per problem found
+    pub problems: Vec<LocalizedMessage>,
+}
@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::common::Position;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlantedTree {
+    pub index: usize,
+    pub tree_type: String,
+    pub growth_stage: u8,
+    pub is_growing: bool,
+    pub position: Option<Position>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeTypeCount {
+    pub tree_type: String,
+    /// Planted trees of the type
+    pub count: u64,
+}
+
+/// Highest growth stage of the base game tree types, as declared in
+/// `maps_treeTypes.xml`. Unknown (modded) types return `None`.
+pub fn tree_max_growth_stage(tree_type: &str) -> Option<u8> {
+    match tree_type.to_uppercase().as_str() {
+        "SPRUCE1" | "SPRUCE2" | "PINE" => Some(7),
+        "OAK" | "BIRCH" | "MAPLE" | "BEECH" | "POPLAR" => Some(5),
+        "WILLOW" => Some(4),
+        _ => None,
+    }
+}
+
+/// Count planted trees per type, sorted by type name.
+pub fn count_trees_by_type(trees: &[PlantedTree]) -> Vec<TreeTypeCount> {
+    let mut counts: BTreeMap<&str, u64> = BTreeMap::new();
+    for tree in trees {
+        *counts.entry(tree.tree_type.as_str()).or_insert(0) += 1;
+    }
+    counts
+        .into_iter()
+        .map(|(tree_type, count)| TreeTypeCount {
+            tree_type: tree_type.to_string(),
+            count,
+        })
+        .collect()
+}
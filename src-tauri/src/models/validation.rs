@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use super::common::LocalizedMessage;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ValidatorSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A registered savegame validator, for the UI to list. Its description is the
+/// `validators.<id>` translation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorInfo {
+    pub id: String,
+    pub severity: ValidatorSeverity,
+}
+
+/// Diagnostics produced by a single validator.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorReport {
+    pub id: String,
+    pub severity: ValidatorSeverity,
+    pub diagnostics: Vec<LocalizedMessage>,
+}
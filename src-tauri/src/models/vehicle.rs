@@ -1,3 +1,5 @@
+use std::collections::{HashMap, HashSet};
+
 use serde::{Deserialize, Serialize};
 
 use super::common::{Position, Rotation};
@@ -20,6 +22,9 @@ pub struct Vehicle {
     pub configurations: Vec<VehicleConfiguration>,
     pub fill_units: Vec<FillUnit>,
     pub attached_implements: Vec<AttachedImplement>,
+    /// Task of the hired worker driving the vehicle (`FIELDWORK`, `GOTO`...),
+    /// `None` when no worker is active.
+    pub worker_task: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -41,6 +46,129 @@ impl PropertyState {
     }
 }
 
+/// A vehicle driven by a hired worker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveWorker {
+    pub unique_id: String,
+    pub display_name: String,
+    pub farm_id: u8,
+    pub task: String,
+}
+
+/// What a vehicle runs on, among the fill types of its fill units.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum FuelKind {
+    Diesel,
+    Def,
+    ElectricCharge,
+    Methane,
+}
+
+impl FuelKind {
+    pub fn from_fill_type(fill_type: &str) -> Option<Self> {
+        match fill_type.to_uppercase().as_str() {
+            "DIESEL" => Some(FuelKind::Diesel),
+            "DEF" => Some(FuelKind::Def),
+            "ELECTRICCHARGE" => Some(FuelKind::ElectricCharge),
+            "METHANE" => Some(FuelKind::Methane),
+            _ => None,
+        }
+    }
+}
+
+/// Level of one consumable of a vehicle against its capacity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FuelLevel {
+    pub kind: FuelKind,
+    pub fill_level: f64,
+    pub capacity: f64,
+    /// Below the threshold the report was asked for
+    pub is_low: bool,
+}
+
+/// A vehicle running low on at least one consumable, with all of them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleFuelReport {
+    pub unique_id: String,
+    pub display_name: String,
+    pub levels: Vec<FuelLevel>,
+}
+
+/// Usage of a fleet: vehicle count, total operating time and average age,
+/// damage and wear.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetWear {
+    pub vehicle_count: usize,
+    pub total_operating_time: f64,
+    pub average_age: f64,
+    pub average_damage: f64,
+    pub average_wear: f64,
+}
+
+impl FleetWear {
+    pub fn of(vehicles: &[Vehicle]) -> Self {
+        let count = vehicles.len();
+        let average = |value: fn(&Vehicle) -> f64| {
+            if count == 0 {
+                0.0
+            } else {
+                vehicles.iter().map(value).sum::<f64>() / count as f64
+            }
+        };
+        FleetWear {
+            vehicle_count: count,
+            total_operating_time: vehicles.iter().map(|v| v.operating_time).sum(),
+            average_age: average(|v| v.age),
+            average_damage: average(|v| v.damage),
+            average_wear: average(|v| v.wear),
+        }
+    }
+}
+
+/// Fleet usage before and after scaling it down.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetWearScaling {
+    pub before: FleetWear,
+    pub after: FleetWear,
+}
+
+/// A vehicle and the implements attached to it, recursively
+/// (tractor → trailer → dolly).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentNode {
+    pub unique_id: String,
+    pub children: Vec<AttachmentNode>,
+}
+
+impl AttachmentNode {
+    /// The node of `unique_id` among `nodes` and their descendants.
+    pub fn find<'a>(nodes: &'a [AttachmentNode], unique_id: &str) -> Option<&'a AttachmentNode> {
+        nodes.iter().find_map(|node| {
+            if node.unique_id == unique_id {
+                Some(node)
+            } else {
+                AttachmentNode::find(&node.children, unique_id)
+            }
+        })
+    }
+
+    /// This vehicle's uniqueId followed by those of everything attached below it.
+    pub fn unique_ids(&self) -> Vec<&str> {
+        let mut ids = vec![self.unique_id.as_str()];
+        for child in &self.children {
+            ids.extend(child.unique_ids());
+        }
+        ids
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct VehicleConfiguration {
@@ -113,3 +241,61 @@ pub fn vehicle_display_name(filename: &str) -> String {
         .collect::<Vec<_>>()
         .join(" ")
 }
+
+/// Groups vehicles into attachment trees, one per vehicle that is not attached
+/// to anything, in savegame order. References to unknown vehicles are ignored.
+/// A corrupt save may attach an implement twice or loop back on itself: each
+/// vehicle appears only once, under the first parent that reaches it, and a
+/// loop with no free vehicle is rooted at its first vehicle in savegame order.
+pub fn build_attachment_tree(vehicles: &[Vehicle]) -> Vec<AttachmentNode> {
+    let by_id: HashMap<&str, &Vehicle> =
+        vehicles.iter().map(|v| (v.unique_id.as_str(), v)).collect();
+    let attached: HashSet<&str> = vehicles
+        .iter()
+        .flat_map(|v| {
+            v.attached_implements
+                .iter()
+                .map(|a| a.attached_vehicle_unique_id.as_str())
+                .filter(move |id| *id != v.unique_id)
+        })
+        .filter(|id| by_id.contains_key(id))
+        .collect();
+
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut roots: Vec<AttachmentNode> = vehicles
+        .iter()
+        .filter(|v| !attached.contains(v.unique_id.as_str()))
+        .map(|v| attachment_node(v, &by_id, &mut visited))
+        .collect();
+
+    // Vehicles only reachable through a cycle
+    for vehicle in vehicles {
+        if !visited.contains(vehicle.unique_id.as_str()) {
+            roots.push(attachment_node(vehicle, &by_id, &mut visited));
+        }
+    }
+
+    roots
+}
+
+fn attachment_node<'a>(
+    vehicle: &'a Vehicle,
+    by_id: &HashMap<&str, &'a Vehicle>,
+    visited: &mut HashSet<&'a str>,
+) -> AttachmentNode {
+    visited.insert(vehicle.unique_id.as_str());
+    let mut children = Vec::new();
+    for implement in &vehicle.attached_implements {
+        let Some(child) = by_id.get(implement.attached_vehicle_unique_id.as_str()) else {
+            continue;
+        };
+        if visited.contains(child.unique_id.as_str()) {
+            continue;
+        }
+        children.push(attachment_node(child, by_id, visited));
+    }
+    AttachmentNode {
+        unique_id: vehicle.unique_id.clone(),
+        children,
+    }
+}
@@ -4,19 +4,24 @@ use quick_xml::events::Event;
 use quick_xml::Reader;
 
 use crate::error::AppError;
-use crate::models::career::{CareerSavegame, SavegameSummary};
+use crate::models::career::{
+    is_mod_map, map_display_name, CareerSavegame, EconomicMultipliers, SavegameSummary,
+};
+use crate::models::catalog::SavegameMod;
+use crate::parsers::common::{attr_str, attr_str_opt, read_xml_file};
+use crate::parsers::farm::count_farm_players;
+use crate::parsers::files::{savegame_file, CAREER_SAVEGAME};
 
 /// Parse careerSavegame.xml and extract only the fields needed for the summary.
 /// Uses quick-xml event-based Reader for selective lightweight parsing.
 pub fn parse_career_summary(path: &Path) -> Result<SavegameSummary, AppError> {
-    let xml_path = path.join("careerSavegame.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, CAREER_SAVEGAME);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
 
     let mut savegame_name = String::new();
+    let mut map_id = String::new();
     let mut map_title = String::new();
     let mut save_date = String::new();
     let mut economic_difficulty = String::from("NORMAL");
@@ -68,6 +73,7 @@ pub fn parse_career_summary(path: &Path) -> Result<SavegameSummary, AppError> {
                 if in_settings {
                     match current_tag.as_str() {
                         "savegameName" => savegame_name = text,
+                        "mapId" => map_id = text,
                         "mapTitle" => map_title = text,
                         "saveDateFormatted" => save_date = text,
                         "economicDifficulty" => {
@@ -117,20 +123,55 @@ pub fn parse_career_summary(path: &Path) -> Result<SavegameSummary, AppError> {
     Ok(SavegameSummary {
         path: path.display().to_string(),
         name: savegame_name,
+        map_display: map_display_name(&map_id, &map_title),
+        is_mod_map: is_mod_map(&map_id),
+        map_id,
         map_title,
         money,
         play_time,
         save_date,
         economic_difficulty,
+        // A damaged farms.xml must not hide the savegame from the list
+        is_multiplayer: count_farm_players(path).unwrap_or(0) > 1,
     })
 }
 
+/// Mods listed by the `<mod>` elements of careerSavegame.xml, in file order.
+pub fn parse_savegame_mods(path: &Path) -> Result<Vec<SavegameMod>, AppError> {
+    let xml_path = savegame_file(path, CAREER_SAVEGAME);
+    let content = read_xml_file(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut mods = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) if e.name().as_ref() == b"mod" => {
+                mods.push(SavegameMod {
+                    mod_name: attr_str(e, "modName"),
+                    title: attr_str(e, "title"),
+                    version: attr_str(e, "version"),
+                    required: attr_str(e, "required") == "true",
+                });
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(AppError::XmlParseError {
+                    file: xml_path.display().to_string(),
+                    message: e.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(mods)
+}
+
 /// Parse careerSavegame.xml with all CareerSavegame fields.
 pub fn parse_career(path: &Path) -> Result<CareerSavegame, AppError> {
-    let xml_path = path.join("careerSavegame.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, CAREER_SAVEGAME);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
 
@@ -151,6 +192,10 @@ pub fn parse_career(path: &Path) -> Result<CareerSavegame, AppError> {
     let mut snow_enabled = false;
     let mut fuel_usage: u8 = 1;
     let mut traffic_enabled = true;
+    let mut guided_tour_completed: Option<bool> = None;
+    // Custom difficulty: explicit factors stored next to the preset
+    let mut price_multiplier: Option<f64> = None;
+    let mut cost_multiplier: Option<f64> = None;
 
     let mut current_tag = String::new();
     let mut in_settings = false;
@@ -174,6 +219,9 @@ pub fn parse_career(path: &Path) -> Result<CareerSavegame, AppError> {
                             }
                         }
                     }
+                    "guidedTour" => {
+                        guided_tour_completed = attr_str_opt(e, "completed").map(|v| v == "true");
+                    }
                     _ => {}
                 }
                 if in_settings {
@@ -221,6 +269,8 @@ pub fn parse_career(path: &Path) -> Result<CareerSavegame, AppError> {
                         "snowEnabled" | "isSnowEnabled" => snow_enabled = text.trim() == "true",
                         "fuelUsage" => fuel_usage = text.trim().parse().unwrap_or(1),
                         "trafficEnabled" => traffic_enabled = text.trim() == "true",
+                        "priceMultiplier" => price_multiplier = text.trim().parse().ok(),
+                        "costMultiplier" => cost_multiplier = text.trim().parse().ok(),
                         _ => {}
                     }
                 } else if in_statistics {
@@ -243,6 +293,8 @@ pub fn parse_career(path: &Path) -> Result<CareerSavegame, AppError> {
                             _ => {}
                         }
                     }
+                } else if tag_name == "guidedTour" {
+                    guided_tour_completed = attr_str_opt(e, "completed").map(|v| v == "true");
                 }
             }
             Ok(Event::Eof) => break,
@@ -256,9 +308,20 @@ pub fn parse_career(path: &Path) -> Result<CareerSavegame, AppError> {
         }
     }
 
+    let mut economic_multipliers = EconomicMultipliers::for_difficulty(&economic_difficulty);
+    if price_multiplier.is_some() || cost_multiplier.is_some() {
+        economic_multipliers = EconomicMultipliers {
+            income: price_multiplier.unwrap_or(economic_multipliers.income),
+            expenses: cost_multiplier.unwrap_or(economic_multipliers.expenses),
+            custom: true,
+        };
+    }
+
     Ok(CareerSavegame {
         savegame_name,
         creation_date,
+        map_display: map_display_name(&map_id, &map_title),
+        is_mod_map: is_mod_map(&map_id),
         map_id,
         map_title,
         save_date,
@@ -274,6 +337,8 @@ pub fn parse_career(path: &Path) -> Result<CareerSavegame, AppError> {
         snow_enabled,
         fuel_usage,
         traffic_enabled,
+        guided_tour_completed,
+        economic_multipliers,
     })
 }
 
@@ -295,10 +360,14 @@ mod tests {
         let summary = parse_career_summary(&path).unwrap();
         assert_eq!(summary.name, "Ma partie");
         assert_eq!(summary.map_title, "Riverbend Springs");
+        assert_eq!(summary.map_id, "MapUS");
+        assert_eq!(summary.map_display, "Riverbend Springs");
+        assert!(!summary.is_mod_map);
         assert!((summary.money - 1_000_000.0).abs() < 0.01);
         assert!((summary.play_time - 12345.678).abs() < 0.01);
         assert_eq!(summary.save_date, "2025-01-15");
         assert_eq!(summary.economic_difficulty, "NORMAL");
+        assert!(!summary.is_multiplayer);
     }
 
     #[test]
@@ -307,9 +376,29 @@ mod tests {
         let summary = parse_career_summary(&path).unwrap();
         assert_eq!(summary.name, "Partie 2");
         assert_eq!(summary.map_title, "Elm Creek");
+        // No mapId: the title is all there is
+        assert_eq!(summary.map_display, "Elm Creek");
         assert_eq!(summary.economic_difficulty, "EASY");
     }
 
+    #[test]
+    fn test_parse_savegame_mods() {
+        let path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_modded");
+        let mods = parse_savegame_mods(&path).unwrap();
+        assert_eq!(mods.len(), 2);
+        assert_eq!(mods[0].mod_name, "FS25_AutoDrive");
+        assert_eq!(mods[0].version, "3.0.0.4");
+        assert!(!mods[0].required);
+        assert!(mods[1].required);
+
+        assert!(parse_savegame_mods(&fixtures_path().join("savegame2"))
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_parse_career_summary_invalid_xml() {
         let dir = std::env::temp_dir().join("fs25_test_invalid_xml");
@@ -332,4 +421,61 @@ mod tests {
 
         let _ = fs::remove_dir_all(&dir);
     }
+
+    #[test]
+    fn test_parse_career_latin1() {
+        let dir = std::env::temp_dir().join("fs25_test_career_latin1");
+        let _ = fs::create_dir_all(&dir);
+        let mut xml = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?>\n<careerSavegame>\n  <settings>\n    <savegameName>Ferme Dupr".to_vec();
+        xml.push(0xE9); // 'é' in Latin-1
+        xml.extend_from_slice(b"</savegameName>\n    <mapTitle>Riverbend Springs</mapTitle>\n  </settings>\n</careerSavegame>\n");
+        fs::write(dir.join("careerSavegame.xml"), &xml).unwrap();
+
+        let career = parse_career(&dir).unwrap();
+        assert_eq!(career.savegame_name, "Ferme Dupré");
+        assert_eq!(career.map_title, "Riverbend Springs");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_parse_career_summary_utf8_bom() {
+        let dir = std::env::temp_dir().join("fs25_test_career_bom");
+        let _ = fs::create_dir_all(&dir);
+        let mut xml = vec![0xEF, 0xBB, 0xBF];
+        xml.extend_from_slice("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<careerSavegame><settings><savegameName>Étang</savegameName></settings></careerSavegame>".as_bytes());
+        fs::write(dir.join("careerSavegame.xml"), &xml).unwrap();
+
+        let summary = parse_career_summary(&dir).unwrap();
+        assert_eq!(summary.name, "Étang");
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_parse_career_economic_multipliers() {
+        let path = fixtures_path().join("savegame1");
+        let career = parse_career(&path).unwrap();
+        assert_eq!(
+            career.economic_multipliers,
+            EconomicMultipliers::for_difficulty(&career.economic_difficulty)
+        );
+        assert!(!career.economic_multipliers.custom);
+
+        let dir = std::env::temp_dir().join("fs25_test_career_custom_difficulty");
+        let _ = fs::create_dir_all(&dir);
+        fs::write(
+            dir.join("careerSavegame.xml"),
+            "<careerSavegame><settings><economicDifficulty>3</economicDifficulty><priceMultiplier>2.5</priceMultiplier></settings></careerSavegame>",
+        )
+        .unwrap();
+
+        let career = parse_career(&dir).unwrap();
+        assert_eq!(career.economic_difficulty, "HARD");
+        assert_eq!(career.economic_multipliers.income, 2.5);
+        assert_eq!(career.economic_multipliers.expenses, 1.0);
+        assert!(career.economic_multipliers.custom);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
 }
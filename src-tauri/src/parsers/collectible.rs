@@ -1,29 +1,45 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use quick_xml::events::Event;
 use quick_xml::Reader;
 
 use crate::error::AppError;
-use crate::models::collectible::Collectible;
+use crate::models::collectible::{Collectible, CollectibleMetadata};
+use crate::parsers::common::{attr_str, attr_str_opt, attr_u32, read_xml_file};
+use crate::parsers::files::{savegame_file, COLLECTIBLES};
 
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
+/// Number of collectibles on the base game maps, shown in game as #1 to #25.
+pub const BASE_COLLECTIBLE_COUNT: u32 = 25;
+
+/// Optional JSON file in the savegame folder mapping collectible indices to display
+/// metadata, e.g. `{"3": {"name": "Rusty sign", "region": "North hills"}}`.
+/// It overrides the built-in names, e.g. for a modded map.
+pub const COLLECTIBLE_METADATA_FILE: &str = "collectibleMetadata.json";
+
+/// Built-in metadata of the base game collectibles: the number the game shows
+/// for them. Indices past the base game count have none.
+pub fn builtin_collectible_metadata(index: u32) -> Option<CollectibleMetadata> {
+    (index < BASE_COLLECTIBLE_COUNT).then(|| CollectibleMetadata {
+        name: format!("#{}", index + 1),
+        region: None,
+    })
 }
 
-fn attr_u32(e: &quick_xml::events::BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
+/// Load the collectible metadata overrides. A missing or malformed file yields an
+/// empty map.
+pub fn load_collectible_metadata(path: &Path) -> HashMap<u32, CollectibleMetadata> {
+    std::fs::read_to_string(path.join(COLLECTIBLE_METADATA_FILE))
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
 }
 
 pub fn parse_collectibles(path: &Path) -> Result<Vec<Collectible>, AppError> {
-    let xml_path = path.join("collectibles.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, COLLECTIBLES);
+    let content = read_xml_file(&xml_path)?;
 
+    let metadata = load_collectible_metadata(path);
     let mut reader = Reader::from_str(&content);
     let mut collectibles: Vec<Collectible> = Vec::new();
 
@@ -32,9 +48,20 @@ pub fn parse_collectibles(path: &Path) -> Result<Vec<Collectible>, AppError> {
             Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if tag == "collectible" {
+                    let index = attr_u32(e, "index");
+                    let meta = metadata
+                        .get(&index)
+                        .cloned()
+                        .or_else(|| builtin_collectible_metadata(index));
                     collectibles.push(Collectible {
-                        index: attr_u32(e, "index"),
+                        index,
                         collected: attr_str(e, "isCollected") == "true",
+                        name: meta
+                            .as_ref()
+                            .map(|m| m.name.clone())
+                            .unwrap_or_else(|| index.to_string()),
+                        region: meta.and_then(|m| m.region),
+                        marked: attr_str_opt(e, "isMarked").map(|v| v == "true"),
                     });
                 }
             }
@@ -83,4 +110,56 @@ mod tests {
         assert!(matches!(result, Err(AppError::IoError { .. })));
         let _ = std::fs::remove_dir_all(&dir);
     }
+
+    #[test]
+    fn test_parse_collectibles_builtin_names() {
+        let path = fixtures_path().join("savegame_complete");
+        let collectibles = parse_collectibles(&path).unwrap();
+        assert_eq!(collectibles[0].name, "#1");
+        assert_eq!(collectibles[24].name, "#25");
+        assert!(collectibles[3].region.is_none());
+    }
+
+    #[test]
+    fn test_parse_collectibles_metadata_fallback() {
+        let dir = std::env::temp_dir().join("fs25_test_collectible_fallback");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("collectibles.xml"),
+            r#"<collectibles><collectible index="30" isCollected="false" /></collectibles>"#,
+        )
+        .unwrap();
+
+        // Past the base game collectibles, the raw index is shown
+        let collectibles = parse_collectibles(&dir).unwrap();
+        assert_eq!(collectibles[0].name, "30");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_parse_collectibles_metadata_override() {
+        let dir = std::env::temp_dir().join("fs25_test_collectible_metadata");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(
+            fixtures_path().join("savegame_complete").join("collectibles.xml"),
+            dir.join("collectibles.xml"),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join(COLLECTIBLE_METADATA_FILE),
+            r#"{"1": {"name": "Vieille enseigne", "region": "Nord"}, "2": {"name": "Cloche"}}"#,
+        )
+        .unwrap();
+
+        let collectibles = parse_collectibles(&dir).unwrap();
+        assert_eq!(collectibles[1].name, "Vieille enseigne");
+        assert_eq!(collectibles[1].region.as_deref(), Some("Nord"));
+        assert_eq!(collectibles[2].name, "Cloche");
+        assert!(collectibles[2].region.is_none());
+        // Collectibles the file does not list keep their built-in name
+        assert_eq!(collectibles[0].name, "#1");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,506 @@
+use std::borrow::Cow;
+use std::io::Read;
+use std::path::Path;
+use std::str::FromStr;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::error::AppError;
+use crate::models::common::Position;
+use crate::parsers::files::{savegame_file, SAVEGAME_FILES};
+
+/// Text encoding of a savegame XML file, kept so that writers save it back
+/// the way it was read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum XmlEncoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    /// Only when the declaration announces it, so bytes written back match it
+    Latin1,
+    /// UTF-8 with stray invalid bytes, typically a name typed in a Latin-1 editor.
+    /// Those bytes are read as Latin-1 characters and written back as valid UTF-8.
+    RepairedUtf8,
+    /// `RepairedUtf8` behind a UTF-8 BOM, written back with the BOM
+    RepairedUtf8Bom,
+}
+
+impl XmlEncoding {
+    /// Name of the encoding as written in an XML declaration.
+    pub fn declaration_name(self) -> &'static str {
+        match self {
+            XmlEncoding::Utf8
+            | XmlEncoding::Utf8Bom
+            | XmlEncoding::RepairedUtf8
+            | XmlEncoding::RepairedUtf8Bom => "utf-8",
+            XmlEncoding::Utf16Le | XmlEncoding::Utf16Be => "utf-16",
+            XmlEncoding::Latin1 => "iso-8859-1",
+        }
+    }
+}
+
+const UTF8_BOM: &[u8] = &[0xEF, 0xBB, 0xBF];
+
+/// Whether the `<?xml ...?>` declaration announces a single-byte Western encoding.
+fn declares_latin1(bytes: &[u8]) -> bool {
+    let head: Vec<u8> = bytes.iter().take(128).map(|b| b.to_ascii_lowercase()).collect();
+    let head = String::from_utf8_lossy(&head);
+    let Some(end) = head.find("?>") else {
+        return false;
+    };
+    let decl = &head[..end];
+    ["iso-8859-1", "iso-8859-15", "latin1", "latin-1", "windows-1252"]
+        .iter()
+        .any(|name| decl.contains(name))
+}
+
+fn decode_utf16(bytes: &[u8], little_endian: bool) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| {
+            if little_endian {
+                u16::from_le_bytes([pair[0], pair[1]])
+            } else {
+                u16::from_be_bytes([pair[0], pair[1]])
+            }
+        })
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// Decode UTF-8 `bytes` holding invalid sequences, reading each invalid byte as a
+/// Latin-1 character.
+fn decode_repaired_utf8(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len());
+    let mut rest = bytes;
+    loop {
+        match std::str::from_utf8(rest) {
+            Ok(valid) => {
+                text.push_str(valid);
+                return text;
+            }
+            Err(e) => {
+                let (valid, invalid) = rest.split_at(e.valid_up_to());
+                text.push_str(std::str::from_utf8(valid).unwrap_or_default());
+                let invalid_len = e.error_len().unwrap_or(invalid.len());
+                text.extend(invalid[..invalid_len].iter().map(|&b| b as char));
+                rest = &invalid[invalid_len..];
+            }
+        }
+    }
+}
+
+/// Decode the raw bytes of an XML file. A BOM selects UTF-8 or UTF-16; otherwise the
+/// file is Latin-1 when its declaration says so, and UTF-8 in every other case.
+/// Bytes that are not valid UTF-8 are repaired one by one, so the file is written
+/// back as the UTF-8 its declaration announces.
+pub fn decode_xml(bytes: &[u8]) -> (String, XmlEncoding) {
+    if let Some(rest) = bytes.strip_prefix(UTF8_BOM) {
+        return match std::str::from_utf8(rest) {
+            Ok(text) => (text.to_string(), XmlEncoding::Utf8Bom),
+            Err(_) => (decode_repaired_utf8(rest), XmlEncoding::RepairedUtf8Bom),
+        };
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return (decode_utf16(rest, true), XmlEncoding::Utf16Le);
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        return (decode_utf16(rest, false), XmlEncoding::Utf16Be);
+    }
+
+    if declares_latin1(bytes) {
+        return (bytes.iter().map(|&b| b as char).collect(), XmlEncoding::Latin1);
+    }
+    match std::str::from_utf8(bytes) {
+        Ok(text) => (text.to_string(), XmlEncoding::Utf8),
+        Err(_) => (decode_repaired_utf8(bytes), XmlEncoding::RepairedUtf8),
+    }
+}
+
+/// Encode UTF-8 output of a writer back to `encoding`. Characters Latin-1 cannot
+/// represent are written as XML character references.
+pub fn encode_xml(utf8: &[u8], encoding: XmlEncoding) -> Cow<'_, [u8]> {
+    match encoding {
+        XmlEncoding::Utf8 | XmlEncoding::RepairedUtf8 => Cow::Borrowed(utf8),
+        XmlEncoding::Utf8Bom | XmlEncoding::RepairedUtf8Bom => {
+            let mut bytes = UTF8_BOM.to_vec();
+            bytes.extend_from_slice(utf8);
+            Cow::Owned(bytes)
+        }
+        XmlEncoding::Utf16Le | XmlEncoding::Utf16Be => {
+            let text = String::from_utf8_lossy(utf8);
+            let little_endian = encoding == XmlEncoding::Utf16Le;
+            let mut bytes = if little_endian {
+                vec![0xFF, 0xFE]
+            } else {
+                vec![0xFE, 0xFF]
+            };
+            for unit in text.encode_utf16() {
+                if little_endian {
+                    bytes.extend_from_slice(&unit.to_le_bytes());
+                } else {
+                    bytes.extend_from_slice(&unit.to_be_bytes());
+                }
+            }
+            Cow::Owned(bytes)
+        }
+        XmlEncoding::Latin1 => {
+            let text = String::from_utf8_lossy(utf8);
+            let mut bytes = Vec::with_capacity(text.len());
+            for ch in text.chars() {
+                if (ch as u32) <= 0xFF {
+                    bytes.push(ch as u8);
+                } else {
+                    bytes.extend_from_slice(format!("&#{};", ch as u32).as_bytes());
+                }
+            }
+            Cow::Owned(bytes)
+        }
+    }
+}
+
+/// Whether decoded XML text starts with a complete `<?xml ...?>` declaration.
+/// Leading whitespace, a truncated declaration or a missing one all fail.
+pub fn has_xml_declaration(content: &str) -> bool {
+    let Some(rest) = content.strip_prefix("<?xml") else {
+        return false;
+    };
+    if !rest.starts_with(char::is_whitespace) {
+        return false;
+    }
+    match rest.find("?>") {
+        Some(end) => !rest[..end].contains('<') && rest[..end].contains("version"),
+        None => false,
+    }
+}
+
+/// Bytes read from the start of a file to find its XML declaration.
+const XML_HEAD_LEN: u64 = 256;
+
+/// The first `XML_HEAD_LEN` bytes of `file`, decoded.
+fn read_xml_head(file: &Path) -> Option<String> {
+    let mut head = Vec::new();
+    std::fs::File::open(file)
+        .ok()?
+        .take(XML_HEAD_LEN)
+        .read_to_end(&mut head)
+        .ok()?;
+    Some(decode_xml(&head).0)
+}
+
+/// Names on disk of the savegame files in `dir` not starting with a `<?xml ...?>`
+/// declaration; the game refuses to load such a file. Only the head of each file
+/// is read, and missing files are skipped.
+pub fn files_without_xml_declaration(dir: &Path) -> Vec<String> {
+    let mut files: Vec<String> = SAVEGAME_FILES
+        .iter()
+        .map(|name| savegame_file(dir, name))
+        .filter(|file| read_xml_head(file).is_some_and(|head| !has_xml_declaration(&head)))
+        .filter_map(|file| Some(file.file_name()?.to_string_lossy().to_string()))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Read an XML file of the savegame, whatever its encoding, along with that encoding.
+/// A file cut short (a save interrupted mid-write) is refused with `TruncatedFile`.
+pub fn read_xml_file_with_encoding(xml_path: &Path) -> Result<(String, XmlEncoding), AppError> {
+    let bytes = std::fs::read(xml_path).map_err(|e| AppError::IoError {
+        message: format!("{}: {}", xml_path.display(), e),
+    })?;
+    let (content, encoding) = decode_xml(&bytes);
+    if is_truncated_xml(&content) {
+        return Err(AppError::TruncatedFile {
+            file: xml_path.display().to_string(),
+        });
+    }
+    Ok((content, encoding))
+}
+
+/// Read an XML file for display only: a truncated file is cut back to its last
+/// complete top-level element and its root closed again. The second value tells
+/// whether such a recovery took place.
+pub fn read_partial_xml_file(xml_path: &Path) -> Result<(String, bool), AppError> {
+    let bytes = std::fs::read(xml_path).map_err(|e| AppError::IoError {
+        message: format!("{}: {}", xml_path.display(), e),
+    })?;
+    let (content, _) = decode_xml(&bytes);
+    if !is_truncated_xml(&content) {
+        return Ok((content, false));
+    }
+    recover_truncated_xml(&content)
+        .map(|recovered| (recovered, true))
+        .ok_or_else(|| AppError::TruncatedFile {
+            file: xml_path.display().to_string(),
+        })
+}
+
+/// Whether the document ends before its root element is closed. Power loss
+/// can also leave the tail of the file zeroed, which counts as missing.
+pub fn is_truncated_xml(content: &str) -> bool {
+    let mut reader = Reader::from_str(content);
+    let root = loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => break e.name().as_ref().to_vec(),
+            // A self-closing root, or nothing the parser can make sense of
+            Ok(Event::Empty(_)) | Ok(Event::Eof) | Err(_) => return false,
+            _ => {}
+        }
+    };
+
+    let closing = format!("</{}>", String::from_utf8_lossy(&root));
+    !content
+        .trim_end_matches(|c: char| c.is_whitespace() || c == '\0')
+        .ends_with(&closing)
+}
+
+/// The part of `content` up to its last complete child of the root element,
+/// with the root closed again. `None` when not even the root start tag is complete.
+pub fn recover_truncated_xml(content: &str) -> Option<String> {
+    let mut reader = Reader::from_str(content);
+    let mut depth: usize = 0;
+    let mut root: Option<String> = None;
+    let mut complete_end: usize = 0;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                if depth == 0 {
+                    root = Some(String::from_utf8_lossy(e.name().as_ref()).into_owned());
+                    complete_end = reader.buffer_position() as usize;
+                }
+                depth += 1;
+            }
+            Ok(Event::End(_)) => {
+                depth = depth.saturating_sub(1);
+                if depth == 1 {
+                    complete_end = reader.buffer_position() as usize;
+                }
+            }
+            Ok(Event::Empty(_)) if depth == 1 => {
+                complete_end = reader.buffer_position() as usize;
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    let root = root?;
+    Some(format!("{}\n</{}>", &content[..complete_end], root))
+}
+
+/// Read an XML file of the savegame as text, whatever its encoding.
+pub fn read_xml_file(xml_path: &Path) -> Result<String, AppError> {
+    read_xml_file_with_encoding(xml_path).map(|(content, _)| content)
+}
+
+/// Value of an attribute with XML entities resolved, or an empty string when absent.
+/// A value with a malformed entity is returned as written.
+pub fn attr_str(e: &BytesStart, key: &str) -> String {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == key.as_bytes())
+        .map(|a| match a.unescape_value() {
+            Ok(value) => value.into_owned(),
+            Err(_) => String::from_utf8_lossy(&a.value).into_owned(),
+        })
+        .unwrap_or_default()
+}
+
+pub fn attr_str_opt(e: &BytesStart, key: &str) -> Option<String> {
+    let s = attr_str(e, key);
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+/// Parse an unsigned integer, tolerating surrounding whitespace and a float
+/// notation with no fractional part (`"3.000000"`), which some tools write.
+fn parse_uint<T: FromStr + TryFrom<u64>>(value: &str) -> Option<T> {
+    let value = value.trim();
+    value.parse().ok().or_else(|| {
+        let v: f64 = value.parse().ok()?;
+        if v.fract() == 0.0 && v >= 0.0 && v <= u64::MAX as f64 {
+            T::try_from(v as u64).ok()
+        } else {
+            None
+        }
+    })
+}
+
+fn parse_f64(value: &str) -> Option<f64> {
+    value.trim().parse().ok()
+}
+
+pub fn attr_f64(e: &BytesStart, key: &str) -> f64 {
+    parse_f64(&attr_str(e, key)).unwrap_or(0.0)
+}
+
+pub fn attr_f64_opt(e: &BytesStart, key: &str) -> Option<f64> {
+    parse_f64(&attr_str(e, key))
+}
+
+pub fn attr_u8(e: &BytesStart, key: &str) -> u8 {
+    parse_uint(&attr_str(e, key)).unwrap_or(0)
+}
+
+pub fn attr_u32(e: &BytesStart, key: &str) -> u32 {
+    parse_uint(&attr_str(e, key)).unwrap_or(0)
+}
+
+pub fn attr_u32_opt(e: &BytesStart, key: &str) -> Option<u32> {
+    parse_uint(&attr_str(e, key))
+}
+
+pub fn attr_u64_opt(e: &BytesStart, key: &str) -> Option<u64> {
+    parse_uint(&attr_str(e, key))
+}
+
+pub fn attr_bool(e: &BytesStart, key: &str) -> bool {
+    attr_str(e, key).trim().eq_ignore_ascii_case("true")
+}
+
+/// An attribute holding an `"x y z"` position, `None` when absent or malformed.
+pub fn attr_position(e: &BytesStart, key: &str) -> Option<Position> {
+    let parts: Vec<f64> = attr_str(e, key)
+        .split_whitespace()
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    match parts[..] {
+        [x, y, z] => Some(Position { x, y, z }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_utf8_bom() {
+        let (text, encoding) = decode_xml(b"\xEF\xBB\xBF<a>Dupr\xC3\xA9</a>");
+        assert_eq!(text, "<a>Dupré</a>");
+        assert_eq!(encoding, XmlEncoding::Utf8Bom);
+    }
+
+    #[test]
+    fn test_decode_repaired_utf8() {
+        // A Latin-1 é typed into a UTF-8 file that already holds an en dash
+        let (text, encoding) = decode_xml(b"<a>Ferme Dupr\xE9 \xE2\x80\x93 1</a>");
+        assert_eq!(text, "<a>Ferme Dupré – 1</a>");
+        assert_eq!(encoding, XmlEncoding::RepairedUtf8);
+        // Written back as valid UTF-8
+        let bytes = encode_xml(text.as_bytes(), encoding);
+        assert_eq!(decode_xml(&bytes), (text, XmlEncoding::Utf8));
+    }
+
+    #[test]
+    fn test_decode_repaired_ascii_utf8() {
+        // A single stray byte in an otherwise ASCII file is still damaged UTF-8
+        let xml = b"<?xml version=\"1.0\" encoding=\"utf-8\"?><a>Dupr\xE9</a>";
+        let (text, encoding) = decode_xml(xml);
+        assert!(text.ends_with("<a>Dupré</a>"));
+        assert_eq!(encoding, XmlEncoding::RepairedUtf8);
+        let (text, encoding) = decode_xml(b"<a>Dupr\xE9</a>");
+        assert_eq!(text, "<a>Dupré</a>");
+        assert_eq!(encoding, XmlEncoding::RepairedUtf8);
+    }
+
+    #[test]
+    fn test_decode_repaired_utf8_bom() {
+        let (text, encoding) = decode_xml(b"\xEF\xBB\xBF<a>Dupr\xE9</a>");
+        assert_eq!(text, "<a>Dupré</a>");
+        assert_eq!(encoding, XmlEncoding::RepairedUtf8Bom);
+        // Written back as valid UTF-8, keeping the BOM
+        let bytes = encode_xml(text.as_bytes(), encoding);
+        assert_eq!(decode_xml(&bytes), (text, XmlEncoding::Utf8Bom));
+    }
+
+    #[test]
+    fn test_decode_declared_latin1() {
+        let xml = b"<?xml version=\"1.0\" encoding=\"ISO-8859-1\"?><a>abc</a>";
+        let (_, encoding) = decode_xml(xml);
+        assert_eq!(encoding, XmlEncoding::Latin1);
+    }
+
+    #[test]
+    fn test_encoding_round_trip() {
+        let text = "<a>Ferme Dupré – 1</a>";
+        for encoding in [
+            XmlEncoding::Utf8,
+            XmlEncoding::Utf8Bom,
+            XmlEncoding::Utf16Le,
+            XmlEncoding::Utf16Be,
+        ] {
+            let bytes = encode_xml(text.as_bytes(), encoding);
+            assert_eq!(decode_xml(&bytes), (text.to_string(), encoding));
+        }
+
+        // The dash is outside Latin-1 and becomes a character reference
+        let bytes = encode_xml(text.as_bytes(), XmlEncoding::Latin1);
+        assert_eq!(bytes.as_ref(), b"<a>Ferme Dupr\xE9 &#8211; 1</a>");
+    }
+
+    #[test]
+    fn test_has_xml_declaration() {
+        assert!(has_xml_declaration("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<a/>"));
+        assert!(!has_xml_declaration("<a/>"));
+        assert!(!has_xml_declaration("\n<?xml version=\"1.0\"?><a/>"));
+        assert!(!has_xml_declaration("<?xml version=\"1.0\" <a/>"));
+        assert!(!has_xml_declaration("<?xml-stylesheet href=\"a.xsl\"?><a/>"));
+    }
+
+    #[test]
+    fn test_attr_helpers() {
+        let e = BytesStart::from_content(
+            r#"item name="Dupr&#233; &amp; Fils" count=" 3.000000" price="12.5" flag="TRUE" bad="x""#,
+            4,
+        );
+        assert_eq!(attr_str(&e, "name"), "Dupré & Fils");
+        assert_eq!(attr_u8(&e, "count"), 3);
+        assert_eq!(attr_u32_opt(&e, "price"), None);
+        assert_eq!(attr_f64(&e, "price"), 12.5);
+        assert!(attr_bool(&e, "flag"));
+        assert_eq!(attr_u32(&e, "bad"), 0);
+        assert_eq!(attr_u64_opt(&e, "missing"), None);
+        assert_eq!(attr_str_opt(&e, "missing"), None);
+    }
+
+    #[test]
+    fn test_attr_position() {
+        let e = BytesStart::from_content(r#"tree position="1.5 -2 30" bad="1 2" nan="1 x 2""#, 4);
+        assert_eq!(
+            attr_position(&e, "position").map(|p| (p.x, p.y, p.z)),
+            Some((1.5, -2.0, 30.0))
+        );
+        assert!(attr_position(&e, "bad").is_none());
+        assert!(attr_position(&e, "nan").is_none());
+        assert!(attr_position(&e, "missing").is_none());
+    }
+
+    #[test]
+    fn test_files_without_xml_declaration() {
+        let dir = std::env::temp_dir().join("fs25_test_files_without_declaration");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let declared = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<farms/>";
+        std::fs::write(dir.join("farms.xml"), declared).unwrap();
+        // Found through its file name variant, and reported under that name
+        std::fs::write(dir.join("Vehicles.XML"), "<vehicles/>").unwrap();
+        // Not a savegame file
+        std::fs::write(dir.join("notes.xml"), "<notes/>").unwrap();
+        // Only the head is read: a large body past it does not matter
+        let large = format!(
+            "\u{FEFF}<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<items>{}",
+            "<item/>".repeat(100_000)
+        );
+        std::fs::write(dir.join("items.xml"), large).unwrap();
+
+        assert_eq!(files_without_xml_declaration(&dir), vec!["Vehicles.XML"]);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
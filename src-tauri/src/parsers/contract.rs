@@ -5,24 +5,12 @@ use quick_xml::Reader;
 
 use crate::error::AppError;
 use crate::models::contract::ContractSettings;
-
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
-}
+use crate::parsers::common::{attr_f64, read_xml_file};
+use crate::parsers::files::{savegame_file, CONTRACTS};
 
 pub fn parse_contract_settings(path: &Path) -> Result<ContractSettings, AppError> {
-    let xml_path = path.join("r_contracts.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, CONTRACTS);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut settings = ContractSettings {
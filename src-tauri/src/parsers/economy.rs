@@ -5,41 +5,12 @@ use quick_xml::Reader;
 
 use crate::error::AppError;
 use crate::models::economy::{Economy, FillTypePrice, GreatDemand, PeriodPrice};
-
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
-}
-
-fn attr_u32(e: &quick_xml::events::BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
-
-fn attr_u64_opt(e: &quick_xml::events::BytesStart, key: &str) -> Option<u64> {
-    let s = attr_str(e, key);
-    if s.is_empty() {
-        None
-    } else {
-        s.parse().ok()
-    }
-}
-
-fn attr_bool(e: &quick_xml::events::BytesStart, key: &str) -> bool {
-    attr_str(e, key) == "true"
-}
+use crate::parsers::common::{attr_bool, attr_f64, attr_str, attr_u32, attr_u64_opt, read_xml_file};
+use crate::parsers::files::{savegame_file, ECONOMY};
 
 pub fn parse_economy(path: &Path) -> Result<Economy, AppError> {
-    let xml_path = path.join("economy.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, ECONOMY);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut great_demands: Vec<GreatDemand> = Vec::new();
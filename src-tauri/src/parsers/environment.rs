@@ -1,10 +1,12 @@
 use std::path::Path;
 
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 
 use crate::error::AppError;
 use crate::models::environment::{Environment, WeatherEvent};
+use crate::parsers::common::{attr_f64, attr_str, read_xml_file};
+use crate::parsers::files::{savegame_file, ENVIRONMENT};
 
 fn text_content(reader: &mut Reader<&[u8]>) -> String {
     let mut buf = String::new();
@@ -20,24 +22,22 @@ fn text_content(reader: &mut Reader<&[u8]>) -> String {
     buf.trim().to_string()
 }
 
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
+/// Weather instance of the forecast or of the history of environment.xml.
+fn weather_event(e: &BytesStart) -> WeatherEvent {
+    WeatherEvent {
+        type_name: attr_str(e, "typeName"),
+        season: attr_str(e, "season"),
+        variation_index: attr_str(e, "variationIndex").parse().unwrap_or(0),
+        start_day: attr_str(e, "startDay").parse().unwrap_or(0),
+        start_day_time: attr_str(e, "startDayTime").parse().unwrap_or(0),
+        duration: attr_str(e, "duration").parse().unwrap_or(0),
+    }
 }
 
 /// Parse environment.xml and return the Environment data.
 pub fn parse_environment(path: &Path) -> Result<Environment, AppError> {
-    let xml_path = path.join("environment.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, ENVIRONMENT);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
 
@@ -48,7 +48,11 @@ pub fn parse_environment(path: &Path) -> Result<Environment, AppError> {
     let mut weather_forecast: Vec<WeatherEvent> = Vec::new();
     let mut snow_height: f64 = 0.0;
     let mut ground_wetness: f64 = 0.0;
+    let mut weather_history: Vec<WeatherEvent> = Vec::new();
+    let mut has_history = false;
+    let mut time_since_last_rain: Option<u64> = None;
     let mut in_forecast = false;
+    let mut in_history = false;
     let mut in_weather = false;
 
     loop {
@@ -70,36 +74,26 @@ pub fn parse_environment(path: &Path) -> Result<Environment, AppError> {
                     }
                     "weather" => {
                         in_weather = true;
+                        time_since_last_rain = attr_str(e, "timeSinceLastRain").parse().ok();
                     }
                     "forecast" => {
                         in_forecast = true;
                     }
-                    "instance" if in_forecast => {
-                        weather_forecast.push(WeatherEvent {
-                            type_name: attr_str(e, "typeName"),
-                            season: attr_str(e, "season"),
-                            variation_index: attr_str(e, "variationIndex").parse().unwrap_or(0),
-                            start_day: attr_str(e, "startDay").parse().unwrap_or(0),
-                            start_day_time: attr_str(e, "startDayTime").parse().unwrap_or(0),
-                            duration: attr_str(e, "duration").parse().unwrap_or(0),
-                        });
+                    "history" if in_weather => {
+                        in_history = true;
+                        has_history = true;
                     }
+                    "instance" if in_forecast => weather_forecast.push(weather_event(e)),
+                    "instance" if in_history => weather_history.push(weather_event(e)),
                     _ => {}
                 }
             }
             Ok(Event::Empty(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 match tag.as_str() {
-                    "instance" if in_forecast => {
-                        weather_forecast.push(WeatherEvent {
-                            type_name: attr_str(e, "typeName"),
-                            season: attr_str(e, "season"),
-                            variation_index: attr_str(e, "variationIndex").parse().unwrap_or(0),
-                            start_day: attr_str(e, "startDay").parse().unwrap_or(0),
-                            start_day_time: attr_str(e, "startDayTime").parse().unwrap_or(0),
-                            duration: attr_str(e, "duration").parse().unwrap_or(0),
-                        });
-                    }
+                    "instance" if in_forecast => weather_forecast.push(weather_event(e)),
+                    "instance" if in_history => weather_history.push(weather_event(e)),
+                    "history" if in_weather => has_history = true,
                     "snow" if in_weather => {
                         snow_height = attr_f64(e, "height");
                     }
@@ -113,6 +107,7 @@ pub fn parse_environment(path: &Path) -> Result<Environment, AppError> {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 match tag.as_str() {
                     "forecast" => in_forecast = false,
+                    "history" => in_history = false,
                     "weather" => in_weather = false,
                     _ => {}
                 }
@@ -136,6 +131,8 @@ pub fn parse_environment(path: &Path) -> Result<Environment, AppError> {
         weather_forecast,
         snow_height,
         ground_wetness,
+        weather_history: has_history.then_some(weather_history),
+        time_since_last_rain,
     })
 }
 
@@ -166,6 +163,39 @@ mod tests {
         assert_eq!(env.weather_forecast[3].type_name, "TWISTER");
         assert!((env.snow_height - 0.5).abs() < 0.01);
         assert!((env.ground_wetness - 0.3).abs() < 0.01);
+        assert_eq!(env.time_since_last_rain, Some(1243));
+        // This save does not keep past weather
+        assert!(env.weather_history.is_none());
+    }
+
+    #[test]
+    fn test_parse_environment_weather_history() {
+        let dir = std::env::temp_dir().join("fs25_test_env_history");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<environment>
+    <dayTime>100.0</dayTime>
+    <currentDay>12</currentDay>
+    <weather timeSinceLastRain="30">
+        <history>
+            <instance typeName="SNOW" season="WINTER" variationIndex="1" startDay="10" startDayTime="0" duration="7200000"/>
+            <instance typeName="RAIN" season="WINTER" variationIndex="2" startDay="11" startDayTime="3600000" duration="3600000"/>
+        </history>
+        <forecast>
+            <instance typeName="SUN" season="WINTER" variationIndex="1" startDay="12" startDayTime="0" duration="3600000"/>
+        </forecast>
+    </weather>
+</environment>"#;
+        std::fs::write(dir.join("environment.xml"), xml).unwrap();
+        let env = parse_environment(&dir).unwrap();
+        let history = env.weather_history.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].type_name, "SNOW");
+        assert_eq!(history[1].start_day, 11);
+        assert_eq!(env.weather_forecast.len(), 1);
+        assert_eq!(env.time_since_last_rain, Some(30));
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
     #[test]
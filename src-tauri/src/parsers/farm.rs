@@ -5,30 +5,8 @@ use quick_xml::Reader;
 
 use crate::error::AppError;
 use crate::models::farm::{DailyFinance, Farm, FarmPlayer, FarmStatistics};
-
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
-}
-
-fn attr_u32(e: &quick_xml::events::BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
-
-fn attr_u8(e: &quick_xml::events::BytesStart, key: &str) -> u8 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
-
-fn attr_bool(e: &quick_xml::events::BytesStart, key: &str) -> bool {
-    attr_str(e, key) == "true"
-}
+use crate::parsers::common::{attr_bool, attr_f64, attr_str, attr_u32, attr_u8, read_xml_file};
+use crate::parsers::files::{savegame_file, FARMS};
 
 /// Read the text content of the current element from the reader.
 fn read_text_content(reader: &mut Reader<&[u8]>) -> String {
@@ -45,6 +23,13 @@ fn read_text_content(reader: &mut Reader<&[u8]>) -> String {
     text
 }
 
+/// Parse a statistics counter. Modded or long-running saves can exceed `u32`, and some
+/// write counters as floats, so fall back to a saturating float conversion.
+fn parse_count(text: &str) -> u64 {
+    text.parse::<u64>()
+        .unwrap_or_else(|_| text.parse::<f64>().map(|f| f as u64).unwrap_or(0))
+}
+
 /// Parse child elements of <statistics> into FarmStatistics.
 fn parse_statistics_children(reader: &mut Reader<&[u8]>) -> FarmStatistics {
     let mut stats = FarmStatistics::default();
@@ -54,7 +39,7 @@ fn parse_statistics_children(reader: &mut Reader<&[u8]>) -> FarmStatistics {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 let text = read_text_content(reader);
                 let f = text.parse::<f64>().unwrap_or(0.0);
-                let u = text.parse::<u32>().unwrap_or(0);
+                let u = parse_count(&text);
                 match tag.as_str() {
                     // Distances
                     "traveledDistance" => stats.traveled_distance = f,
@@ -173,10 +158,8 @@ fn parse_daily_finance_children(reader: &mut Reader<&[u8]>, day: u32) -> DailyFi
 
 /// Parse farms.xml and return the list of farms with their players, stats and finances.
 pub fn parse_farms(path: &Path) -> Result<Vec<Farm>, AppError> {
-    let xml_path = path.join("farms.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, FARMS);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut farms: Vec<Farm> = Vec::new();
@@ -196,6 +179,7 @@ pub fn parse_farms(path: &Path) -> Result<Vec<Farm>, AppError> {
                             name: attr_str(e, "name"),
                             color: attr_u8(e, "color"),
                             loan: attr_f64(e, "loan"),
+                            loan_interest_rate: attr_str(e, "loanInterestRate").parse().ok(),
                             money: attr_f64(e, "money"),
                             players: Vec::new(),
                             statistics: FarmStatistics::default(),
@@ -279,6 +263,42 @@ pub fn parse_farms(path: &Path) -> Result<Vec<Farm>, AppError> {
     Ok(farms)
 }
 
+/// Count the players registered on every farm of farms.xml, without parsing the
+/// statistics and finances. A savegame without farms.xml has no players.
+pub fn count_farm_players(path: &Path) -> Result<usize, AppError> {
+    let xml_path = savegame_file(path, FARMS);
+    if !xml_path.exists() {
+        return Ok(0);
+    }
+    let content = read_xml_file(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut in_players = false;
+    let mut count = 0;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"players" => in_players = true,
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"players" => in_players = false,
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e))
+                if in_players && e.name().as_ref() == b"player" =>
+            {
+                count += 1;
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(AppError::XmlParseError {
+                    file: xml_path.display().to_string(),
+                    message: e.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(count)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -313,4 +333,37 @@ mod tests {
         assert!(matches!(result, Err(AppError::IoError { .. })));
         let _ = std::fs::remove_dir_all(&dir);
     }
+
+    #[test]
+    fn test_count_farm_players() {
+        let path = fixtures_path().join("savegame_complete");
+        assert_eq!(count_farm_players(&path).unwrap(), 1);
+
+        let dir = std::env::temp_dir().join("fs25_test_count_players");
+        let _ = std::fs::create_dir_all(&dir);
+        std::fs::write(
+            dir.join("farms.xml"),
+            r#"<farms>
+  <farm farmId="1" name="A"><players><player uniqueUserId="a"/><player uniqueUserId="b"/></players></farm>
+  <farm farmId="2" name="B"><players><player uniqueUserId="c"/></players></farm>
+</farms>"#,
+        )
+        .unwrap();
+        assert_eq!(count_farm_players(&dir).unwrap(), 3);
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let empty = std::env::temp_dir().join("fs25_test_count_players_none");
+        let _ = std::fs::create_dir_all(&empty);
+        assert_eq!(count_farm_players(&empty).unwrap(), 0);
+        let _ = std::fs::remove_dir_all(&empty);
+    }
+
+    #[test]
+    fn test_parse_count_beyond_u32() {
+        assert_eq!(parse_count("5000000000"), 5_000_000_000);
+        assert_eq!(parse_count("42.0"), 42);
+        assert_eq!(parse_count("1e30"), u64::MAX);
+        assert_eq!(parse_count("-3"), 0);
+        assert_eq!(parse_count("garbage"), 0);
+    }
 }
@@ -5,29 +5,13 @@ use quick_xml::Reader;
 
 use crate::error::AppError;
 use crate::models::field::{Farmland, Field};
-
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_u32(e: &quick_xml::events::BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
-
-fn attr_u8(e: &quick_xml::events::BytesStart, key: &str) -> u8 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
+use crate::parsers::common::{attr_str, attr_u32, attr_u8, read_xml_file};
+use crate::parsers::files::{savegame_file, FARMLAND, FIELDS};
 
 /// Parse fields.xml and return the list of fields.
 pub fn parse_fields(path: &Path) -> Result<Vec<Field>, AppError> {
-    let xml_path = path.join("fields.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, FIELDS);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut fields: Vec<Field> = Vec::new();
@@ -72,10 +56,8 @@ pub fn parse_fields(path: &Path) -> Result<Vec<Field>, AppError> {
 
 /// Parse farmland.xml and return the list of farmlands.
 pub fn parse_farmlands(path: &Path) -> Result<Vec<Farmland>, AppError> {
-    let xml_path = path.join("farmland.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, FARMLAND);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut farmlands: Vec<Farmland> = Vec::new();
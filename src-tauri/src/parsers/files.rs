@@ -0,0 +1,161 @@
+//! Names of the savegame files, and how they are found in a savegame folder.
+//! Parsers and writers go through `savegame_file` rather than joining names
+//! themselves, so a file the game renames only needs an override or a variant rule.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use crate::error::AppError;
+
+pub const CAREER_SAVEGAME: &str = "careerSavegame.xml";
+pub const FARMS: &str = "farms.xml";
+pub const VEHICLES: &str = "vehicles.xml";
+pub const SALES: &str = "sales.xml";
+pub const FIELDS: &str = "fields.xml";
+pub const FARMLAND: &str = "farmland.xml";
+pub const PLACEABLES: &str = "placeables.xml";
+pub const MISSIONS: &str = "missions.xml";
+pub const COLLECTIBLES: &str = "collectibles.xml";
+pub const TREE_PLANT: &str = "treePlant.xml";
+pub const CONTRACTS: &str = "r_contracts.xml";
+pub const ENVIRONMENT: &str = "environment.xml";
+pub const ECONOMY: &str = "economy.xml";
+pub const ITEMS: &str = "items.xml";
+
+/// Every savegame file the editor reads or writes, by its expected name.
+pub const SAVEGAME_FILES: &[&str] = &[
+    CAREER_SAVEGAME,
+    FARMS,
+    VEHICLES,
+    SALES,
+    FIELDS,
+    FARMLAND,
+    PLACEABLES,
+    MISSIONS,
+    COLLECTIBLES,
+    TREE_PLANT,
+    CONTRACTS,
+    ENVIRONMENT,
+    ECONOMY,
+    ITEMS,
+];
+
+/// Prefix the game puts on some file names (`r_contracts.xml`) and not others.
+const NAME_PREFIX: &str = "r_";
+
+/// Names set by the user, by expected name. They apply to every savegame.
+static NAME_OVERRIDES: RwLock<Option<HashMap<String, String>>> = RwLock::new(None);
+
+/// Replace the file name overrides, each mapping an expected name of
+/// `SAVEGAME_FILES` to the name to use instead. An empty map removes them all.
+pub fn set_name_overrides(overrides: HashMap<String, String>) -> Result<(), AppError> {
+    validate_name_overrides(&overrides)?;
+    *NAME_OVERRIDES.write().unwrap_or_else(|e| e.into_inner()) = Some(overrides);
+    Ok(())
+}
+
+/// Reject overrides of unknown files, and names that are not a plain XML file name.
+pub fn validate_name_overrides(overrides: &HashMap<String, String>) -> Result<(), AppError> {
+    for (expected, name) in overrides {
+        if !SAVEGAME_FILES.contains(&expected.as_str()) {
+            return Err(AppError::SettingsError {
+                message: format!("Unknown savegame file: {}", expected),
+            });
+        }
+        let is_plain_xml_name = Path::new(name)
+            .file_name()
+            .is_some_and(|n| n == name.as_str())
+            && name.to_lowercase().ends_with(".xml");
+        if !is_plain_xml_name {
+            return Err(AppError::SettingsError {
+                message: format!("Invalid file name for {}: {}", expected, name),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Path of the savegame file expected as `name` in `dir`, honoring the user's
+/// overrides. When the file is missing under its name, a variant is looked for;
+/// with none either, the expected path is returned so errors name it.
+pub fn savegame_file(dir: &Path, name: &str) -> PathBuf {
+    let overrides = NAME_OVERRIDES.read().unwrap_or_else(|e| e.into_inner());
+    resolve_file(dir, name, overrides.as_ref())
+}
+
+fn resolve_file(dir: &Path, name: &str, overrides: Option<&HashMap<String, String>>) -> PathBuf {
+    let name = overrides
+        .and_then(|o| o.get(name))
+        .map(String::as_str)
+        .unwrap_or(name);
+    let expected = dir.join(name);
+    if expected.exists() {
+        return expected;
+    }
+    find_variant(dir, name).unwrap_or(expected)
+}
+
+/// A file of `dir` named like `name` but for the case of its letters (saves
+/// copied from Windows to a case-sensitive file system) or the `r_` prefix.
+fn find_variant(dir: &Path, name: &str) -> Option<PathBuf> {
+    let name = name.to_lowercase();
+    let unprefixed = name.strip_prefix(NAME_PREFIX).unwrap_or(&name);
+    let prefixed = format!("{}{}", NAME_PREFIX, unprefixed);
+
+    std::fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.is_file()
+                && path.file_name().is_some_and(|file_name| {
+                    let file_name = file_name.to_string_lossy().to_lowercase();
+                    file_name == unprefixed || file_name == prefixed
+                })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_file_variants() {
+        let dir = std::env::temp_dir().join("fs25_test_files_variants");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join(FARMS), "<farms/>").unwrap();
+        std::fs::write(dir.join("contracts.xml"), "<contracts/>").unwrap();
+        std::fs::write(dir.join("Economy.XML"), "<economy/>").unwrap();
+
+        assert_eq!(resolve_file(&dir, FARMS, None), dir.join(FARMS));
+        assert_eq!(
+            resolve_file(&dir, CONTRACTS, None),
+            dir.join("contracts.xml")
+        );
+        assert_eq!(resolve_file(&dir, ECONOMY, None), dir.join("Economy.XML"));
+        // Nothing like it: the expected path
+        assert_eq!(resolve_file(&dir, MISSIONS, None), dir.join(MISSIONS));
+
+        let overrides = HashMap::from([(FARMS.to_string(), "farms2.xml".to_string())]);
+        std::fs::write(dir.join("farms2.xml"), "<farms/>").unwrap();
+        assert_eq!(
+            resolve_file(&dir, FARMS, Some(&overrides)),
+            dir.join("farms2.xml")
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_validate_name_overrides() {
+        let valid = HashMap::from([(CONTRACTS.to_string(), "contracts.xml".to_string())]);
+        assert!(validate_name_overrides(&valid).is_ok());
+
+        let unknown = HashMap::from([("nope.xml".to_string(), "a.xml".to_string())]);
+        assert!(validate_name_overrides(&unknown).is_err());
+        let nested = HashMap::from([(FARMS.to_string(), "../farms.xml".to_string())]);
+        assert!(validate_name_overrides(&nested).is_err());
+    }
+}
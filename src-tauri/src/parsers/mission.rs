@@ -5,33 +5,10 @@ use quick_xml::Reader;
 
 use crate::error::AppError;
 use crate::models::mission::{Mission, MissionStatus};
-
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
-}
-
-fn attr_u32_opt(e: &quick_xml::events::BytesStart, key: &str) -> Option<u32> {
-    let s = attr_str(e, key);
-    if s.is_empty() { None } else { s.parse().ok() }
-}
-
-fn attr_f64_opt(e: &quick_xml::events::BytesStart, key: &str) -> Option<f64> {
-    let s = attr_str(e, key);
-    if s.is_empty() { None } else { s.parse().ok() }
-}
-
-fn attr_str_opt(e: &quick_xml::events::BytesStart, key: &str) -> Option<String> {
-    let s = attr_str(e, key);
-    if s.is_empty() { None } else { Some(s) }
-}
+use crate::parsers::common::{
+    attr_f64, attr_f64_opt, attr_str, attr_str_opt, attr_u32_opt, read_xml_file,
+};
+use crate::parsers::files::{savegame_file, MISSIONS};
 
 fn is_mission_tag(tag: &str) -> bool {
     tag.ends_with("Mission") && tag != "missions"
@@ -42,10 +19,8 @@ fn extract_mission_type(tag: &str) -> String {
 }
 
 pub fn parse_missions(path: &Path) -> Result<Vec<Mission>, AppError> {
-    let xml_path = path.join("missions.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, MISSIONS);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut missions: Vec<Mission> = Vec::new();
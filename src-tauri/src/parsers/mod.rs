@@ -1,14 +1,17 @@
 pub mod career;
 pub mod collectible;
+pub mod common;
 pub mod contract;
 pub mod density_map_config;
 pub mod economy;
 pub mod environment;
 pub mod farm;
 pub mod field;
+pub mod files;
 pub mod gdm;
 pub mod grle;
 pub mod mission;
 pub mod placeable;
 pub mod sale;
+pub mod tree;
 pub mod vehicle;
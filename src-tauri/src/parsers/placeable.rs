@@ -7,33 +7,14 @@ use crate::error::AppError;
 use crate::models::common::Position;
 use crate::models::placeable::{
     placeable_display_name, ConstructionMaterial, ConstructionStep, Placeable, ProductionStock,
+    SellingStation, SellingStationStat,
 };
-
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
-}
-
-fn attr_u8(e: &quick_xml::events::BytesStart, key: &str) -> u8 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
-
-fn attr_u32(e: &quick_xml::events::BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
+use crate::parsers::common::{attr_f64, attr_str, attr_u32, attr_u8, read_xml_file};
+use crate::parsers::files::{savegame_file, PLACEABLES};
 
 pub fn parse_placeables(path: &Path) -> Result<Vec<Placeable>, AppError> {
-    let xml_path = path.join("placeables.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, PLACEABLES);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut placeables: Vec<Placeable> = Vec::new();
@@ -203,6 +184,74 @@ pub fn parse_placeables(path: &Path) -> Result<Vec<Placeable>, AppError> {
     Ok(placeables)
 }
 
+/// List the placeables of placeables.xml holding a `<sellingStation>` section
+/// with its per fill type `<stats>`. Saves without the section give an empty list.
+pub fn parse_selling_stations(path: &Path) -> Result<Vec<SellingStation>, AppError> {
+    let xml_path = savegame_file(path, PLACEABLES);
+    let content = read_xml_file(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut stations: Vec<SellingStation> = Vec::new();
+
+    let mut placeable_index: usize = 0;
+    let mut depth: u32 = 0;
+    let mut current: Option<SellingStation> = None;
+    let mut in_selling_station = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                depth += 1;
+                match e.name().as_ref() {
+                    b"placeable" if depth == 2 => {
+                        current = Some(SellingStation {
+                            placeable_index,
+                            display_name: placeable_display_name(&attr_str(e, "filename")),
+                            stats: Vec::new(),
+                        });
+                        placeable_index += 1;
+                    }
+                    b"sellingStation" if current.is_some() => in_selling_station = true,
+                    _ => {}
+                }
+            }
+            Ok(Event::Empty(ref e)) if in_selling_station && e.name().as_ref() == b"stats" => {
+                if let Some(ref mut station) = current {
+                    station.stats.push(SellingStationStat {
+                        fill_type: attr_str(e, "fillType"),
+                        received: attr_f64(e, "received"),
+                        paid: attr_f64(e, "paid"),
+                    });
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                match e.name().as_ref() {
+                    b"placeable" if depth == 2 => {
+                        if let Some(station) = current.take() {
+                            if !station.stats.is_empty() {
+                                stations.push(station);
+                            }
+                        }
+                    }
+                    b"sellingStation" => in_selling_station = false,
+                    _ => {}
+                }
+                depth = depth.saturating_sub(1);
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(AppError::XmlParseError {
+                    file: xml_path.display().to_string(),
+                    message: e.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(stations)
+}
+
 struct PlaceableBuilder {
     index: usize,
     filename: String,
@@ -306,6 +355,37 @@ mod tests {
         assert_eq!(pre_placed[0].farm_id, 0);
     }
 
+    #[test]
+    fn test_parse_selling_stations() {
+        let path = fixtures_path().join("savegame_complete");
+        assert!(parse_selling_stations(&path).unwrap().is_empty());
+
+        let dir = std::env::temp_dir().join("fs25_test_selling_stations");
+        let _ = std::fs::create_dir_all(&dir);
+        let content = std::fs::read_to_string(path.join("placeables.xml"))
+            .unwrap()
+            .replace(
+                r#"<sentTranslation x="50.0" y="0.0" z="-200.0" />
+    </component>"#,
+                r#"<sentTranslation x="50.0" y="0.0" z="-200.0" />
+    </component>
+    <sellingStation>
+      <stats fillType="WHEAT" received="12000.000000" paid="5400.000000" />
+      <stats fillType="BARLEY" received="800.000000" paid="300.000000" />
+    </sellingStation>"#,
+            );
+        std::fs::write(dir.join("placeables.xml"), content).unwrap();
+
+        let stations = parse_selling_stations(&dir).unwrap();
+        assert_eq!(stations.len(), 1);
+        assert_eq!(stations[0].placeable_index, 3);
+        assert_eq!(stations[0].stats.len(), 2);
+        assert_eq!(stations[0].stats[0].fill_type, "WHEAT");
+        assert!((stations[0].stats[0].received - 12000.0).abs() < 0.01);
+        assert!((stations[0].stats[1].paid - 300.0).abs() < 0.01);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     #[test]
     fn test_parse_placeables_missing_file() {
         let dir = std::env::temp_dir().join("fs25_test_no_placeables");
@@ -6,29 +6,13 @@ use quick_xml::Reader;
 use crate::error::AppError;
 use crate::models::sale::{BoughtConfiguration, SaleItem};
 use crate::models::vehicle::vehicle_display_name;
-
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
-}
-
-fn attr_u32(e: &quick_xml::events::BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
-}
+use crate::parsers::common::{attr_f64, attr_str, attr_u32, read_xml_file};
+use crate::parsers::files::{savegame_file, SALES};
 
 /// Parse sales.xml and return the list of items for sale.
 pub fn parse_sales(path: &Path) -> Result<Vec<SaleItem>, AppError> {
-    let xml_path = path.join("sales.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, SALES);
+    let content = read_xml_file(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut sales: Vec<SaleItem> = Vec::new();
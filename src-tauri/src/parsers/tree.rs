@@ -0,0 +1,125 @@
+use std::path::Path;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::error::AppError;
+use crate::models::tree::PlantedTree;
+use crate::parsers::common::{attr_position, attr_str, read_xml_file};
+use crate::parsers::files::{savegame_file, TREE_PLANT};
+
+/// Growth stage attribute names seen across game versions, in lookup order.
+pub const GROWTH_STAGE_ATTRS: &[&str] = &["growthStateI", "growthStage", "growthState"];
+
+fn parse_growth_stage(e: &quick_xml::events::BytesStart) -> u8 {
+    GROWTH_STAGE_ATTRS
+        .iter()
+        .map(|key| attr_str(e, key))
+        .find(|value| !value.is_empty())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Parse treePlant.xml and return the planted trees in file order.
+pub fn parse_trees(path: &Path) -> Result<Vec<PlantedTree>, AppError> {
+    let xml_path = savegame_file(path, TREE_PLANT);
+    let content = read_xml_file(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut trees: Vec<PlantedTree> = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if tag == "tree" {
+                    trees.push(PlantedTree {
+                        index: trees.len(),
+                        tree_type: attr_str(e, "treeType"),
+                        growth_stage: parse_growth_stage(e),
+                        is_growing: attr_str(e, "isGrowing") == "true",
+                        position: attr_position(e, "position"),
+                    });
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                return Err(AppError::XmlParseError {
+                    file: xml_path.display().to_string(),
+                    message: e.to_string(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    Ok(trees)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::tree::count_trees_by_type;
+
+    fn fixtures_path() -> std::path::PathBuf {
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+    }
+
+    #[test]
+    fn test_parse_trees_nominal() {
+        let path = fixtures_path().join("savegame_complete");
+        let trees = parse_trees(&path).unwrap();
+        assert_eq!(trees.len(), 4);
+
+        let spruce = &trees[0];
+        assert_eq!(spruce.tree_type, "SPRUCE1");
+        assert_eq!(spruce.growth_stage, 2);
+        assert!(spruce.is_growing);
+        let pos = spruce.position.as_ref().unwrap();
+        assert!((pos.x - -512.25).abs() < 0.001);
+        assert!((pos.z - 310.75).abs() < 0.001);
+
+        assert_eq!(trees[1].growth_stage, 7);
+        assert!(!trees[1].is_growing);
+        assert_eq!(trees[3].index, 3);
+    }
+
+    #[test]
+    fn test_count_trees_by_type() {
+        let path = fixtures_path().join("savegame_complete");
+        let trees = parse_trees(&path).unwrap();
+        let counts = count_trees_by_type(&trees);
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[0].tree_type, "BIRCH");
+        assert_eq!(counts[0].count, 1);
+        assert_eq!(counts[2].tree_type, "SPRUCE1");
+        assert_eq!(counts[2].count, 2);
+    }
+
+    #[test]
+    fn test_parse_trees_alternate_growth_attribute() {
+        let dir = std::env::temp_dir().join("fs25_test_trees_alt_attr");
+        let _ = std::fs::create_dir_all(&dir);
+        std::fs::write(
+            dir.join("treePlant.xml"),
+            r#"<treePlant><tree treeType="PINE" growthStage="4" position="bad"/></treePlant>"#,
+        )
+        .unwrap();
+        let trees = parse_trees(&dir).unwrap();
+        assert_eq!(trees.len(), 1);
+        assert_eq!(trees[0].growth_stage, 4);
+        assert!(trees[0].position.is_none());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_parse_trees_missing_file() {
+        let dir = std::env::temp_dir().join("fs25_test_no_trees");
+        let _ = std::fs::create_dir_all(&dir);
+        let result = parse_trees(&dir);
+        assert!(matches!(result, Err(AppError::IoError { .. })));
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
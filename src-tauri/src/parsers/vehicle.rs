@@ -1,6 +1,6 @@
 use std::path::Path;
 
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use quick_xml::Reader;
 
 use crate::error::AppError;
@@ -8,36 +8,29 @@ use crate::models::common::{Position, Rotation};
 use crate::models::vehicle::{
     vehicle_display_name, AttachedImplement, FillUnit, Vehicle, VehicleConfiguration, PropertyState,
 };
+use crate::parsers::common::{
+    attr_f64, attr_str, attr_str_opt, attr_u32, attr_u8, read_partial_xml_file, read_xml_file,
+};
+use crate::parsers::files::{savegame_file, VEHICLES};
 
-fn attr_str(e: &quick_xml::events::BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_f64(e: &quick_xml::events::BytesStart, key: &str) -> f64 {
-    attr_str(e, key).parse().unwrap_or(0.0)
-}
-
-fn attr_u8(e: &quick_xml::events::BytesStart, key: &str) -> u8 {
-    attr_str(e, key).parse().unwrap_or(0)
+/// Parse vehicles.xml and return the list of all vehicles.
+pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
+    let xml_path = savegame_file(path, VEHICLES);
+    let content = read_xml_file(&xml_path)?;
+    parse_vehicles_xml(&content, &xml_path)
 }
 
-fn attr_u32(e: &quick_xml::events::BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
+/// Parse the vehicles of a truncated vehicles.xml, up to the last complete one,
+/// for read-only display. The second value tells whether the file was truncated.
+pub fn parse_vehicles_partial(path: &Path) -> Result<(Vec<Vehicle>, bool), AppError> {
+    let xml_path = savegame_file(path, VEHICLES);
+    let (content, recovered) = read_partial_xml_file(&xml_path)?;
+    Ok((parse_vehicles_xml(&content, &xml_path)?, recovered))
 }
 
-/// Parse vehicles.xml and return the list of all vehicles.
 /// Uses manual event-based parsing due to the complex component-based XML structure.
-pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
-    let xml_path = path.join("vehicles.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
-
-    let mut reader = Reader::from_str(&content);
+pub(crate) fn parse_vehicles_xml(content: &str, xml_path: &Path) -> Result<Vec<Vehicle>, AppError> {
+    let mut reader = Reader::from_str(content);
     let mut vehicles: Vec<Vehicle> = Vec::new();
 
     // State tracking
@@ -48,6 +41,7 @@ pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
     let mut in_attached_implements = false;
     let mut in_configurations = false;
     let mut in_wearable = false;
+    let mut in_ai_job = false;
 
     loop {
         match reader.read_event() {
@@ -76,6 +70,7 @@ pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
                             configurations: Vec::new(),
                             fill_units: Vec::new(),
                             attached_implements: Vec::new(),
+                            worker_task: None,
                         });
                     }
                     "component" if in_vehicle => {
@@ -96,6 +91,14 @@ pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
                             vb.damage = attr_f64(e, "damage");
                         }
                     }
+                    "aiJobVehicle" if in_vehicle => {
+                        in_ai_job = true;
+                    }
+                    "job" if in_ai_job => {
+                        if let Some(ref mut vb) = current_vehicle {
+                            vb.worker_task = Some(worker_task(e));
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -150,6 +153,9 @@ pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
                         "wearNode" if in_wearable => {
                             vb.wear = attr_f64(e, "amount");
                         }
+                        "job" if in_ai_job => {
+                            vb.worker_task = Some(worker_task(e));
+                        }
                         "wearable" => {
                             // Self-closing <wearable .../> (fallback for test fixtures)
                             vb.damage = attr_f64(e, "damage");
@@ -173,6 +179,7 @@ pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
                     "attacherJoints" => in_attached_implements = false,
                     "boughtConfigurations" => in_configurations = false,
                     "wearable" => in_wearable = false,
+                    "aiJobVehicle" => in_ai_job = false,
                     _ => {}
                 }
             }
@@ -190,6 +197,11 @@ pub fn parse_vehicles(path: &Path) -> Result<Vec<Vehicle>, AppError> {
     Ok(vehicles)
 }
 
+/// Task of an active `<job>` node under `<aiJobVehicle>`.
+fn worker_task(e: &BytesStart) -> String {
+    attr_str_opt(e, "type").unwrap_or_else(|| "UNKNOWN".to_string())
+}
+
 struct VehicleBuilder {
     unique_id: String,
     filename: String,
@@ -206,6 +218,7 @@ struct VehicleBuilder {
     configurations: Vec<VehicleConfiguration>,
     fill_units: Vec<FillUnit>,
     attached_implements: Vec<AttachedImplement>,
+    worker_task: Option<String>,
 }
 
 impl VehicleBuilder {
@@ -226,6 +239,7 @@ impl VehicleBuilder {
             configurations: self.configurations,
             fill_units: self.fill_units,
             attached_implements: self.attached_implements,
+            worker_task: self.worker_task,
         }
     }
 }
@@ -255,6 +269,34 @@ mod tests {
         // Wearable data
         assert!((tractor.damage - 0.05).abs() < 0.001);
         assert!((tractor.wear - 0.12).abs() < 0.001);
+        // Hired worker
+        assert_eq!(tractor.worker_task.as_deref(), Some("FIELDWORK"));
+        let baler = vehicles.iter().find(|v| v.unique_id == "vehicle0002").unwrap();
+        assert_eq!(baler.worker_task, None);
+    }
+
+    #[test]
+    fn test_parse_vehicles_truncated() {
+        let dir = std::env::temp_dir().join("fs25_test_pv_truncated");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let content = std::fs::read_to_string(
+            fixtures_path().join("savegame_complete").join("vehicles.xml"),
+        )
+        .unwrap();
+        // Cut in the middle of the third vehicle
+        let cut = content.find("krampeBandit750").unwrap();
+        std::fs::write(dir.join("vehicles.xml"), &content[..cut]).unwrap();
+
+        let result = parse_vehicles(&dir);
+        assert!(matches!(result, Err(AppError::TruncatedFile { .. })));
+
+        let (vehicles, recovered) = parse_vehicles_partial(&dir).unwrap();
+        assert!(recovered);
+        let ids: Vec<&str> = vehicles.iter().map(|v| v.unique_id.as_str()).collect();
+        assert_eq!(ids, vec!["vehicle0001", "vehicle0002"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
     }
 
     #[test]
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fs;
 use std::io::Read;
 use std::path::Path;
@@ -5,16 +6,33 @@ use std::path::Path;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 
-use crate::models::catalog::{CatalogVehicle, VehicleSource};
+use crate::models::catalog::{CatalogKind, CatalogVehicle, VehicleSource};
 
 /// Scan base game vehicles directory and mod zips to build a vehicle catalog.
 pub fn scan_vehicle_catalog(game_path: &Path, mods_dir: &Path) -> Vec<CatalogVehicle> {
+    scan_vehicle_catalog_chunked(game_path, mods_dir, |_| {})
+}
+
+/// Same scan as `scan_vehicle_catalog`, handing the vehicles found to `on_chunk`
+/// as each source completes: the base game, each DLC and each mod zip. Chunks
+/// are unsorted; the returned catalog is the sorted one.
+pub fn scan_vehicle_catalog_chunked(
+    game_path: &Path,
+    mods_dir: &Path,
+    mut on_chunk: impl FnMut(&[CatalogVehicle]),
+) -> Vec<CatalogVehicle> {
     let mut catalog = Vec::new();
+    let mut emit_from = |catalog: &Vec<CatalogVehicle>, start: usize| {
+        if catalog.len() > start {
+            on_chunk(&catalog[start..]);
+        }
+    };
 
     // Scan base game vehicles
     let vehicles_dir = game_path.join("data").join("vehicles");
     if vehicles_dir.exists() {
         scan_directory_recursive(&vehicles_dir, game_path, &mut catalog);
+        emit_from(&catalog, 0);
     }
 
     // Scan DLC/PDLC vehicles
@@ -24,20 +42,26 @@ pub fn scan_vehicle_catalog(game_path: &Path, mods_dir: &Path) -> Vec<CatalogVeh
             for entry in entries.flatten() {
                 let dlc_vehicles = entry.path().join("data").join("vehicles");
                 if dlc_vehicles.exists() {
+                    let start = catalog.len();
                     scan_directory_recursive(&dlc_vehicles, game_path, &mut catalog);
+                    emit_from(&catalog, start);
                 }
             }
         }
     }
 
-    // Scan mod zips
+    // Scan mod zips, and unzipped mod folders without a zip of the same name
     if mods_dir.exists() {
         if let Ok(entries) = fs::read_dir(mods_dir) {
             for entry in entries.flatten() {
                 let path = entry.path();
+                let start = catalog.len();
                 if path.extension().and_then(|e| e.to_str()) == Some("zip") {
                     scan_mod_zip(&path, &mut catalog);
+                } else if is_mod_folder(&path) && !path.with_extension("zip").exists() {
+                    scan_mod_folder(&path, &mut catalog);
                 }
+                emit_from(&catalog, start);
             }
         }
     }
@@ -48,6 +72,18 @@ pub fn scan_vehicle_catalog(game_path: &Path, mods_dir: &Path) -> Vec<CatalogVeh
     catalog
 }
 
+/// Keep the entries whose category is of one of `kinds`; `None` keeps everything.
+pub fn filter_catalog(
+    catalog: &[CatalogVehicle],
+    kinds: Option<&[CatalogKind]>,
+) -> Vec<CatalogVehicle> {
+    catalog
+        .iter()
+        .filter(|v| kinds.is_none_or(|k| k.contains(&CatalogKind::from_category(&v.category))))
+        .cloned()
+        .collect()
+}
+
 fn scan_directory_recursive(
     dir: &Path,
     game_path: &Path,
@@ -153,6 +189,115 @@ fn scan_mod_zip(zip_path: &Path, catalog: &mut Vec<CatalogVehicle>) {
     }
 }
 
+/// Mods of the mods folder, zipped or unzipped, by name with the version of
+/// their modDesc.xml (empty when it has none). As for the catalog, a zip wins
+/// over a folder of the same name.
+pub fn scan_installed_mods(mods_dir: &Path) -> HashMap<String, String> {
+    let mut mods = HashMap::new();
+    let Ok(entries) = fs::read_dir(mods_dir) else {
+        return mods;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let mod_desc = if path.extension().and_then(|e| e.to_str()) == Some("zip") {
+            read_zipped_mod_desc(&path)
+        } else if is_mod_folder(&path) && !path.with_extension("zip").exists() {
+            fs::read_to_string(path.join("modDesc.xml")).ok()
+        } else {
+            continue;
+        };
+        let name = path.file_stem().unwrap_or_default().to_string_lossy();
+        let version = mod_desc.as_deref().map(parse_mod_version);
+        mods.insert(name.to_string(), version.unwrap_or_default());
+    }
+
+    mods
+}
+
+fn read_zipped_mod_desc(zip_path: &Path) -> Option<String> {
+    let mut archive = zip::ZipArchive::new(fs::File::open(zip_path).ok()?).ok()?;
+    let mut entry = archive.by_name("modDesc.xml").ok()?;
+    let mut content = String::new();
+    entry.read_to_string(&mut content).ok()?;
+    Some(content)
+}
+
+/// Text of the `<version>` of a modDesc.xml.
+fn parse_mod_version(mod_desc: &str) -> String {
+    let mut reader = Reader::from_str(mod_desc);
+    let mut in_version = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"version" => in_version = true,
+            Ok(Event::Text(ref t)) if in_version => {
+                return t.unescape().unwrap_or_default().trim().to_string();
+            }
+            Ok(Event::End(_)) => in_version = false,
+            Ok(Event::Eof) | Err(_) => return String::new(),
+            _ => {}
+        }
+    }
+}
+
+/// Whether `path` is an unzipped mod, as used while developing one.
+pub(crate) fn is_mod_folder(path: &Path) -> bool {
+    path.is_dir() && path.join("modDesc.xml").is_file()
+}
+
+fn scan_mod_folder(mod_dir: &Path, catalog: &mut Vec<CatalogVehicle>) {
+    let mod_name = mod_dir
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+
+    let mut xml_paths = Vec::new();
+    collect_xml_files(mod_dir, &mut xml_paths);
+
+    for xml_path in xml_paths {
+        let Ok(content) = fs::read_to_string(&xml_path) else {
+            continue;
+        };
+        let Some(store_data) = parse_store_data(&content) else {
+            continue;
+        };
+        let Ok(relative) = xml_path.strip_prefix(mod_dir) else {
+            continue;
+        };
+        // Same $moddir$ format as zipped mods
+        let xml_filename = format!(
+            "$moddir${}/{}",
+            mod_name,
+            relative.to_string_lossy().replace('\\', "/")
+        );
+
+        catalog.push(CatalogVehicle {
+            xml_filename,
+            name: store_data.name,
+            brand: store_data.brand,
+            category: store_data.category,
+            price: store_data.price,
+            source: VehicleSource::Mod(mod_name.clone()),
+        });
+    }
+}
+
+fn collect_xml_files(dir: &Path, out: &mut Vec<std::path::PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_xml_files(&path, out);
+        } else if path.extension().and_then(|e| e.to_str()) == Some("xml") {
+            out.push(path);
+        }
+    }
+}
+
 struct StoreData {
     name: String,
     brand: String,
@@ -352,6 +497,216 @@ mod tests {
         assert!(parse_store_data(xml).is_none());
     }
 
+    fn store_xml(name: &str, brand: &str, price: u32) -> String {
+        format!(
+            "<vehicle><storeData><name>{}</name><brand>{}</brand>\
+             <category>tractorsS</category><price>{}</price></storeData></vehicle>",
+            name, brand, price
+        )
+    }
+
+    #[test]
+    fn test_chunked_scan_matches_full_scan() {
+        use std::io::Write;
+
+        let base = std::env::temp_dir().join("fs25_test_catalog_chunked");
+        let _ = fs::remove_dir_all(&base);
+        let game = base.join("game");
+        let vehicles = game.join("data").join("vehicles").join("zetor");
+        fs::create_dir_all(&vehicles).unwrap();
+        fs::write(vehicles.join("a.xml"), store_xml("Proxima", "ZETOR", 40000)).unwrap();
+        fs::write(vehicles.join("b.xml"), store_xml("Crystal", "ZETOR", 90000)).unwrap();
+        let mods = base.join("mods");
+        fs::create_dir_all(&mods).unwrap();
+        let mut zip = zip::ZipWriter::new(fs::File::create(mods.join("FS25_Test.zip")).unwrap());
+        zip.start_file("tractor.xml", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(store_xml("Mod Tractor", "AGCO", 1000).as_bytes())
+            .unwrap();
+        zip.finish().unwrap();
+
+        let mut chunks: Vec<usize> = Vec::new();
+        let chunked = scan_vehicle_catalog_chunked(&game, &mods, |c| chunks.push(c.len()));
+        assert_eq!(chunks, vec![2, 1]);
+
+        let full = scan_vehicle_catalog(&game, &mods);
+        let names = |c: &[CatalogVehicle]| c.iter().map(|v| v.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&chunked), names(&full));
+        assert_eq!(names(&full), vec!["Mod Tractor", "Crystal", "Proxima"]);
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_scan_unzipped_mod_folder() {
+        use std::io::Write;
+
+        let base = std::env::temp_dir().join("fs25_test_catalog_mod_folder");
+        let _ = fs::remove_dir_all(&base);
+        let mods = base.join("mods");
+        let folder = mods.join("FS25_DevTractor").join("vehicles");
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(mods.join("FS25_DevTractor").join("modDesc.xml"), "<modDesc/>").unwrap();
+        fs::write(folder.join("tractor.xml"), store_xml("Dev Tractor", "AGCO", 1000)).unwrap();
+        // Not a mod: no modDesc.xml
+        fs::create_dir_all(mods.join("screenshots")).unwrap();
+        fs::write(mods.join("screenshots").join("a.xml"), store_xml("Stray", "AGCO", 1)).unwrap();
+
+        let catalog = scan_vehicle_catalog(&base.join("game"), &mods);
+        assert_eq!(catalog.len(), 1);
+        assert_eq!(catalog[0].xml_filename, "$moddir$FS25_DevTractor/vehicles/tractor.xml");
+        assert!(matches!(&catalog[0].source, VehicleSource::Mod(m) if m == "FS25_DevTractor"));
+
+        // The zip wins over a folder of the same name
+        let mut zip =
+            zip::ZipWriter::new(fs::File::create(mods.join("FS25_DevTractor.zip")).unwrap());
+        zip.start_file("tractor.xml", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(store_xml("Zipped Tractor", "AGCO", 1000).as_bytes())
+            .unwrap();
+        zip.finish().unwrap();
+        let catalog = scan_vehicle_catalog(&base.join("game"), &mods);
+        assert_eq!(catalog.len(), 1);
+        assert_eq!(catalog[0].name, "Zipped Tractor");
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_scan_installed_mods() {
+        use crate::models::catalog::{used_mods, SavegameMod};
+        use std::io::Write;
+
+        let base = std::env::temp_dir().join("fs25_test_catalog_installed_mods");
+        let _ = fs::remove_dir_all(&base);
+        let mods = base.join("mods");
+        fs::create_dir_all(mods.join("FS25_AutoDrive")).unwrap();
+        fs::write(
+            mods.join("FS25_AutoDrive").join("modDesc.xml"),
+            "<modDesc descVersion=\"92\"><version>3.0.0.5</version></modDesc>",
+        )
+        .unwrap();
+        let mut zip = zip::ZipWriter::new(fs::File::create(mods.join("FS25_Unused.zip")).unwrap());
+        zip.start_file("modDesc.xml", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"<modDesc><version>1.0.0.0</version></modDesc>")
+            .unwrap();
+        zip.finish().unwrap();
+        fs::create_dir_all(mods.join("screenshots")).unwrap();
+
+        let installed = scan_installed_mods(&mods);
+        assert_eq!(installed.len(), 2);
+        assert_eq!(installed["FS25_AutoDrive"], "3.0.0.5");
+        assert_eq!(installed["FS25_Unused"], "1.0.0.0");
+
+        let save_mod = |name: &str, version: &str| SavegameMod {
+            mod_name: name.to_string(),
+            title: name.to_string(),
+            version: version.to_string(),
+            required: false,
+        };
+        let used = used_mods(
+            &[
+                save_mod("FS25_AutoDrive", "3.0.0.4"),
+                save_mod("FS25_Missing", "1.0.0.0"),
+            ],
+            &installed,
+        );
+        assert_eq!(used.len(), 1);
+        assert_eq!(used[0].installed_version, "3.0.0.5");
+        assert!(used[0].version_mismatch);
+
+        let _ = fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_missing_vehicle_mods() {
+        use crate::models::catalog::{missing_vehicle_mods, mod_vehicle_filename};
+        use crate::parsers::vehicle::parse_vehicles;
+
+        let save = std::env::temp_dir().join("fs25_test_catalog_missing_mods");
+        let _ = fs::remove_dir_all(&save);
+        fs::create_dir_all(&save).unwrap();
+        fs::write(
+            save.join("vehicles.xml"),
+            r#"<vehicles>
+  <vehicle filename="data/vehicles/fendt/vario/vario.xml" uniqueId="v1" farmId="1"></vehicle>
+  <vehicle filename="$moddir$FS25_Installed/tractor.xml" uniqueId="v2" farmId="1"></vehicle>
+  <vehicle filename="$moddir$FS25_Installed/removed.xml" uniqueId="v3" farmId="1"></vehicle>
+  <vehicle filename="$moddir$FS25_Gone/trailer.xml" uniqueId="v4" farmId="2"></vehicle>
+  <vehicle filename="D:/FS25/mods/FS25_Gone/plow.xml" uniqueId="v5" farmId="1"></vehicle>
+  <vehicle filename="$moddir$FS25_Dealer/truck.xml" uniqueId="v6" farmId="0"></vehicle>
+</vehicles>"#,
+        )
+        .unwrap();
+        let vehicles = parse_vehicles(&save).unwrap();
+        let catalog = vec![CatalogVehicle {
+            xml_filename: "$moddir$FS25_Installed/tractor.xml".to_string(),
+            name: "Tractor".to_string(),
+            brand: "TEST".to_string(),
+            category: "tractorsL".to_string(),
+            price: 1000,
+            source: VehicleSource::Mod("FS25_Installed".to_string()),
+        }];
+
+        assert_eq!(
+            mod_vehicle_filename("C:\\Games\\mods\\FS25_Gone\\plow.xml").as_deref(),
+            Some("$moddir$FS25_Gone/plow.xml")
+        );
+        assert_eq!(mod_vehicle_filename("data/vehicles/a/a.xml"), None);
+
+        let missing = missing_vehicle_mods(&vehicles, &catalog);
+        assert_eq!(missing.len(), 2);
+        assert_eq!(missing[0].mod_name, "FS25_Gone");
+        assert!(!missing[0].installed);
+        let ids: Vec<&str> = missing[0]
+            .vehicles
+            .iter()
+            .map(|v| v.unique_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["v4", "v5"]);
+        assert_eq!(missing[1].mod_name, "FS25_Installed");
+        assert!(missing[1].installed);
+        assert_eq!(missing[1].vehicles.len(), 1);
+        assert_eq!(missing[1].vehicles[0].unique_id, "v3");
+
+        let _ = fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_filter_catalog_by_kind() {
+        let entry = |name: &str, category: &str| CatalogVehicle {
+            xml_filename: format!("data/vehicles/{}.xml", name),
+            name: name.to_string(),
+            brand: "TEST".to_string(),
+            category: category.to_string(),
+            price: 1000,
+            source: VehicleSource::BaseGame,
+        };
+        let catalog = vec![
+            entry("tractor", "tractorsL"),
+            entry("combine", "harvesters"),
+            entry("sprayer", "sprayerVehicles"),
+            entry("plow", "plows"),
+            entry("trailer", "trailers"),
+            entry("pallet", "pallets"),
+        ];
+        let names = |c: Vec<CatalogVehicle>| c.into_iter().map(|v| v.name).collect::<Vec<_>>();
+
+        assert_eq!(filter_catalog(&catalog, None).len(), 6);
+        assert_eq!(
+            names(filter_catalog(&catalog, Some(&[CatalogKind::Vehicle]))),
+            vec!["tractor", "combine", "sprayer"]
+        );
+        assert_eq!(
+            names(filter_catalog(
+                &catalog,
+                Some(&[CatalogKind::Implement, CatalogKind::Pallet])
+            )),
+            vec!["plow", "trailer", "pallet"]
+        );
+    }
+
     #[test]
     fn test_clean_localized_name() {
         assert_eq!(clean_localized_name("900 Vario"), "900 Vario");
@@ -1,8 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use crate::error::AppError;
+use crate::models::career::is_mod_map;
 use crate::models::density::{
     DensityEditPayload, FieldDensityData, FruitCoverage, GroundCoverage, LevelDistribution,
 };
@@ -79,7 +80,7 @@ fn load_map_data(
     game_path: &Path,
     map_id: &str,
 ) -> Result<(GrleImage, Vec<String>), AppError> {
-    if map_id.contains('.') {
+    if is_mod_map(map_id) {
         // Modded map: e.g. "FS25_Pallegney.FS25_Pallegney"
         load_mod_map_data(game_path, map_id)
     } else {
@@ -92,25 +93,8 @@ fn load_builtin_map_data(
     game_path: &Path,
     map_id: &str,
 ) -> Result<(GrleImage, Vec<String>), AppError> {
-    let map_dir = match map_id {
-        "MapUS" => "mapUS",
-        "MapEU" => "mapEU",
-        "MapAS" => "mapAS",
-        other => {
-            return Err(AppError::DensityMapError {
-                message: format!("Unknown built-in map: {}. DLC maps are not yet supported.", other),
-            });
-        }
-    };
-
-    let farmlands_path = game_path
-        .join("data")
-        .join("maps")
-        .join(map_dir)
-        .join("data")
-        .join("infoLayer_farmlands.grle");
-
-    let farmlands_grle = read_grle_file(&farmlands_path)?;
+    let map_dir = builtin_map_dir(map_id)?;
+    let farmlands_grle = read_grle_file(&builtin_farmlands_path(game_path, map_dir))?;
 
     // 1. Base shared fruit types
     let fruit_types_path = game_path
@@ -143,31 +127,10 @@ fn load_mod_map_data(
     game_path: &Path,
     map_id: &str,
 ) -> Result<(GrleImage, Vec<String>), AppError> {
-    // Extract mod name from map_id like "FS25_Pallegney.FS25_Pallegney"
-    let mod_name = map_id
-        .split('.')
-        .next()
-        .ok_or_else(|| AppError::DensityMapError {
-            message: format!("Invalid mod map ID: {}", map_id),
-        })?;
-
-    // Mods are in the user's FarmingSimulator2025/mods/ directory
-    let mods_path = get_mods_dir()?;
-    let zip_path = mods_path.join(format!("{}.zip", mod_name));
-
-    if !zip_path.exists() {
-        return Err(AppError::DensityMapError {
-            message: format!(
-                "Mod zip not found: {}. Expected at {}",
-                mod_name,
-                zip_path.display()
-            ),
-        });
-    }
+    let zip_path = mod_map_zip_path(map_id)?;
 
     // Read farmlands GRLE from zip
-    let farmlands_data =
-        read_file_from_zip(&zip_path, "maps/data/infoLayer_farmlands.grle")?;
+    let farmlands_data = read_file_from_zip(&zip_path, MOD_FARMLANDS_PATH)?;
     let farmlands_grle = grle::parse_grle(&farmlands_data)?;
 
     // Read fruit types from zip
@@ -188,6 +151,106 @@ fn load_mod_map_data(
     Ok((farmlands_grle, fruit_types))
 }
 
+/// Location of the farmland info layer inside a mod map zip.
+const MOD_FARMLANDS_PATH: &str = "maps/data/infoLayer_farmlands.grle";
+
+/// Farmland info layer of a built-in or mod map.
+fn read_map_farmlands(game_path: &Path, map_id: &str) -> Result<GrleImage, AppError> {
+    if is_mod_map(map_id) {
+        grle::parse_grle(&read_file_from_zip(
+            &mod_map_zip_path(map_id)?,
+            MOD_FARMLANDS_PATH,
+        )?)
+    } else {
+        read_grle_file(&builtin_farmlands_path(game_path, builtin_map_dir(map_id)?))
+    }
+}
+
+/// Farmland ids painted on the map's farmland info layer, which is what the game
+/// knows as farmland regardless of the entries of the savegame's farmland.xml.
+pub fn map_farmland_ids(game_path: &Path, map_id: &str) -> Result<HashSet<u32>, AppError> {
+    let farmlands_grle = read_map_farmlands(game_path, map_id)?;
+
+    Ok(build_farmland_pixel_map(&farmlands_grle)
+        .into_keys()
+        .map(u32::from)
+        .collect())
+}
+
+/// Side of the base game maps, in meters. Mod maps are assumed to share it, so
+/// the areas of larger mod maps come out too small.
+const MAP_SIZE_METERS: f64 = 2048.0;
+
+/// Area of every farmland of the map, in hectares, by farmland id.
+pub fn map_farmland_areas(game_path: &Path, map_id: &str) -> Result<HashMap<u32, f64>, AppError> {
+    Ok(farmland_areas(
+        &read_map_farmlands(game_path, map_id)?,
+        MAP_SIZE_METERS,
+    ))
+}
+
+/// Hectares covered by each farmland id of a farmland layer spanning a square
+/// map of `map_size` meters.
+fn farmland_areas(farmlands: &GrleImage, map_size: f64) -> HashMap<u32, f64> {
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for &id in farmlands.pixels.iter().filter(|&&id| id > 0) {
+        *counts.entry(u32::from(id)).or_default() += 1;
+    }
+
+    let pixel_side = map_size / farmlands.width.max(1) as f64;
+    let pixel_hectares = pixel_side * pixel_side / 10_000.0;
+    counts
+        .into_iter()
+        .map(|(id, count)| (id, count as f64 * pixel_hectares))
+        .collect()
+}
+
+/// Game data folder of a built-in map: "MapUS" → "mapUS"
+fn builtin_map_dir(map_id: &str) -> Result<&'static str, AppError> {
+    match map_id {
+        "MapUS" => Ok("mapUS"),
+        "MapEU" => Ok("mapEU"),
+        "MapAS" => Ok("mapAS"),
+        other => Err(AppError::DensityMapError {
+            message: format!("Unknown built-in map: {}. DLC maps are not yet supported.", other),
+        }),
+    }
+}
+
+fn builtin_farmlands_path(game_path: &Path, map_dir: &str) -> PathBuf {
+    game_path
+        .join("data")
+        .join("maps")
+        .join(map_dir)
+        .join("data")
+        .join("infoLayer_farmlands.grle")
+}
+
+/// Zip of a modded map, e.g. "FS25_Pallegney.FS25_Pallegney" → mods/FS25_Pallegney.zip
+fn mod_map_zip_path(map_id: &str) -> Result<PathBuf, AppError> {
+    let mod_name = map_id
+        .split('.')
+        .next()
+        .ok_or_else(|| AppError::DensityMapError {
+            message: format!("Invalid mod map ID: {}", map_id),
+        })?;
+
+    // Mods are in the user's FarmingSimulator2025/mods/ directory
+    let zip_path = get_mods_dir()?.join(format!("{}.zip", mod_name));
+
+    if !zip_path.exists() {
+        return Err(AppError::DensityMapError {
+            message: format!(
+                "Mod zip not found: {}. Expected at {}",
+                mod_name,
+                zip_path.display()
+            ),
+        });
+    }
+
+    Ok(zip_path)
+}
+
 /// Append DLC fruit types from the game log that aren't already in the list.
 /// The game log records the exact fruit type registration order including DLC types.
 /// Also appends known extra fruit types as a final fallback.
@@ -868,3 +931,22 @@ pub fn save_density_edits(
 
     Ok(modified_files)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_farmland_areas() {
+        // 4x4 layer over a 2048 m map: 512 m pixels of 26.2144 ha
+        let farmlands = GrleImage {
+            width: 4,
+            height: 4,
+            pixels: vec![1, 1, 0, 2, 1, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0],
+        };
+        let areas = farmland_areas(&farmlands, 2048.0);
+        assert_eq!(areas.len(), 2);
+        assert!((areas[&1] - 4.0 * 26.2144).abs() < 1e-9);
+        assert!((areas[&2] - 2.0 * 26.2144).abs() < 1e-9);
+    }
+}
@@ -1,3 +1,9 @@
 pub mod catalog;
 pub mod density_map;
+pub mod production;
+pub mod report;
+pub mod save_verification;
+pub mod savegame_diff;
+pub mod settings;
 pub mod vehicle_image;
+pub mod xml_diff;
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::parsers::common::attr_str;
+
+/// Fill types the productions of a placeable consume and produce, as declared
+/// in its definition XML.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProductionRecipes {
+    pub inputs: HashSet<String>,
+    pub outputs: HashSet<String>,
+}
+
+/// Read the recipes of a base game placeable (`data/...` or `$data/...`
+/// filename) from the game install. Mod placeables and placeables without
+/// productions return `None`.
+pub fn read_production_recipes(game_path: &Path, filename: &str) -> Option<ProductionRecipes> {
+    let relative = filename.strip_prefix('$').unwrap_or(filename);
+    if !relative.starts_with("data/") {
+        return None;
+    }
+    let xml_path = relative
+        .split('/')
+        .fold(game_path.to_path_buf(), |path, part| path.join(part));
+    let content = fs::read_to_string(xml_path).ok()?;
+    parse_production_recipes(&content)
+}
+
+/// Collect the `fillType` of every `<input>` and `<output>` of the `<production>`
+/// elements. Returns `None` when the placeable declares no production.
+pub fn parse_production_recipes(xml_content: &str) -> Option<ProductionRecipes> {
+    let mut reader = Reader::from_str(xml_content);
+    let mut recipes = ProductionRecipes::default();
+    let mut has_production = false;
+    let mut in_production = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"production" => {
+                has_production = true;
+                in_production = true;
+            }
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => match e.name().as_ref() {
+                b"input" | b"output" if in_production => {
+                    let target = if e.name().as_ref() == b"input" {
+                        &mut recipes.inputs
+                    } else {
+                        &mut recipes.outputs
+                    };
+                    // Some recipes accept any of several fill types
+                    for fill_type in attr_str(e, "fillType").split_whitespace() {
+                        target.insert(fill_type.to_uppercase());
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"production" => {
+                in_production = false;
+            }
+            Ok(Event::Eof) | Err(_) => break,
+            _ => {}
+        }
+    }
+
+    has_production.then_some(recipes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_production_recipes() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<placeable type="productionPoint">
+  <productionPoint>
+    <productions>
+      <production id="flour" name="$l10n_fillType_flour">
+        <inputs>
+          <input fillType="WHEAT" amount="1"/>
+          <input fillType="barley oat" amount="1"/>
+        </inputs>
+        <outputs>
+          <output fillType="FLOUR" amount="0.8"/>
+        </outputs>
+      </production>
+    </productions>
+    <storage capacityPerFillType="10000"/>
+  </productionPoint>
+</placeable>"#;
+
+        let recipes = parse_production_recipes(xml).unwrap();
+        assert_eq!(
+            recipes.inputs,
+            HashSet::from(["WHEAT".to_string(), "BARLEY".to_string(), "OAT".to_string()])
+        );
+        assert_eq!(recipes.outputs, HashSet::from(["FLOUR".to_string()]));
+    }
+
+    #[test]
+    fn test_parse_production_recipes_none() {
+        let xml = r#"<placeable type="silo"><silo><storages/></silo></placeable>"#;
+        assert!(parse_production_recipes(xml).is_none());
+    }
+
+    #[test]
+    fn test_read_production_recipes_mod_placeable() {
+        let game_path = std::env::temp_dir();
+        assert!(read_production_recipes(&game_path, "$moddir$FS25_Mill/mill.xml").is_none());
+    }
+}
@@ -0,0 +1,220 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::common::LocalizedMessage;
+use crate::models::vehicle::PropertyState;
+use crate::models::SavegameData;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+/// A report section: a heading and a table, or a plain list when there are no columns.
+struct Section {
+    title: String,
+    columns: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+fn property_state_label(state: &PropertyState) -> &'static str {
+    match state {
+        PropertyState::Owned => "Owned",
+        PropertyState::Rented => "Rented",
+        PropertyState::Mission => "Mission",
+        PropertyState::None => "-",
+    }
+}
+
+fn diagnostic_text(message: &LocalizedMessage) -> String {
+    let mut params: Vec<String> = message
+        .params
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect();
+    params.sort();
+    if params.is_empty() {
+        message.code.clone()
+    } else {
+        format!("{} ({})", message.code, params.join(", "))
+    }
+}
+
+fn sections(data: &SavegameData) -> Vec<Section> {
+    let finances = Section {
+        title: "Farm finances".to_string(),
+        columns: vec!["Farm", "Name", "Money", "Loan"],
+        rows: data
+            .farms
+            .iter()
+            .map(|f| {
+                vec![
+                    f.farm_id.to_string(),
+                    f.name.clone(),
+                    format!("{:.0}", f.money),
+                    format!("{:.0}", f.loan),
+                ]
+            })
+            .collect(),
+    };
+
+    let fleet = Section {
+        title: format!("Fleet ({} vehicles)", data.vehicles.len()),
+        columns: vec!["Vehicle", "Id", "Farm", "State", "Age", "Hours", "Damage"],
+        rows: data
+            .vehicles
+            .iter()
+            .map(|v| {
+                vec![
+                    v.display_name.clone(),
+                    v.unique_id.clone(),
+                    v.farm_id.to_string(),
+                    property_state_label(&v.property_state).to_string(),
+                    format!("{:.0}", v.age),
+                    format!("{:.1}", v.operating_time),
+                    format!("{:.0}%", v.damage * 100.0),
+                ]
+            })
+            .collect(),
+    };
+
+    let fields = Section {
+        title: format!("Fields ({})", data.fields.len()),
+        columns: vec!["Field", "Owner", "Fruit", "Growth state"],
+        rows: data
+            .fields
+            .iter()
+            .map(|f| {
+                let owner = data
+                    .farmlands
+                    .iter()
+                    .find(|fl| fl.id == f.id)
+                    .map(|fl| fl.farm_id.to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                vec![
+                    f.id.to_string(),
+                    owner,
+                    f.fruit_type.clone(),
+                    f.growth_state.to_string(),
+                ]
+            })
+            .collect(),
+    };
+
+    let diagnostics = Section {
+        title: format!("Diagnostics ({})", data.warnings.len()),
+        columns: vec![],
+        rows: data
+            .warnings
+            .iter()
+            .map(|w| vec![diagnostic_text(w)])
+            .collect(),
+    };
+
+    vec![finances, fleet, fields, diagnostics]
+}
+
+fn markdown_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn render_markdown(data: &SavegameData, sections: &[Section]) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# {}\n", markdown_cell(&data.career.savegame_name));
+    let _ = writeln!(out, "- Map: {}", markdown_cell(&data.career.map_display));
+    let _ = writeln!(out, "- Saved: {}", data.career.save_date);
+    let _ = writeln!(out, "- Difficulty: {}", data.career.economic_difficulty);
+
+    for section in sections {
+        let _ = writeln!(out, "\n## {}\n", section.title);
+        if section.rows.is_empty() {
+            out.push_str("None\n");
+        } else if section.columns.is_empty() {
+            for row in &section.rows {
+                let _ = writeln!(out, "- {}", markdown_cell(&row.join(" ")));
+            }
+        } else {
+            let _ = writeln!(out, "| {} |", section.columns.join(" | "));
+            let _ = writeln!(out, "|{}", " --- |".repeat(section.columns.len()));
+            for row in &section.rows {
+                let cells: Vec<String> = row.iter().map(|c| markdown_cell(c)).collect();
+                let _ = writeln!(out, "| {} |", cells.join(" | "));
+            }
+        }
+    }
+
+    out
+}
+
+fn html_escape(value: &str) -> String {
+    quick_xml::escape::escape(value).into_owned()
+}
+
+fn render_html(data: &SavegameData, sections: &[Section]) -> String {
+    let title = html_escape(&data.career.savegame_name);
+    let mut out = String::new();
+    let _ = writeln!(
+        out,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>",
+        title
+    );
+    out.push_str("<style>body{font-family:sans-serif}table{border-collapse:collapse}td,th{border:1px solid #ccc;padding:4px 8px;text-align:left}</style>\n</head>\n<body>\n");
+    let _ = writeln!(out, "<h1>{}</h1>\n<ul>", title);
+    let _ = writeln!(
+        out,
+        "<li>Map: {}</li>",
+        html_escape(&data.career.map_display)
+    );
+    let _ = writeln!(
+        out,
+        "<li>Saved: {}</li>",
+        html_escape(&data.career.save_date)
+    );
+    let _ = writeln!(
+        out,
+        "<li>Difficulty: {}</li>\n</ul>",
+        html_escape(&data.career.economic_difficulty)
+    );
+
+    for section in sections {
+        let _ = writeln!(out, "<h2>{}</h2>", html_escape(&section.title));
+        if section.rows.is_empty() {
+            out.push_str("<p>None</p>\n");
+        } else if section.columns.is_empty() {
+            out.push_str("<ul>\n");
+            for row in &section.rows {
+                let _ = writeln!(out, "<li>{}</li>", html_escape(&row.join(" ")));
+            }
+            out.push_str("</ul>\n");
+        } else {
+            out.push_str("<table>\n<tr>");
+            for column in &section.columns {
+                let _ = write!(out, "<th>{}</th>", column);
+            }
+            out.push_str("</tr>\n");
+            for row in &section.rows {
+                out.push_str("<tr>");
+                for cell in row {
+                    let _ = write!(out, "<td>{}</td>", html_escape(cell));
+                }
+                out.push_str("</tr>\n");
+            }
+            out.push_str("</table>\n");
+        }
+    }
+
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+/// Render a human-readable summary of the savegame: finances, fleet, fields and diagnostics.
+pub fn render_report(data: &SavegameData, format: ReportFormat) -> String {
+    let sections = sections(data);
+    match format {
+        ReportFormat::Markdown => render_markdown(data, &sections),
+        ReportFormat::Html => render_html(data, &sections),
+    }
+}
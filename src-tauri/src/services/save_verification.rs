@@ -0,0 +1,571 @@
+use std::fmt::Display;
+use std::path::Path;
+
+use crate::error::AppError;
+use crate::models::changes::{SavegameChanges, UnappliedChange};
+use crate::models::common::LocalizedMessage;
+use crate::models::mission::MissionStatus;
+use crate::models::vehicle::PropertyState;
+use crate::parsers::collectible::parse_collectibles;
+use crate::parsers::contract::parse_contract_settings;
+use crate::parsers::economy::parse_economy;
+use crate::parsers::environment::parse_environment;
+use crate::parsers::farm::parse_farms;
+use crate::parsers::field::{parse_farmlands, parse_fields};
+use crate::parsers::files::{
+    COLLECTIBLES, CONTRACTS, ECONOMY, ENVIRONMENT, FARMLAND, FARMS, FIELDS, MISSIONS, PLACEABLES,
+    SALES, TREE_PLANT, VEHICLES,
+};
+use crate::parsers::mission::parse_missions;
+use crate::parsers::placeable::parse_placeables;
+use crate::parsers::sale::parse_sales;
+use crate::parsers::vehicle::parse_vehicles;
+
+/// Values are written with six decimals, so a smaller gap is not a difference.
+const FLOAT_TOLERANCE: f64 = 1e-3;
+
+/// Requested values a verification found missing from the savegame, the files
+/// that could not be read back to check them, and the sections of the changes
+/// that cannot be matched back and were not checked.
+#[derive(Debug, Default)]
+pub struct Verification {
+    pub unapplied: Vec<UnappliedChange>,
+    pub errors: Vec<LocalizedMessage>,
+    /// Keys of the unchecked sections, as in `SavegameChanges` (`trees`...)
+    pub unverified: Vec<String>,
+}
+
+impl Verification {
+    fn read<T>(&mut self, file: &str, result: Result<T, AppError>) -> Option<T> {
+        result
+            .map_err(|e| {
+                self.errors.push(
+                    LocalizedMessage::new("errors.verifyReadError")
+                        .with_param("file", file)
+                        .with_param("details", e),
+                )
+            })
+            .ok()
+    }
+
+    fn not_found(&mut self, file: &str, target: String) {
+        self.unapplied.push(UnappliedChange {
+            file: file.to_string(),
+            target,
+            property: None,
+            expected: None,
+            actual: None,
+        });
+    }
+
+    fn differs(
+        &mut self,
+        file: &str,
+        target: &str,
+        property: &str,
+        expected: String,
+        actual: String,
+    ) {
+        self.unapplied.push(UnappliedChange {
+            file: file.to_string(),
+            target: target.to_string(),
+            property: Some(property.to_string()),
+            expected: Some(expected),
+            actual: Some(actual),
+        });
+    }
+
+    fn check<T: PartialEq + Display>(
+        &mut self,
+        file: &str,
+        target: &str,
+        property: &str,
+        expected: Option<T>,
+        actual: T,
+    ) {
+        if let Some(expected) = expected.filter(|e| *e != actual) {
+            self.differs(
+                file,
+                target,
+                property,
+                expected.to_string(),
+                actual.to_string(),
+            );
+        }
+    }
+
+    fn check_f64(
+        &mut self,
+        file: &str,
+        target: &str,
+        property: &str,
+        expected: Option<f64>,
+        actual: f64,
+    ) {
+        if let Some(expected) = expected.filter(|e| (e - actual).abs() > FLOAT_TOLERANCE) {
+            self.differs(
+                file,
+                target,
+                property,
+                expected.to_string(),
+                actual.to_string(),
+            );
+        }
+    }
+}
+
+/// Read back the files of `files_modified` and compare them with what `changes`
+/// requested. Files not in `files_modified` are skipped: their writer failed and
+/// the save already reported it. Sale additions, trees, and sale or great demand
+/// batches that delete or add entries cannot be matched back: they are listed in
+/// `unverified` instead.
+pub fn verify_changes(
+    save_path: &Path,
+    changes: &SavegameChanges,
+    files_modified: &[String],
+) -> Verification {
+    let changes = &changes.selected_only();
+    let written = |file: &str| files_modified.iter().any(|f| f == file);
+    let mut verification = Verification::default();
+
+    if written(FARMS) && (changes.finance.is_some() || changes.farms.is_some()) {
+        if let Some(farms) = verification.read(FARMS, parse_farms(save_path)) {
+            if let Some(ref finance) = changes.finance {
+                match farms.iter().find(|f| f.farm_id == 1) {
+                    Some(farm) => {
+                        verification.check_f64(FARMS, "farm 1", "money", finance.money, farm.money);
+                        verification.check_f64(FARMS, "farm 1", "loan", finance.loan, farm.loan);
+                    }
+                    None => verification.not_found(FARMS, "farm 1".to_string()),
+                }
+            }
+            for change in changes.farms.iter().flatten() {
+                let target = format!("farm {}", change.farm_id);
+                match farms.iter().find(|f| f.farm_id == change.farm_id) {
+                    Some(farm) => {
+                        verification.check(FARMS, &target, "color", change.color, farm.color)
+                    }
+                    None => verification.not_found(FARMS, target),
+                }
+            }
+        }
+    }
+
+    if let Some(vehicle_changes) = changes.vehicles.as_ref().filter(|_| written(VEHICLES)) {
+        if let Some(vehicles) = verification.read(VEHICLES, parse_vehicles(save_path)) {
+            for change in vehicle_changes {
+                let target = &change.unique_id;
+                let vehicle = vehicles.iter().find(|v| &v.unique_id == target);
+                match vehicle {
+                    Some(_) if change.delete => verification.differs(
+                        VEHICLES,
+                        target,
+                        "delete",
+                        true.to_string(),
+                        false.to_string(),
+                    ),
+                    Some(v) => {
+                        verification.check_f64(VEHICLES, target, "age", change.age, v.age);
+                        verification.check_f64(VEHICLES, target, "price", change.price, v.price);
+                        verification.check(VEHICLES, target, "farmId", change.farm_id, v.farm_id);
+                        let property_state = change.property_state.as_deref();
+                        if property_state
+                            .is_some_and(|s| PropertyState::from_str(s) != v.property_state)
+                        {
+                            verification.differs(
+                                VEHICLES,
+                                target,
+                                "propertyState",
+                                property_state.unwrap_or_default().to_string(),
+                                format!("{:?}", v.property_state).to_uppercase(),
+                            );
+                        }
+                        verification.check_f64(
+                            VEHICLES,
+                            target,
+                            "operatingTime",
+                            change.operating_time,
+                            v.operating_time,
+                        );
+                        verification.check_f64(VEHICLES, target, "damage", change.damage, v.damage);
+                        verification.check_f64(VEHICLES, target, "wear", change.wear, v.wear);
+                        for unit_change in change.fill_units.iter().flatten() {
+                            let unit_target = format!("{} fill unit {}", target, unit_change.index);
+                            match v.fill_units.iter().find(|u| u.index == unit_change.index) {
+                                Some(unit) => verification.check_f64(
+                                    VEHICLES,
+                                    &unit_target,
+                                    "fillLevel",
+                                    Some(unit_change.fill_level),
+                                    unit.fill_level,
+                                ),
+                                None => verification.not_found(VEHICLES, unit_target),
+                            }
+                        }
+                    }
+                    None if change.delete => {}
+                    None => verification.not_found(VEHICLES, target.clone()),
+                }
+            }
+        }
+    }
+
+    // Deleting items shifts the index of the following ones, so only batches
+    // without deletions can be matched back by index
+    let sale_changes = changes.sales.as_ref().filter(|_| written(SALES));
+    let sales_shifted = sale_changes.is_some_and(|sales| sales.iter().any(|s| s.delete));
+    if sales_shifted {
+        verification.unverified.push("sales".to_string());
+    }
+    if changes.sale_additions.is_some() && written(SALES) {
+        verification.unverified.push("saleAdditions".to_string());
+    }
+    if let Some(sale_changes) = sale_changes.filter(|_| !sales_shifted) {
+        if let Some(sales) = verification.read(SALES, parse_sales(save_path)) {
+            for change in sale_changes {
+                let target = format!("sale item {}", change.index);
+                match sales.iter().find(|s| s.index == change.index) {
+                    Some(s) => {
+                        verification.check(SALES, &target, "price", change.price, s.price);
+                        verification.check_f64(SALES, &target, "damage", change.damage, s.damage);
+                        verification.check_f64(SALES, &target, "wear", change.wear, s.wear);
+                        verification.check(SALES, &target, "age", change.age, s.age);
+                        verification.check_f64(
+                            SALES,
+                            &target,
+                            "operatingTime",
+                            change.operating_time,
+                            s.operating_time,
+                        );
+                        verification.check(
+                            SALES,
+                            &target,
+                            "timeLeft",
+                            change.time_left,
+                            s.time_left,
+                        );
+                    }
+                    None => verification.not_found(SALES, target),
+                }
+            }
+        }
+    }
+
+    if let Some(field_changes) = changes.fields.as_ref().filter(|_| written(FIELDS)) {
+        if let Some(fields) = verification.read(FIELDS, parse_fields(save_path)) {
+            for change in field_changes {
+                let target = format!("field {}", change.id);
+                let Some(f) = fields.iter().find(|f| f.id == change.id) else {
+                    verification.not_found(FIELDS, target);
+                    continue;
+                };
+                let texts = [
+                    ("fruitType", &change.fruit_type, &f.fruit_type),
+                    ("plannedFruit", &change.planned_fruit, &f.planned_fruit),
+                    ("groundType", &change.ground_type, &f.ground_type),
+                    ("sprayType", &change.spray_type, &f.spray_type),
+                ];
+                for (property, expected, actual) in texts {
+                    verification.check(FIELDS, &target, property, expected.as_ref(), actual);
+                }
+                let levels = [
+                    ("growthState", change.growth_state, f.growth_state),
+                    ("weedState", change.weed_state, f.weed_state),
+                    ("stoneLevel", change.stone_level, f.stone_level),
+                    ("sprayLevel", change.spray_level, f.spray_level),
+                    ("limeLevel", change.lime_level, f.lime_level),
+                    ("plowLevel", change.plow_level, f.plow_level),
+                    ("rollerLevel", change.roller_level, f.roller_level),
+                    (
+                        "stubbleShredLevel",
+                        change.stubble_shred_level,
+                        f.stubble_shred_level,
+                    ),
+                    ("waterLevel", change.water_level, f.water_level),
+                ];
+                for (property, expected, actual) in levels {
+                    verification.check(FIELDS, &target, property, expected, actual);
+                }
+            }
+        }
+    }
+
+    if let Some(farmland_changes) = changes.farmlands.as_ref().filter(|_| written(FARMLAND)) {
+        if let Some(farmlands) = verification.read(FARMLAND, parse_farmlands(save_path)) {
+            for change in farmland_changes {
+                let target = format!("farmland {}", change.id);
+                match farmlands.iter().find(|f| f.id == change.id) {
+                    Some(f) => verification.check(
+                        FARMLAND,
+                        &target,
+                        "farmId",
+                        Some(change.farm_id),
+                        f.farm_id,
+                    ),
+                    None => verification.not_found(FARMLAND, target),
+                }
+            }
+        }
+    }
+
+    if let Some(placeable_changes) = changes.placeables.as_ref().filter(|_| written(PLACEABLES)) {
+        if let Some(placeables) = verification.read(PLACEABLES, parse_placeables(save_path)) {
+            for change in placeable_changes {
+                let target = format!("placeable {}", change.index);
+                match placeables.iter().find(|p| p.index == change.index) {
+                    Some(p) => {
+                        verification.check(
+                            PLACEABLES,
+                            &target,
+                            "farmId",
+                            change.farm_id,
+                            p.farm_id,
+                        );
+                        verification.check_f64(PLACEABLES, &target, "price", change.price, p.price);
+                        if change.complete_construction && p.is_under_construction {
+                            verification.differs(
+                                PLACEABLES,
+                                &target,
+                                "completeConstruction",
+                                true.to_string(),
+                                false.to_string(),
+                            );
+                        }
+                    }
+                    None => verification.not_found(PLACEABLES, target),
+                }
+            }
+        }
+    }
+
+    if let Some(mission_changes) = changes.missions.as_ref().filter(|_| written(MISSIONS)) {
+        if let Some(missions) = verification.read(MISSIONS, parse_missions(save_path)) {
+            for change in mission_changes {
+                let target = &change.unique_id;
+                let Some(m) = missions.iter().find(|m| &m.unique_id == target) else {
+                    verification.not_found(MISSIONS, target.clone());
+                    continue;
+                };
+                verification.check_f64(MISSIONS, target, "reward", change.reward, m.reward);
+                verification.check_f64(
+                    MISSIONS,
+                    target,
+                    "completion",
+                    change.completion,
+                    m.completion,
+                );
+                let status = change.status.as_deref().map(MissionStatus::from_str);
+                verification.check(
+                    MISSIONS,
+                    target,
+                    "status",
+                    status.as_ref().map(MissionStatus::to_xml_str),
+                    m.status.to_xml_str(),
+                );
+                verification.check_f64(
+                    MISSIONS,
+                    target,
+                    "reimbursement",
+                    change.reimbursement,
+                    m.reimbursement,
+                );
+                if let Some(expected) = change.deposited_liters {
+                    verification.check_f64(
+                        MISSIONS,
+                        target,
+                        "depositedLiters",
+                        Some(expected),
+                        m.deposited_liters.unwrap_or_default(),
+                    );
+                }
+            }
+        }
+    }
+
+    if let Some(env_changes) = changes
+        .environment
+        .as_ref()
+        .filter(|_| written(ENVIRONMENT))
+    {
+        if let Some(env) = verification.read(ENVIRONMENT, parse_environment(save_path)) {
+            let target = "environment";
+            verification.check_f64(
+                ENVIRONMENT,
+                target,
+                "dayTime",
+                env_changes.day_time,
+                env.day_time,
+            );
+            verification.check(
+                ENVIRONMENT,
+                target,
+                "currentDay",
+                env_changes.current_day,
+                env.current_day,
+            );
+            verification.check_f64(
+                ENVIRONMENT,
+                target,
+                "snowHeight",
+                env_changes.snow_height,
+                env.snow_height,
+            );
+            verification.check_f64(
+                ENVIRONMENT,
+                target,
+                "groundWetness",
+                env_changes.ground_wetness,
+                env.ground_wetness,
+            );
+        }
+    }
+
+    if let Some(collectible_changes) = changes
+        .collectibles
+        .as_ref()
+        .filter(|_| written(COLLECTIBLES))
+    {
+        if let Some(collectibles) = verification.read(COLLECTIBLES, parse_collectibles(save_path)) {
+            for change in collectible_changes {
+                let target = format!("collectible {}", change.index);
+                match collectibles.iter().find(|c| c.index == change.index) {
+                    Some(c) => {
+                        verification.check(
+                            COLLECTIBLES,
+                            &target,
+                            "collected",
+                            Some(change.collected),
+                            c.collected,
+                        );
+                        // Only saves that store the marker state get it written
+                        if let Some(marked) = c.marked {
+                            verification.check(
+                                COLLECTIBLES,
+                                &target,
+                                "marked",
+                                change.marked,
+                                marked,
+                            );
+                        }
+                    }
+                    None => verification.not_found(COLLECTIBLES, target),
+                }
+            }
+        }
+    }
+
+    if let Some(contract_changes) = changes
+        .contract_settings
+        .as_ref()
+        .filter(|_| written(CONTRACTS))
+    {
+        if let Some(settings) = verification.read(CONTRACTS, parse_contract_settings(save_path)) {
+            let target = "contract settings";
+            verification.check_f64(
+                CONTRACTS,
+                target,
+                "leaseVehicle",
+                contract_changes.lease_vehicle,
+                settings.lease_vehicle,
+            );
+            verification.check_f64(
+                CONTRACTS,
+                target,
+                "missionPerFarm",
+                contract_changes.mission_per_farm,
+                settings.mission_per_farm,
+            );
+            verification.check_f64(
+                CONTRACTS,
+                target,
+                "allowClearAdd",
+                contract_changes.allow_clear_add,
+                settings.allow_clear_add,
+            );
+        }
+    }
+
+    if let Some(economy_changes) = changes.economy.as_ref().filter(|_| written(ECONOMY)) {
+        // Like sale items, demands are matched back by slot only when none moved
+        let shifted = economy_changes
+            .great_demand_additions
+            .as_ref()
+            .is_some_and(|a| !a.is_empty())
+            || economy_changes
+                .great_demand_deletions
+                .as_ref()
+                .is_some_and(|d| !d.is_empty());
+        if shifted {
+            verification.unverified.push("economy".to_string());
+        } else if let Some(demand_changes) = economy_changes.great_demand_changes.as_ref() {
+            if let Some(economy) = verification.read(ECONOMY, parse_economy(save_path)) {
+                for change in demand_changes {
+                    let demand = match change.unique_id.as_deref() {
+                        Some(id) => economy.great_demands.iter().find(|d| d.unique_id == id),
+                        None => economy
+                            .great_demands
+                            .iter()
+                            .find(|d| d.index == change.index),
+                    };
+                    let target = change
+                        .unique_id
+                        .clone()
+                        .unwrap_or_else(|| format!("great demand {}", change.index));
+                    let Some(d) = demand else {
+                        verification.not_found(ECONOMY, target);
+                        continue;
+                    };
+                    verification.check(
+                        ECONOMY,
+                        &target,
+                        "fillTypeName",
+                        change.fill_type_name.as_ref(),
+                        &d.fill_type_name,
+                    );
+                    verification.check_f64(
+                        ECONOMY,
+                        &target,
+                        "demandMultiplier",
+                        change.demand_multiplier,
+                        d.demand_multiplier,
+                    );
+                    verification.check(
+                        ECONOMY,
+                        &target,
+                        "demandStartDay",
+                        change.demand_start_day,
+                        d.demand_start_day,
+                    );
+                    verification.check(
+                        ECONOMY,
+                        &target,
+                        "demandStartHour",
+                        change.demand_start_hour,
+                        d.demand_start_hour,
+                    );
+                    verification.check(
+                        ECONOMY,
+                        &target,
+                        "demandDuration",
+                        change.demand_duration,
+                        d.demand_duration,
+                    );
+                    verification.check(
+                        ECONOMY,
+                        &target,
+                        "isRunning",
+                        change.is_running,
+                        d.is_running,
+                    );
+                    verification.check(ECONOMY, &target, "isValid", change.is_valid, d.is_valid);
+                }
+            }
+        }
+    }
+
+    if changes.trees.is_some() && written(TREE_PLANT) {
+        verification.unverified.push("trees".to_string());
+    }
+
+    verification
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::error::AppError;
+use crate::models::save_diff::{FileChange, FileDiff, SavegameDiff};
+use crate::services::xml_diff::xml_equivalent;
+
+/// Names of the regular files directly inside a savegame folder.
+fn file_names(dir: &Path) -> Result<BTreeSet<String>, AppError> {
+    let mut names = BTreeSet::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() {
+            names.insert(entry.file_name().to_string_lossy().to_string());
+        }
+    }
+    Ok(names)
+}
+
+/// Whether a file present in both folders holds the same content.
+/// XML files are compared with `xml_equivalent`, other files byte for byte.
+fn same_file(before: &Path, after: &Path) -> Result<bool, AppError> {
+    let left = std::fs::read(before)?;
+    let right = std::fs::read(after)?;
+    if left == right {
+        return Ok(true);
+    }
+    if before.extension().and_then(|e| e.to_str()) != Some("xml") {
+        return Ok(false);
+    }
+    Ok(xml_equivalent(
+        &String::from_utf8_lossy(&left),
+        &String::from_utf8_lossy(&right),
+    ))
+}
+
+/// Compares two savegame folders file by file.
+pub fn diff_savegame_dirs(before: &Path, after: &Path) -> Result<SavegameDiff, AppError> {
+    let before_files = file_names(before)?;
+    let after_files = file_names(after)?;
+
+    let mut files = Vec::new();
+    for file in before_files.union(&after_files) {
+        let change = match (before_files.contains(file), after_files.contains(file)) {
+            (true, false) => FileChange::Removed,
+            (false, true) => FileChange::Added,
+            _ if same_file(&before.join(file), &after.join(file))? => continue,
+            _ => FileChange::Modified,
+        };
+        files.push(FileDiff {
+            file: file.clone(),
+            change,
+        });
+    }
+
+    Ok(SavegameDiff {
+        before_path: before.display().to_string(),
+        after_path: after.display().to_string(),
+        files,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_diff_savegame_dirs() {
+        let base = std::env::temp_dir().join("fs25_test_savegame_diff");
+        let _ = fs::remove_dir_all(&base);
+        let before = base.join("before");
+        let after = base.join("after");
+        fs::create_dir_all(&before).unwrap();
+        fs::create_dir_all(&after).unwrap();
+
+        fs::write(
+            before.join("farms.xml"),
+            r#"<farms><farm money="1000"/></farms>"#,
+        )
+        .unwrap();
+        fs::write(
+            after.join("farms.xml"),
+            "<farms>\n    <farm money=\"1000.000000\" />\n</farms>",
+        )
+        .unwrap();
+        fs::write(
+            before.join("sales.xml"),
+            r#"<sales><item age="1"/></sales>"#,
+        )
+        .unwrap();
+        fs::write(after.join("sales.xml"), r#"<sales><item age="2"/></sales>"#).unwrap();
+        fs::write(before.join("old.xml"), "<old/>").unwrap();
+        fs::write(after.join("density.gdm"), [1u8, 2, 3]).unwrap();
+
+        let diff = diff_savegame_dirs(&before, &after).unwrap();
+        let files: Vec<(&str, FileChange)> = diff
+            .files
+            .iter()
+            .map(|f| (f.file.as_str(), f.change))
+            .collect();
+        assert_eq!(
+            files,
+            vec![
+                ("density.gdm", FileChange::Added),
+                ("old.xml", FileChange::Removed),
+                ("sales.xml", FileChange::Modified),
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&base);
+    }
+}
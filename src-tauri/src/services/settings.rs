@@ -0,0 +1,161 @@
+use std::path::Path;
+
+use serde_json::Value as JsonValue;
+
+use crate::error::AppError;
+use crate::models::settings::{AppSettings, SettingsExport};
+use crate::parsers::files::validate_name_overrides;
+
+/// Plugin-store file holding the user's settings.
+pub const SETTINGS_STORE_FILE: &str = "settings.json";
+
+/// Current version of the exported settings file format.
+pub const SETTINGS_EXPORT_VERSION: u32 = 1;
+
+const LOCALES: &[&str] = &["fr", "en"];
+const THEMES: &[&str] = &["light", "dark", "system"];
+const MAX_BACKUPS_RANGE: std::ops::RangeInclusive<u32> = 1..=100;
+
+/// Build settings from raw store entries. Unknown keys are ignored.
+pub fn settings_from_entries(entries: Vec<(String, JsonValue)>) -> Result<AppSettings, AppError> {
+    let object: serde_json::Map<String, JsonValue> = entries.into_iter().collect();
+    serde_json::from_value(JsonValue::Object(object)).map_err(|e| AppError::SettingsError {
+        message: e.to_string(),
+    })
+}
+
+/// Store entries for every setting that has a value.
+pub fn settings_to_entries(settings: &AppSettings) -> Result<Vec<(String, JsonValue)>, AppError> {
+    match serde_json::to_value(settings) {
+        Ok(JsonValue::Object(object)) => Ok(object
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .collect()),
+        Ok(_) => Ok(Vec::new()),
+        Err(e) => Err(AppError::SettingsError {
+            message: e.to_string(),
+        }),
+    }
+}
+
+/// Reject settings the frontend could not load back.
+pub fn validate_settings(settings: &AppSettings) -> Result<(), AppError> {
+    if let Some(ref locale) = settings.locale {
+        if !LOCALES.contains(&locale.as_str()) {
+            return Err(AppError::SettingsError {
+                message: format!("Unsupported locale: {}", locale),
+            });
+        }
+    }
+    if let Some(ref theme) = settings.theme {
+        if !THEMES.contains(&theme.as_str()) {
+            return Err(AppError::SettingsError {
+                message: format!("Unsupported theme: {}", theme),
+            });
+        }
+    }
+    if let Some(max_backups) = settings.max_backups {
+        if !MAX_BACKUPS_RANGE.contains(&max_backups) {
+            return Err(AppError::SettingsError {
+                message: format!("maxBackups out of range: {}", max_backups),
+            });
+        }
+    }
+    if let Some(ref names) = settings.save_file_names {
+        validate_name_overrides(names)?;
+    }
+    Ok(())
+}
+
+/// Write settings to an export file.
+pub fn export_settings(destination: &Path, settings: &AppSettings) -> Result<(), AppError> {
+    let export = SettingsExport {
+        version: SETTINGS_EXPORT_VERSION,
+        exported_at: chrono::Local::now().to_rfc3339(),
+        settings: settings.clone(),
+    };
+    let json = serde_json::to_string_pretty(&export).map_err(|e| AppError::SettingsError {
+        message: e.to_string(),
+    })?;
+    std::fs::write(destination, json)?;
+    Ok(())
+}
+
+/// Read and validate an export file.
+pub fn read_settings_export(source: &Path) -> Result<AppSettings, AppError> {
+    let content = std::fs::read_to_string(source)?;
+    let export: SettingsExport =
+        serde_json::from_str(&content).map_err(|e| AppError::SettingsError {
+            message: e.to_string(),
+        })?;
+    if export.version > SETTINGS_EXPORT_VERSION {
+        return Err(AppError::SettingsError {
+            message: format!("Unsupported settings file version: {}", export.version),
+        });
+    }
+    validate_settings(&export.settings)?;
+    Ok(export.settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn sample_settings() -> AppSettings {
+        AppSettings {
+            locale: Some("en".to_string()),
+            theme: Some("dark".to_string()),
+            advanced_mode: Some(true),
+            disclaimer_accepted: Some(true),
+            default_path: Some("/saves".to_string()),
+            max_backups: Some(15),
+            game_path: None,
+            check_for_updates_on_startup: Some(false),
+            save_file_names: Some(HashMap::from([(
+                "r_contracts.xml".to_string(),
+                "contracts.xml".to_string(),
+            )])),
+        }
+    }
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let file = std::env::temp_dir().join("fs25_test_settings_roundtrip.json");
+        let settings = sample_settings();
+        export_settings(&file, &settings).unwrap();
+        let imported = read_settings_export(&file).unwrap();
+        assert_eq!(imported, settings);
+        let _ = std::fs::remove_file(&file);
+    }
+
+    #[test]
+    fn test_entries_roundtrip_skips_unset_and_unknown_keys() {
+        let settings = sample_settings();
+        let mut entries = settings_to_entries(&settings).unwrap();
+        assert!(!entries.iter().any(|(k, _)| k == "gamePath"));
+        assert!(entries.iter().any(|(k, _)| k == "checkForUpdatesOnStartup"));
+
+        entries.push(("somethingElse".to_string(), JsonValue::from(1)));
+        assert_eq!(settings_from_entries(entries).unwrap(), settings);
+    }
+
+    #[test]
+    fn test_import_rejects_invalid_settings() {
+        let file = std::env::temp_dir().join("fs25_test_settings_invalid.json");
+
+        std::fs::write(&file, r#"{"version": 1, "exportedAt": "", "settings": {"theme": "neon"}}"#).unwrap();
+        assert!(matches!(read_settings_export(&file), Err(AppError::SettingsError { .. })));
+
+        std::fs::write(&file, r#"{"version": 1, "exportedAt": "", "settings": {"maxBackups": 0}}"#).unwrap();
+        assert!(matches!(read_settings_export(&file), Err(AppError::SettingsError { .. })));
+
+        std::fs::write(&file, r#"{"version": 99, "exportedAt": "", "settings": {}}"#).unwrap();
+        assert!(matches!(read_settings_export(&file), Err(AppError::SettingsError { .. })));
+
+        std::fs::write(&file, "not json").unwrap();
+        assert!(matches!(read_settings_export(&file), Err(AppError::SettingsError { .. })));
+
+        let _ = std::fs::remove_file(&file);
+    }
+}
@@ -6,8 +6,77 @@ use std::sync::{Arc, Mutex};
 
 use quick_xml::events::Event;
 use quick_xml::Reader;
+use serde::Serialize;
 
 use crate::error::AppError;
+use crate::services::catalog::is_mod_folder;
+
+/// Where the files of a mod live: its zip, or an unzipped folder.
+enum ModSource {
+    Zip(PathBuf),
+    Folder(PathBuf),
+}
+
+impl ModSource {
+    /// Locate `mod_name` in the mods directory, preferring the zip when both exist.
+    fn locate(mods_dir: &Path, mod_name: &str) -> Option<Self> {
+        let zip_path = mods_dir.join(format!("{}.zip", mod_name));
+        if zip_path.exists() {
+            return Some(ModSource::Zip(zip_path));
+        }
+        let folder = mods_dir.join(mod_name);
+        is_mod_folder(&folder).then_some(ModSource::Folder(folder))
+    }
+
+    /// Read a file given by its path relative to the mod root.
+    fn read(&self, internal_path: &str) -> Option<Vec<u8>> {
+        match self {
+            ModSource::Zip(zip_path) => {
+                VehicleImageService::read_file_from_zip(zip_path, internal_path)
+            }
+            ModSource::Folder(folder) => {
+                fs::read(folder.join(internal_path.replace('\\', "/"))).ok()
+            }
+        }
+    }
+}
+
+/// Why a vehicle has no image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImageFailure {
+    /// Encrypted DLC, whose files cannot be read
+    SkippedPdlc,
+    /// Neither `ModName.zip` nor an unzipped `ModName` folder is in the mods directory
+    ModNotFound,
+    XmlNotFound,
+    /// The vehicle XML declares no store image
+    XmlNoImage,
+    DdsNotFound,
+    DecodeFailed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImageRoute {
+    BaseGame,
+    Mod,
+    Skipped,
+}
+
+/// Outcome of resolving one vehicle image, for troubleshooting missing thumbnails.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageDiagnosis {
+    pub filename: String,
+    pub route: ImageRoute,
+    pub image_path: Option<String>,
+    pub failure: Option<ImageFailure>,
+    /// I/O error that interrupted the resolution
+    pub error: Option<String>,
+}
+
+type Resolution = Result<PathBuf, ImageFailure>;
 
 #[derive(Clone)]
 pub struct VehicleImageService {
@@ -156,14 +225,7 @@ impl VehicleImageService {
         mods_dir: &Path,
         vehicle_filename: &str,
     ) -> Result<Option<PathBuf>, AppError> {
-        // Skip encrypted DLC vehicles (no way to extract)
-        if vehicle_filename.contains("$pdlcdir$") || vehicle_filename.contains("$dlcdir$") {
-            return Ok(None);
-        }
-
-        // Skip absolute paths to PDLC/DLC directories
-        let lower = vehicle_filename.to_lowercase();
-        if lower.contains("/pdlc/") || lower.contains("\\pdlc\\") {
+        if Self::is_dlc_filename(vehicle_filename) {
             return Ok(None);
         }
 
@@ -175,15 +237,9 @@ impl VehicleImageService {
             }
         }
 
-        let result = if vehicle_filename.contains("$moddir$") {
-            self.resolve_mod_image(game_path, mods_dir, vehicle_filename)
-        } else if let Some(converted) = Self::try_convert_absolute_mod_path(vehicle_filename) {
-            // Sale items use absolute paths like "C:/.../mods/ModName/vehicle.xml"
-            // Convert to $moddir$ format so resolve_mod_image can handle it
-            self.resolve_mod_image(game_path, mods_dir, &converted)
-        } else {
-            self.resolve_base_game_image(game_path, vehicle_filename)
-        };
+        let result = self
+            .resolve_uncached(game_path, mods_dir, vehicle_filename)
+            .map(|(_, resolution)| resolution.ok());
 
         // Store in cache regardless of result
         let cache_value = match &result {
@@ -198,25 +254,88 @@ impl VehicleImageService {
         result
     }
 
+    /// Resolve a vehicle image bypassing the in-memory index, reporting the route
+    /// taken and why no image was found.
+    pub fn diagnose_image(
+        &self,
+        game_path: &Path,
+        mods_dir: &Path,
+        vehicle_filename: &str,
+    ) -> ImageDiagnosis {
+        let mut diagnosis = ImageDiagnosis {
+            filename: vehicle_filename.to_string(),
+            route: ImageRoute::Skipped,
+            image_path: None,
+            failure: None,
+            error: None,
+        };
+        if Self::is_dlc_filename(vehicle_filename) {
+            diagnosis.failure = Some(ImageFailure::SkippedPdlc);
+            return diagnosis;
+        }
+
+        match self.resolve_uncached(game_path, mods_dir, vehicle_filename) {
+            Ok((route, resolution)) => {
+                diagnosis.route = route;
+                match resolution {
+                    Ok(path) => diagnosis.image_path = Some(path.to_string_lossy().to_string()),
+                    Err(failure) => diagnosis.failure = Some(failure),
+                }
+            }
+            Err(e) => diagnosis.error = Some(e.to_string()),
+        }
+        diagnosis
+    }
+
+    /// Encrypted DLC vehicles, whose files cannot be extracted.
+    fn is_dlc_filename(vehicle_filename: &str) -> bool {
+        let lower = vehicle_filename.to_lowercase();
+        vehicle_filename.contains("$pdlcdir$")
+            || vehicle_filename.contains("$dlcdir$")
+            // Absolute paths to PDLC/DLC directories
+            || lower.contains("/pdlc/")
+            || lower.contains("\\pdlc\\")
+    }
+
+    fn resolve_uncached(
+        &self,
+        game_path: &Path,
+        mods_dir: &Path,
+        vehicle_filename: &str,
+    ) -> Result<(ImageRoute, Resolution), AppError> {
+        if vehicle_filename.contains("$moddir$") {
+            let resolution = self.resolve_mod_image(game_path, mods_dir, vehicle_filename)?;
+            Ok((ImageRoute::Mod, resolution))
+        } else if let Some(converted) = Self::try_convert_absolute_mod_path(vehicle_filename) {
+            // Sale items use absolute paths like "C:/.../mods/ModName/vehicle.xml"
+            // Convert to $moddir$ format so resolve_mod_image can handle it
+            let resolution = self.resolve_mod_image(game_path, mods_dir, &converted)?;
+            Ok((ImageRoute::Mod, resolution))
+        } else {
+            let resolution = self.resolve_base_game_image(game_path, vehicle_filename)?;
+            Ok((ImageRoute::BaseGame, resolution))
+        }
+    }
+
     /// Resolve image for a base game vehicle (files on disk).
     fn resolve_base_game_image(
         &self,
         game_path: &Path,
         vehicle_filename: &str,
-    ) -> Result<Option<PathBuf>, AppError> {
+    ) -> Result<Resolution, AppError> {
         let cache_key = vehicle_filename
             .replace(['/', '\\'], "_")
             .replace(".xml", ".png");
         let png_path = self.cache_dir.join(&cache_key);
 
         if png_path.exists() {
-            return Ok(Some(png_path));
+            return Ok(Ok(png_path));
         }
 
         // Savegame filenames are like "data/vehicles/brand/model/model.xml"
         let xml_path = game_path.join(vehicle_filename.replace('/', "\\"));
         if !xml_path.exists() {
-            return Ok(None);
+            return Ok(Err(ImageFailure::XmlNotFound));
         }
 
         let xml_content = fs::read_to_string(&xml_path).map_err(|_| AppError::ImageError {
@@ -225,36 +344,33 @@ impl VehicleImageService {
 
         let image_ref = match Self::extract_image_from_xml(&xml_content) {
             Some(r) => r,
-            None => return Ok(None),
+            None => return Ok(Err(ImageFailure::XmlNoImage)),
         };
 
         let dds_path = Self::resolve_dds_path(game_path, &image_ref);
         if !dds_path.exists() {
-            return Ok(None);
+            return Ok(Err(ImageFailure::DdsNotFound));
         }
 
         let dds_data = fs::read(&dds_path)?;
-        match Self::convert_dds_bytes_to_png(&dds_data, &png_path) {
-            Ok(()) => Ok(Some(png_path)),
-            Err(_) => Ok(None),
-        }
+        Ok(Self::convert_to_cache(&dds_data, png_path))
     }
 
-    /// Resolve image for a mod vehicle (files inside a .zip).
+    /// Resolve image for a mod vehicle (files inside a .zip or an unzipped folder).
     /// Filename format: `$moddir$ModName/path/to/vehicle.xml`
     fn resolve_mod_image(
         &self,
         game_path: &Path,
         mods_dir: &Path,
         vehicle_filename: &str,
-    ) -> Result<Option<PathBuf>, AppError> {
+    ) -> Result<Resolution, AppError> {
         let cache_key = vehicle_filename
             .replace(['/', '\\', '$'], "_")
             .replace(".xml", ".png");
         let png_path = self.cache_dir.join(&cache_key);
 
         if png_path.exists() {
-            return Ok(Some(png_path));
+            return Ok(Ok(png_path));
         }
 
         // Parse "$moddir$ModName/internal/path.xml"
@@ -263,53 +379,54 @@ impl VehicleImageService {
             .unwrap_or(vehicle_filename);
         let (mod_name, internal_xml_path) = match after_prefix.split_once('/') {
             Some((name, path)) => (name, path),
-            None => return Ok(None),
+            None => return Ok(Err(ImageFailure::XmlNotFound)),
         };
 
-        let zip_path = mods_dir.join(format!("{}.zip", mod_name));
-        if !zip_path.exists() {
-            return Ok(None);
-        }
+        let source = match ModSource::locate(mods_dir, mod_name) {
+            Some(source) => source,
+            None => return Ok(Err(ImageFailure::ModNotFound)),
+        };
 
-        // Read the vehicle XML from the zip
-        let xml_content = match Self::read_file_from_zip(&zip_path, internal_xml_path) {
+        // Read the vehicle XML from the mod
+        let xml_content = match source.read(internal_xml_path) {
             Some(data) => String::from_utf8_lossy(&data).to_string(),
-            None => return Ok(None),
+            None => return Ok(Err(ImageFailure::XmlNotFound)),
         };
 
         let image_ref = match Self::extract_image_from_xml(&xml_content) {
             Some(r) => r,
-            None => return Ok(None),
+            None => return Ok(Err(ImageFailure::XmlNoImage)),
         };
 
-        // If image_ref starts with $data/, read from game directory (not the zip)
+        // If image_ref starts with $data/, read from game directory (not the mod)
         if image_ref.starts_with("$data/") {
             let dds_path = Self::resolve_dds_path(game_path, &image_ref);
             if !dds_path.exists() {
-                return Ok(None);
+                return Ok(Err(ImageFailure::DdsNotFound));
             }
             let dds_data = fs::read(&dds_path)?;
-            return match Self::convert_dds_bytes_to_png(&dds_data, &png_path) {
-                Ok(()) => Ok(Some(png_path)),
-                Err(_) => Ok(None),
-            };
+            return Ok(Self::convert_to_cache(&dds_data, png_path));
         }
 
-        // Image path is relative to the zip root, fix .png → .dds
+        // Image path is relative to the mod root, fix .png → .dds
         let dds_internal_path = if image_ref.ends_with(".png") {
             format!("{}.dds", &image_ref[..image_ref.len() - 4])
         } else {
             image_ref.clone()
         };
 
-        let dds_data = match Self::read_file_from_zip(&zip_path, &dds_internal_path) {
+        let dds_data = match source.read(&dds_internal_path) {
             Some(data) => data,
-            None => return Ok(None),
+            None => return Ok(Err(ImageFailure::DdsNotFound)),
         };
 
-        match Self::convert_dds_bytes_to_png(&dds_data, &png_path) {
-            Ok(()) => Ok(Some(png_path)),
-            Err(_) => Ok(None),
+        Ok(Self::convert_to_cache(&dds_data, png_path))
+    }
+
+    fn convert_to_cache(dds_data: &[u8], png_path: PathBuf) -> Resolution {
+        match Self::convert_dds_bytes_to_png(dds_data, &png_path) {
+            Ok(()) => Ok(png_path),
+            Err(_) => Err(ImageFailure::DecodeFailed),
         }
     }
 
@@ -361,6 +478,16 @@ impl VehicleImageService {
         Ok(size)
     }
 
+    /// Forget which images were resolved, keeping the converted PNGs on disk, so
+    /// vehicles without an image (e.g. from a since-updated mod) are looked up again.
+    /// Returns the number of entries dropped.
+    pub fn reset_index(&self) -> usize {
+        let mut cache = self.index_cache.lock().unwrap();
+        let count = cache.len();
+        cache.clear();
+        count
+    }
+
     /// Get total size of cached images in bytes.
     pub fn cache_size(&self) -> u64 {
         if !self.cache_dir.exists() {
@@ -379,3 +506,42 @@ impl VehicleImageService {
             .unwrap_or(0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diagnose_image_failures() {
+        let base = std::env::temp_dir().join("fs25_test_image_diagnosis");
+        let _ = fs::remove_dir_all(&base);
+        let game = base.join("game");
+        let mods = base.join("mods");
+        fs::create_dir_all(&mods).unwrap();
+        let service = VehicleImageService::new(base.join("cache")).unwrap();
+
+        let diagnose = |filename: &str| service.diagnose_image(&game, &mods, filename);
+
+        let pdlc = diagnose("$pdlcdir$agiPack/vehicles/tractor.xml");
+        assert_eq!(pdlc.route, ImageRoute::Skipped);
+        assert_eq!(pdlc.failure, Some(ImageFailure::SkippedPdlc));
+
+        let missing_mod = diagnose("$moddir$FS25_Gone/tractor.xml");
+        assert_eq!(missing_mod.route, ImageRoute::Mod);
+        assert_eq!(missing_mod.failure, Some(ImageFailure::ModNotFound));
+
+        let folder = mods.join("FS25_NoImage");
+        fs::create_dir_all(&folder).unwrap();
+        fs::write(folder.join("modDesc.xml"), "<modDesc/>").unwrap();
+        fs::write(
+            folder.join("tractor.xml"),
+            "<vehicle><storeData><name>T</name></storeData></vehicle>",
+        )
+        .unwrap();
+        let no_image = diagnose("$moddir$FS25_NoImage/tractor.xml");
+        assert_eq!(no_image.failure, Some(ImageFailure::XmlNoImage));
+        assert!(no_image.image_path.is_none());
+
+        let _ = fs::remove_dir_all(&base);
+    }
+}
@@ -0,0 +1,130 @@
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+#[derive(Debug)]
+enum Node {
+    Start(String, Vec<(String, String)>),
+    End(String),
+    Text(String),
+}
+
+fn attributes(e: &BytesStart) -> Vec<(String, String)> {
+    let mut attrs: Vec<(String, String)> = e
+        .attributes()
+        .flatten()
+        .map(|a| {
+            (
+                String::from_utf8_lossy(a.key.as_ref()).to_string(),
+                a.unescape_value().unwrap_or_default().to_string(),
+            )
+        })
+        .collect();
+    attrs.sort();
+    attrs
+}
+
+fn nodes(xml: &str) -> Option<Vec<Node>> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+    let mut nodes = Vec::new();
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                nodes.push(Node::Start(name, attributes(e)));
+            }
+            Ok(Event::Empty(ref e)) => {
+                let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                nodes.push(Node::Start(name.clone(), attributes(e)));
+                nodes.push(Node::End(name));
+            }
+            Ok(Event::End(ref e)) => {
+                nodes.push(Node::End(
+                    String::from_utf8_lossy(e.name().as_ref()).to_string(),
+                ));
+            }
+            Ok(Event::Text(ref e)) => {
+                nodes.push(Node::Text(e.unescape().ok()?.to_string()));
+            }
+            Ok(Event::CData(ref e)) => {
+                nodes.push(Node::Text(String::from_utf8_lossy(e).to_string()));
+            }
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(_) => return None,
+        }
+    }
+
+    Some(nodes)
+}
+
+/// Two values are the same if they are equal as strings or as numbers,
+/// so `"1000"` and `"1000.000000"` do not count as a change.
+fn same_value(a: &str, b: &str) -> bool {
+    if a == b {
+        return true;
+    }
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x == y,
+        _ => false,
+    }
+}
+
+/// Whether two XML documents hold the same content, ignoring formatting:
+/// whitespace between elements, `<a></a>` vs `<a/>`, attribute order,
+/// comments and numeric formatting. Unparsable documents are never equivalent.
+pub fn xml_equivalent(a: &str, b: &str) -> bool {
+    let (Some(left), Some(right)) = (nodes(a), nodes(b)) else {
+        return false;
+    };
+    if left.len() != right.len() {
+        return false;
+    }
+
+    left.iter().zip(right.iter()).all(|pair| match pair {
+        (Node::Start(n1, a1), Node::Start(n2, a2)) => {
+            n1 == n2
+                && a1.len() == a2.len()
+                && a1
+                    .iter()
+                    .zip(a2.iter())
+                    .all(|((k1, v1), (k2, v2))| k1 == k2 && same_value(v1, v2))
+        }
+        (Node::End(n1), Node::End(n2)) => n1 == n2,
+        (Node::Text(t1), Node::Text(t2)) => same_value(t1, t2),
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formatting_is_ignored() {
+        let a = r#"<?xml version="1.0"?>
+<root>
+    <item b="2" a="1.000000" />
+    <name>Farm</name>
+</root>"#;
+        let b = r#"<root><item a="1" b="2"></item><!-- note --><name>Farm</name></root>"#;
+        assert!(xml_equivalent(a, b));
+    }
+
+    #[test]
+    fn test_value_changes_detected() {
+        assert!(!xml_equivalent(r#"<a x="1"/>"#, r#"<a x="2"/>"#));
+        assert!(!xml_equivalent("<a>Farm</a>", "<a>Ferme</a>"));
+        assert!(!xml_equivalent("<a><b/></a>", "<a><b/><b/></a>"));
+        assert!(!xml_equivalent(r#"<a x="1"/>"#, r#"<a x="1" y="0"/>"#));
+    }
+
+    #[test]
+    fn test_escaping_is_ignored() {
+        assert!(xml_equivalent(
+            r#"<a name="Dupr&#233; &amp; Fils"/>"#,
+            r#"<a name="Dupré &amp; Fils"/>"#
+        ));
+    }
+}
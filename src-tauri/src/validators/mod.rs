@@ -1,2 +1,3 @@
 pub mod path;
 pub mod savegame;
+pub mod version;
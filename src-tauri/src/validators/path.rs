@@ -1,6 +1,9 @@
 use std::path::{Path, PathBuf};
 
 use crate::error::AppError;
+use crate::models::common::LocalizedMessage;
+use crate::models::settings::GamePathCheck;
+use crate::parsers::files::{savegame_file, CAREER_SAVEGAME};
 
 /// Validates that a savegame path is safe to operate on.
 ///
@@ -20,7 +23,7 @@ pub fn validate_savegame_path(path: &str) -> Result<PathBuf, AppError> {
     }
 
     // Must contain careerSavegame.xml to be a valid savegame
-    if !path_buf.join("careerSavegame.xml").exists() && path_buf.exists() {
+    if !savegame_file(&path_buf, CAREER_SAVEGAME).exists() && path_buf.exists() {
         return Err(AppError::IoError {
             message: "Invalid path: not a valid savegame directory".to_string(),
         });
@@ -65,6 +68,70 @@ pub fn validate_game_path(path: &str) -> Result<PathBuf, AppError> {
     Ok(path_buf)
 }
 
+/// Folders every FS25 installation has, relative to the game path.
+pub const GAME_PATH_MARKERS: &[&str] = &["data/vehicles", "data/placeables", "data/maps"];
+
+/// Steam app id of Farming Simulator 25.
+const STEAM_APP_ID: &str = "2300320";
+
+/// Check a user-supplied game installation path in detail: unlike
+/// `validate_game_path`, every problem is reported instead of the first one.
+pub fn check_game_path(path: &str) -> GamePathCheck {
+    let path_buf = PathBuf::from(path);
+    let mut check = GamePathCheck {
+        valid: false,
+        version: None,
+        missing_markers: Vec::new(),
+        problems: Vec::new(),
+    };
+
+    if has_path_traversal(&path_buf) {
+        check
+            .problems
+            .push(LocalizedMessage::new("errors.gamePath.traversal"));
+        return check;
+    }
+    if !path_buf.is_dir() {
+        check
+            .problems
+            .push(LocalizedMessage::new("errors.gamePath.notFound").with_param("path", path));
+        return check;
+    }
+
+    for marker in GAME_PATH_MARKERS {
+        let marker_path = marker
+            .split('/')
+            .fold(path_buf.clone(), |p, part| p.join(part));
+        if !marker_path.is_dir() {
+            check.missing_markers.push(marker.to_string());
+            check.problems.push(
+                LocalizedMessage::new("errors.gamePath.missingMarker").with_param("marker", marker),
+            );
+        }
+    }
+
+    check.valid = check.missing_markers.is_empty();
+    check.version = steam_build_id(&path_buf);
+    check
+}
+
+/// Read the build id from the Steam app manifest, two levels above a
+/// `steamapps/common/<game>` install.
+fn steam_build_id(game_path: &Path) -> Option<String> {
+    let manifest = game_path
+        .parent()?
+        .parent()?
+        .join(format!("appmanifest_{}.acf", STEAM_APP_ID));
+    let content = std::fs::read_to_string(manifest).ok()?;
+    content.lines().find_map(|line| {
+        let mut parts = line.split('"').filter(|p| !p.trim().is_empty());
+        match (parts.next(), parts.next()) {
+            (Some("buildid"), Some(id)) => Some(id.to_string()),
+            _ => None,
+        }
+    })
+}
+
 /// Checks if a path contains `..` components.
 fn has_path_traversal(path: &Path) -> bool {
     path.components().any(|c| matches!(c, std::path::Component::ParentDir))
@@ -96,4 +163,40 @@ mod tests {
         let result = validate_savegames_base_path(&tmp.display().to_string());
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_check_game_path_reports_missing_markers() {
+        let base = std::env::temp_dir().join("fs25_test_check_game_path");
+        let _ = std::fs::remove_dir_all(&base);
+        let game = base.join("common").join("Farming Simulator 25");
+        std::fs::create_dir_all(game.join("data").join("vehicles")).unwrap();
+
+        let check = check_game_path(&game.to_string_lossy());
+        assert!(!check.valid);
+        assert_eq!(check.missing_markers, vec!["data/placeables", "data/maps"]);
+        assert_eq!(check.problems.len(), 2);
+        assert_eq!(check.problems[0].code, "errors.gamePath.missingMarker");
+        assert_eq!(check.version, None);
+
+        std::fs::create_dir_all(game.join("data").join("placeables")).unwrap();
+        std::fs::create_dir_all(game.join("data").join("maps")).unwrap();
+        std::fs::write(
+            base.join("appmanifest_2300320.acf"),
+            "\"AppState\"\n{\n\t\"appid\"\t\t\"2300320\"\n\t\"buildid\"\t\t\"16231234\"\n}\n",
+        )
+        .unwrap();
+        let check = check_game_path(&game.to_string_lossy());
+        assert!(check.valid);
+        assert!(check.problems.is_empty());
+        assert_eq!(check.version.as_deref(), Some("16231234"));
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_check_game_path_not_found() {
+        let check = check_game_path("/nonexistent/fs25");
+        assert!(!check.valid);
+        assert_eq!(check.problems[0].code, "errors.gamePath.notFound");
+    }
 }
@@ -1,19 +1,139 @@
-use crate::models::common::LocalizedMessage;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::AppError;
+use crate::models::common::{LocalizedMessage, Position};
+use crate::models::field::{is_known_fruit_type, Field, BASE_FRUIT_TYPES, BASE_SPRAY_TYPES};
+use crate::models::validation::{ValidatorInfo, ValidatorReport, ValidatorSeverity};
 use crate::models::SavegameData;
+use crate::parsers::files::{ECONOMY, FIELDS, PLACEABLES};
+use crate::services::production::ProductionRecipes;
+
+/// A cross-file consistency check, appending its findings to the warning list.
+pub type SavegameCheckFn = fn(&SavegameData, &mut Vec<LocalizedMessage>);
+
+/// A registered savegame check, identified by a stable id the frontend localizes
+/// as `validators.<id>`.
+pub struct SavegameCheck {
+    pub id: &'static str,
+    pub severity: ValidatorSeverity,
+    pub run: SavegameCheckFn,
+}
+
+impl SavegameCheck {
+    pub fn info(&self) -> ValidatorInfo {
+        ValidatorInfo {
+            id: self.id.to_string(),
+            severity: self.severity,
+        }
+    }
+}
+
+/// Every check run by `validate_savegame`, in execution order.
+/// Register new checks here; the dispatcher does not need to change.
+pub const SAVEGAME_CHECKS: &[SavegameCheck] = &[
+    SavegameCheck {
+        id: "moneyConsistency",
+        severity: ValidatorSeverity::Warning,
+        run: validate_money_consistency,
+    },
+    SavegameCheck {
+        id: "vehicleFarms",
+        severity: ValidatorSeverity::Warning,
+        run: validate_vehicle_farms,
+    },
+    SavegameCheck {
+        id: "attachmentReferences",
+        severity: ValidatorSeverity::Warning,
+        run: validate_attachment_references,
+    },
+    SavegameCheck {
+        id: "fieldFarmlandLinks",
+        severity: ValidatorSeverity::Info,
+        run: validate_field_farmland_links,
+    },
+    SavegameCheck {
+        id: "fieldFruitTypes",
+        severity: ValidatorSeverity::Warning,
+        run: validate_field_fruit_types,
+    },
+    SavegameCheck {
+        id: "fieldGrowthStates",
+        severity: ValidatorSeverity::Warning,
+        run: validate_field_growth_states,
+    },
+    SavegameCheck {
+        id: "productionFillTypes",
+        severity: ValidatorSeverity::Warning,
+        run: validate_production_fill_types,
+    },
+    SavegameCheck {
+        id: "fillTypeTypos",
+        severity: ValidatorSeverity::Warning,
+        run: validate_fill_type_typos,
+    },
+    SavegameCheck {
+        id: "positionsInBounds",
+        severity: ValidatorSeverity::Warning,
+        run: validate_positions_in_bounds,
+    },
+    SavegameCheck {
+        id: "xmlDeclarations",
+        severity: ValidatorSeverity::Warning,
+        run: validate_xml_declarations,
+    },
+    SavegameCheck {
+        id: "staleGreatDemands",
+        severity: ValidatorSeverity::Info,
+        run: validate_stale_great_demands,
+    },
+];
+
+/// Largest plausible absolute coordinate, in meters. Generous enough for
+/// 16x maps; anything beyond it is most likely a fall-through or a corrupt transform.
+pub const DEFAULT_MAP_BOUND: f64 = 10_000.0;
 
 /// Validates cross-file consistency in a loaded savegame.
 /// Returns a list of localized warning messages for any inconsistencies found.
 pub fn validate_savegame(data: &SavegameData) -> Vec<LocalizedMessage> {
     let mut warnings = Vec::new();
 
-    validate_money_consistency(data, &mut warnings);
-    validate_vehicle_farms(data, &mut warnings);
-    validate_attachment_references(data, &mut warnings);
-    validate_field_farmland_links(data, &mut warnings);
+    for check in SAVEGAME_CHECKS {
+        (check.run)(data, &mut warnings);
+    }
 
     warnings
 }
 
+/// List every registered check.
+pub fn list_checks() -> Vec<ValidatorInfo> {
+    SAVEGAME_CHECKS.iter().map(|c| c.info()).collect()
+}
+
+/// Run the checks matching `ids`, in registry order, returning one report per check.
+/// Fails on an unknown id so typos are not silently ignored.
+pub fn run_checks(data: &SavegameData, ids: &[String]) -> Result<Vec<ValidatorReport>, AppError> {
+    if let Some(unknown) = ids
+        .iter()
+        .find(|id| !SAVEGAME_CHECKS.iter().any(|c| c.id == id.as_str()))
+    {
+        return Err(AppError::Generic(format!("Unknown validator: {}", unknown)));
+    }
+
+    Ok(SAVEGAME_CHECKS
+        .iter()
+        .filter(|c| ids.iter().any(|id| id == c.id))
+        .map(|c| {
+            let mut diagnostics = Vec::new();
+            (c.run)(data, &mut diagnostics);
+            ValidatorReport {
+                id: c.id.to_string(),
+                severity: c.severity,
+                diagnostics,
+            }
+        })
+        .collect())
+}
+
 /// Check that career money matches farm 1 money.
 fn validate_money_consistency(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
     if let Some(farm) = data.farms.iter().find(|f| f.farm_id == 1) {
@@ -61,9 +181,38 @@ fn validate_attachment_references(data: &SavegameData, warnings: &mut Vec<Locali
 
 /// Check that each field has a matching farmland entry.
 fn validate_field_farmland_links(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    push_fields_without_farmland(data, &HashSet::new(), warnings);
+}
+
+/// Same check as `fieldFarmlandLinks`, also accepting the farmlands defined by the
+/// map data: the save does not always enumerate every farmland of the map.
+/// With `map_farmland_ids` unavailable, only farmland.xml is consulted and an
+/// informational note says so.
+pub fn validate_field_farmland_links_with_map(
+    data: &SavegameData,
+    map_farmland_ids: Option<&HashSet<u32>>,
+    warnings: &mut Vec<LocalizedMessage>,
+) {
+    match map_farmland_ids {
+        Some(map_ids) => push_fields_without_farmland(data, map_ids, warnings),
+        None => {
+            warnings.push(
+                LocalizedMessage::new("errors.validation.mapFarmlandsUnavailable")
+                    .with_param("mapId", &data.career.map_id),
+            );
+            push_fields_without_farmland(data, &HashSet::new(), warnings);
+        }
+    }
+}
+
+fn push_fields_without_farmland(
+    data: &SavegameData,
+    map_farmland_ids: &HashSet<u32>,
+    warnings: &mut Vec<LocalizedMessage>,
+) {
     let farmland_ids: Vec<u32> = data.farmlands.iter().map(|fl| fl.id).collect();
     for field in &data.fields {
-        if !farmland_ids.contains(&field.id) {
+        if !farmland_ids.contains(&field.id) && !map_farmland_ids.contains(&field.id) {
             warnings.push(
                 LocalizedMessage::new("errors.validation.fieldNoFarmland")
                     .with_param("fieldId", field.id),
@@ -72,12 +221,283 @@ fn validate_field_farmland_links(data: &SavegameData, warnings: &mut Vec<Localiz
     }
 }
 
+/// Check that each field's current and planned crop is known, e.g. not left
+/// over from a removed mod or a bad edit. Empty values are ignored.
+fn validate_field_fruit_types(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    for field in &data.fields {
+        for fruit in [&field.fruit_type, &field.planned_fruit] {
+            if !fruit.is_empty() && !is_known_fruit_type(fruit, data.economy.as_ref()) {
+                warnings.push(
+                    LocalizedMessage::new("errors.validation.fieldUnknownFruit")
+                        .with_param("fieldId", field.id)
+                        .with_param("fruit", fruit),
+                );
+            }
+        }
+    }
+}
+
+/// Ground types (see `GROUND_TYPES`) of a field ready to harvest.
+const HARVEST_READY_GROUND_TYPES: &[&str] = &["HARVEST_READY", "HARVEST_READY_OTHER"];
+
+/// Ground types of a field that has been sown or planted.
+const SOWN_GROUND_TYPES: &[&str] = &["SOWN", "DIRECT_SOWN", "PLANTED", "RIDGE_SOWN"];
+
+/// Ground types of worked soil, on which nothing grows until it is sown.
+const BARE_GROUND_TYPES: &[&str] = &["CULTIVATED", "SEEDBED", "PLOWED", "ROLLED_SEEDBED"];
+
+/// Whether the crop, growth state and ground type of `field` contradict each
+/// other: a harvest-ready or sown field without a growing crop, or worked soil
+/// with one. `UNKNOWN` and `FALLOW` stand for no crop.
+pub fn has_incoherent_growth_state(field: &Field) -> bool {
+    let ground = field.ground_type.to_uppercase();
+    let fruit = field.fruit_type.to_uppercase();
+    let growing = !matches!(fruit.as_str(), "" | "UNKNOWN" | "FALLOW") && field.growth_state > 0;
+
+    if HARVEST_READY_GROUND_TYPES.contains(&ground.as_str())
+        || SOWN_GROUND_TYPES.contains(&ground.as_str())
+    {
+        !growing
+    } else if BARE_GROUND_TYPES.contains(&ground.as_str()) {
+        field.growth_state > 0
+    } else {
+        false
+    }
+}
+
+/// Check that no field is stuck in a growth state its ground type cannot show,
+/// e.g. harvest-ready ground at growth state 0. `repair_field_states` fixes them.
+fn validate_field_growth_states(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    for field in &data.fields {
+        if !has_incoherent_growth_state(field) {
+            continue;
+        }
+        warnings.push(
+            LocalizedMessage::new("errors.validation.fieldIncoherentGrowth")
+                .with_param("fieldId", field.id)
+                .with_param("groundType", &field.ground_type)
+                .with_param("fruit", &field.fruit_type)
+                .with_param("growthState", field.growth_state),
+        );
+    }
+}
+
+/// Without game data only storages left with no fill type are caught; see
+/// `validate_production_fill_types_with_recipes`.
+fn validate_production_fill_types(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    validate_production_fill_types_with_recipes(data, &HashMap::new(), warnings);
+}
+
+/// Same check as `productionFillTypes`, also comparing each input and output
+/// storage with the recipes of the placeable definition, keyed by placeable
+/// filename. Placeables without recipes (mods, game path unavailable) only get
+/// the basic check.
+pub fn validate_production_fill_types_with_recipes(
+    data: &SavegameData,
+    recipes: &HashMap<String, ProductionRecipes>,
+    warnings: &mut Vec<LocalizedMessage>,
+) {
+    for placeable in &data.placeables {
+        let recipe = recipes.get(&placeable.filename);
+        let storages = placeable
+            .production_inputs
+            .iter()
+            .map(|stock| (stock, recipe.map(|r| &r.inputs)))
+            .chain(
+                placeable
+                    .production_outputs
+                    .iter()
+                    .map(|stock| (stock, recipe.map(|r| &r.outputs))),
+            );
+        for (stock, accepted) in storages {
+            let fill_type = stock.fill_type.to_uppercase();
+            let rejected = fill_type.is_empty()
+                || fill_type == "UNKNOWN"
+                || accepted.is_some_and(|accepted| !accepted.contains(&fill_type));
+            if rejected {
+                warnings.push(
+                    LocalizedMessage::new("errors.validation.productionFillTypeNotAccepted")
+                        .with_param("name", &placeable.display_name)
+                        .with_param("index", placeable.index)
+                        .with_param("fillType", &stock.fill_type),
+                );
+            }
+        }
+    }
+}
+
+/// Check the fill types named by fields (crops and spray types), production
+/// storages and great demands against the base game types and the ones priced in
+/// economy.xml. A value missing from this set but close to one of its entries is
+/// reported with the entry as a suggestion; values unlike any known type are left
+/// alone, as they usually come from mods.
+fn validate_fill_type_typos(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    let mut known: HashSet<String> = BASE_FRUIT_TYPES
+        .iter()
+        .chain(BASE_SPRAY_TYPES)
+        .map(|t| t.to_string())
+        .collect();
+    if let Some(economy) = &data.economy {
+        known.extend(
+            economy
+                .fill_types
+                .iter()
+                .map(|ft| ft.fill_type.to_uppercase()),
+        );
+    }
+
+    let fields = data
+        .fields
+        .iter()
+        .flat_map(|f| [&f.fruit_type, &f.planned_fruit, &f.spray_type].map(|v| (FIELDS, v)));
+    let productions = data.placeables.iter().flat_map(|p| {
+        p.production_inputs
+            .iter()
+            .chain(&p.production_outputs)
+            .map(|stock| (PLACEABLES, &stock.fill_type))
+    });
+    let demands = data
+        .economy
+        .iter()
+        .flat_map(|e| &e.great_demands)
+        .map(|d| (ECONOMY, &d.fill_type_name));
+
+    let mut reported = HashSet::new();
+    for (file, value) in fields.chain(productions).chain(demands) {
+        let upper = value.to_uppercase();
+        if upper.is_empty() || known.contains(&upper) || !reported.insert((file, upper.clone())) {
+            continue;
+        }
+        if let Some(suggestion) = closest_fill_type(&upper, &known) {
+            warnings.push(
+                LocalizedMessage::new("errors.validation.fillTypeTypo")
+                    .with_param("file", file)
+                    .with_param("value", value)
+                    .with_param("suggestion", suggestion),
+            );
+        }
+    }
+}
+
+/// The entry of `known` nearest to `value`, if it is close enough to be a typo:
+/// one edit for names of up to 6 letters (`OAT` and `OIL` are both real), two beyond.
+fn closest_fill_type<'a>(value: &str, known: &'a HashSet<String>) -> Option<&'a str> {
+    let max_distance = if value.len() > 6 { 2 } else { 1 };
+    known
+        .iter()
+        .map(|k| (edit_distance(value, k), k))
+        .filter(|(d, _)| *d <= max_distance)
+        .min()
+        .map(|(_, k)| k.as_str())
+}
+
+/// Levenshtein distance between two strings, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Check that vehicles and placeables are within `DEFAULT_MAP_BOUND`.
+fn validate_positions_in_bounds(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    validate_positions_within(data, DEFAULT_MAP_BOUND, warnings);
+}
+
+/// Flag vehicles and placeables with a coordinate beyond `bound` in absolute value.
+/// Entities without a position are skipped.
+pub fn validate_positions_within(
+    data: &SavegameData,
+    bound: f64,
+    warnings: &mut Vec<LocalizedMessage>,
+) {
+    let out_of_bounds = |pos: &Position| {
+        [pos.x, pos.y, pos.z]
+            .iter()
+            .any(|c| !c.is_finite() || c.abs() > bound)
+    };
+    let with_coordinates = |msg: LocalizedMessage, pos: &Position| {
+        msg.with_param("x", format!("{:.1}", pos.x))
+            .with_param("y", format!("{:.1}", pos.y))
+            .with_param("z", format!("{:.1}", pos.z))
+    };
+
+    for vehicle in &data.vehicles {
+        if let Some(ref pos) = vehicle.position {
+            if out_of_bounds(pos) {
+                warnings.push(with_coordinates(
+                    LocalizedMessage::new("errors.validation.vehicleOutOfBounds")
+                        .with_param("name", &vehicle.display_name)
+                        .with_param("id", &vehicle.unique_id),
+                    pos,
+                ));
+            }
+        }
+    }
+
+    for placeable in &data.placeables {
+        if let Some(ref pos) = placeable.position {
+            if out_of_bounds(pos) {
+                warnings.push(with_coordinates(
+                    LocalizedMessage::new("errors.validation.placeableOutOfBounds")
+                        .with_param("name", &placeable.display_name)
+                        .with_param("index", placeable.index),
+                    pos,
+                ));
+            }
+        }
+    }
+}
+
+/// Report the savegame files the loader found without a `<?xml ...?>`
+/// declaration; the game refuses to load a file without one.
+fn validate_xml_declarations(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    for file in &data.files_without_declaration {
+        warnings.push(
+            LocalizedMessage::new("errors.validation.missingXmlDeclaration")
+                .with_param("file", file.clone()),
+        );
+    }
+}
+
+/// Flag great demands that ended before the current day of environment.xml: the
+/// game never runs them again, so they can be deleted or restarted. Needs both
+/// economy.xml and environment.xml; empty demand slots are skipped.
+fn validate_stale_great_demands(data: &SavegameData, warnings: &mut Vec<LocalizedMessage>) {
+    let (Some(economy), Some(environment)) = (&data.economy, &data.environment) else {
+        return;
+    };
+
+    for demand in &economy.great_demands {
+        if demand.unique_id.is_empty() || demand.end_day() >= environment.current_day {
+            continue;
+        }
+        warnings.push(
+            LocalizedMessage::new("errors.validation.staleGreatDemand")
+                .with_param("id", &demand.unique_id)
+                .with_param("fillType", &demand.fill_type_name)
+                .with_param("endDay", demand.end_day())
+                .with_param("currentDay", environment.current_day),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::career::CareerSavegame;
+    use crate::models::career::{CareerSavegame, EconomicMultipliers};
+    use crate::models::economy::{Economy, FillTypePrice, GreatDemand};
+    use crate::models::environment::Environment;
     use crate::models::farm::{Farm, FarmStatistics};
     use crate::models::field::{Farmland, Field};
+    use crate::models::placeable::{Placeable, ProductionStock};
     use crate::models::vehicle::{AttachedImplement, Vehicle, PropertyState};
 
     fn make_savegame_data() -> SavegameData {
@@ -88,6 +508,8 @@ mod tests {
                 creation_date: "".to_string(),
                 map_id: "".to_string(),
                 map_title: "".to_string(),
+                map_display: "".to_string(),
+                is_mod_map: false,
                 save_date: "".to_string(),
                 economic_difficulty: "normal".to_string(),
                 money: 100000.0,
@@ -101,12 +523,15 @@ mod tests {
                 snow_enabled: false,
                 fuel_usage: 1,
                 traffic_enabled: true,
+                guided_tour_completed: None,
+                economic_multipliers: EconomicMultipliers::for_difficulty("normal"),
             },
             farms: vec![Farm {
                 farm_id: 1,
                 name: "Farm 1".to_string(),
                 color: 1,
                 loan: 0.0,
+                loan_interest_rate: None,
                 money: 100000.0,
                 players: vec![],
                 statistics: FarmStatistics::default(),
@@ -133,6 +558,7 @@ mod tests {
                         attached_vehicle_unique_id: "2".to_string(),
                         move_down: true,
                     }],
+                    worker_task: None,
                 },
                 Vehicle {
                     unique_id: "2".to_string(),
@@ -150,6 +576,7 @@ mod tests {
                     configurations: vec![],
                     fill_units: vec![],
                     attached_implements: vec![],
+                    worker_task: None,
                 },
             ],
             sales: vec![],
@@ -171,12 +598,36 @@ mod tests {
                 ground_type: "".to_string(),
             }],
             farmlands: vec![Farmland { id: 1, farm_id: 1 }],
-            placeables: vec![],
+            placeables: vec![Placeable {
+                index: 0,
+                filename: "data/placeables/production/flourMill/flourMill.xml".to_string(),
+                display_name: "Flour Mill".to_string(),
+                farm_id: 1,
+                price: 150000.0,
+                age: 0.0,
+                position: None,
+                is_pre_placed: false,
+                is_under_construction: false,
+                construction_steps: vec![],
+                production_inputs: vec![ProductionStock {
+                    fill_type: "WHEAT".to_string(),
+                    amount: 2500.0,
+                    capacity: 10000.0,
+                }],
+                production_outputs: vec![ProductionStock {
+                    fill_type: "FLOUR".to_string(),
+                    amount: 1200.0,
+                    capacity: 5000.0,
+                }],
+            }],
             missions: vec![],
             collectibles: vec![],
+            trees: vec![],
+            tree_counts: vec![],
             contract_settings: None,
             environment: None,
             economy: None,
+            files_without_declaration: vec![],
             warnings: vec![],
         }
     }
@@ -212,6 +663,115 @@ mod tests {
         assert!(warnings.iter().any(|w| w.code == "errors.validation.attachmentNotFound" && w.params.get("attachmentId").map(|v| v.as_str()) == Some("999")));
     }
 
+    fn great_demand(unique_id: &str, start_day: u32, duration: u32) -> GreatDemand {
+        GreatDemand {
+            index: 0,
+            unique_id: unique_id.to_string(),
+            fill_type_name: "WHEAT".to_string(),
+            demand_multiplier: 1.5,
+            demand_start_day: start_day,
+            demand_start_hour: 12,
+            demand_duration: duration,
+            is_running: false,
+            is_valid: true,
+        }
+    }
+
+    fn environment_on_day(current_day: u32) -> Environment {
+        Environment {
+            day_time: 0.0,
+            current_day,
+            current_monotonic_day: current_day,
+            days_per_period: 1,
+            weather_forecast: vec![],
+            snow_height: 0.0,
+            ground_wetness: 0.0,
+            weather_history: None,
+            time_since_last_rain: None,
+        }
+    }
+
+    #[test]
+    fn test_all_registered_checks_run() {
+        let mut data = make_savegame_data();
+        data.career.money = 200000.0;
+        data.vehicles[0].farm_id = 99;
+        data.vehicles[0].attached_implements[0].attached_vehicle_unique_id = "999".to_string();
+        data.farmlands.clear();
+        data.fields[0].planned_fruit = "NOPE".to_string();
+        data.placeables[0].production_inputs[0].fill_type = "UNKNOWN".to_string();
+        data.fields[0].spray_type = "FERTILISER".to_string();
+        data.fields[0].ground_type = "PLOWED".to_string();
+        data.vehicles[1].position = Some(Position { x: 0.0, y: -50000.0, z: 0.0 });
+        data.economy = Some(Economy {
+            great_demands: vec![great_demand("station01", 2, 24)],
+            fill_types: vec![],
+        });
+        data.environment = Some(environment_on_day(10));
+        data.files_without_declaration = vec!["farms.xml".to_string()];
+
+        let mut expected = 0;
+        for check in SAVEGAME_CHECKS {
+            let mut check_warnings = Vec::new();
+            (check.run)(&data, &mut check_warnings);
+            assert!(!check_warnings.is_empty(), "Check '{}' reported nothing", check.id);
+            expected += check_warnings.len();
+        }
+
+        let warnings = validate_savegame(&data);
+        assert_eq!(warnings.len(), expected);
+    }
+
+    #[test]
+    fn test_registered_check_ids_unique() {
+        let mut ids: Vec<String> = list_checks().into_iter().map(|c| c.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), SAVEGAME_CHECKS.len());
+    }
+
+    #[test]
+    fn test_registered_checks_localized() {
+        let locales = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../src/locales");
+        for locale in ["en.json", "fr.json"] {
+            let content = std::fs::read_to_string(locales.join(locale)).unwrap();
+            let json: serde_json::Value = serde_json::from_str(&content).unwrap();
+            for check in SAVEGAME_CHECKS {
+                assert!(
+                    json["validators"][check.id].is_string(),
+                    "{} lacks validators.{}",
+                    locale,
+                    check.id
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_checks_subset_grouped() {
+        let mut data = make_savegame_data();
+        data.career.money = 200000.0;
+        data.farmlands.clear();
+
+        let ids = vec!["fieldFarmlandLinks".to_string(), "vehicleFarms".to_string()];
+        let reports = run_checks(&data, &ids).unwrap();
+        assert_eq!(reports.len(), 2);
+        // Registry order, not request order
+        assert_eq!(reports[0].id, "vehicleFarms");
+        assert!(reports[0].diagnostics.is_empty());
+        assert_eq!(reports[1].id, "fieldFarmlandLinks");
+        assert_eq!(reports[1].diagnostics.len(), 1);
+        // moneyConsistency was not selected
+        assert!(reports.iter().all(|r| r.diagnostics.iter().all(|d| d.code != "errors.validation.moneyInconsistency")));
+    }
+
+    #[test]
+    fn test_run_checks_unknown_id() {
+        let data = make_savegame_data();
+        let result = run_checks(&data, &["nope".to_string()]);
+        assert!(matches!(result, Err(AppError::Generic(_))));
+    }
+
     #[test]
     fn test_field_without_farmland_warning() {
         let mut data = make_savegame_data();
@@ -219,4 +779,220 @@ mod tests {
         let warnings = validate_savegame(&data);
         assert!(warnings.iter().any(|w| w.code == "errors.validation.fieldNoFarmland" && w.params.get("fieldId").map(|v| v.as_str()) == Some("1")));
     }
+
+    #[test]
+    fn test_field_farmland_links_with_map() {
+        let mut data = make_savegame_data();
+        data.farmlands.clear();
+
+        let mut warnings = Vec::new();
+        validate_field_farmland_links_with_map(&data, Some(&HashSet::from([1])), &mut warnings);
+        assert!(warnings.is_empty());
+
+        validate_field_farmland_links_with_map(&data, None, &mut warnings);
+        assert_eq!(warnings[0].code, "errors.validation.mapFarmlandsUnavailable");
+        assert!(warnings.iter().any(|w| w.code == "errors.validation.fieldNoFarmland"));
+    }
+
+    #[test]
+    fn test_field_unknown_fruit_warning() {
+        let mut data = make_savegame_data();
+        data.fields[0].fruit_type = "BOGUS".to_string();
+        data.fields[0].planned_fruit = "RYE".to_string();
+
+        let mut warnings = Vec::new();
+        validate_field_fruit_types(&data, &mut warnings);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].code, "errors.validation.fieldUnknownFruit");
+        assert_eq!(warnings[0].params.get("fruit").map(|v| v.as_str()), Some("BOGUS"));
+
+        // A crop priced by the economy is provided by a mod
+        data.economy = Some(Economy {
+            great_demands: vec![],
+            fill_types: vec![FillTypePrice {
+                fill_type: "RYE".to_string(),
+                total_amount: None,
+                price_history: vec![],
+            }],
+        });
+        let mut warnings = Vec::new();
+        validate_field_fruit_types(&data, &mut warnings);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].params.get("fieldId").map(|v| v.as_str()), Some("1"));
+    }
+
+    #[test]
+    fn test_field_incoherent_growth_state() {
+        let mut data = make_savegame_data();
+        let mut warnings = Vec::new();
+        validate_field_growth_states(&data, &mut warnings);
+        assert!(warnings.is_empty());
+
+        // Harvest-ready ground with nothing grown
+        data.fields[0].ground_type = "HARVEST_READY".to_string();
+        data.fields[0].growth_state = 0;
+        validate_field_growth_states(&data, &mut warnings);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "errors.validation.fieldIncoherentGrowth");
+        assert_eq!(warnings[0].params.get("growthState").map(|v| v.as_str()), Some("0"));
+
+        let field = |ground: &str, fruit: &str, growth_state: u8| Field {
+            ground_type: ground.to_string(),
+            fruit_type: fruit.to_string(),
+            growth_state,
+            ..data.fields[0].clone()
+        };
+        let incoherent = |ground, fruit, growth_state| {
+            has_incoherent_growth_state(&field(ground, fruit, growth_state))
+        };
+        assert!(incoherent("SOWN", "UNKNOWN", 0));
+        assert!(incoherent("PLOWED", "WHEAT", 4));
+        assert!(!incoherent("PLANTED", "CANOLA", 6));
+        assert!(!incoherent("CULTIVATED", "UNKNOWN", 0));
+        assert!(!incoherent("STUBBLE_TILLAGE", "WHEAT", 10));
+    }
+
+    #[test]
+    fn test_production_fill_types_with_recipes() {
+        let mut data = make_savegame_data();
+        let mut warnings = Vec::new();
+        validate_production_fill_types(&data, &mut warnings);
+        assert!(warnings.is_empty());
+
+        data.placeables[0].production_inputs[0].fill_type = "GRAPE".to_string();
+        // Without the recipe, any named fill type passes
+        validate_production_fill_types(&data, &mut warnings);
+        assert!(warnings.is_empty());
+
+        let recipes = HashMap::from([(
+            data.placeables[0].filename.clone(),
+            ProductionRecipes {
+                inputs: HashSet::from(["WHEAT".to_string(), "BARLEY".to_string()]),
+                outputs: HashSet::from(["FLOUR".to_string()]),
+            },
+        )]);
+        validate_production_fill_types_with_recipes(&data, &recipes, &mut warnings);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "errors.validation.productionFillTypeNotAccepted");
+        assert_eq!(warnings[0].params.get("fillType").map(|v| v.as_str()), Some("GRAPE"));
+    }
+
+    #[test]
+    fn test_fill_type_typos() {
+        let mut data = make_savegame_data();
+        data.fields[0].fruit_type = "WHEET".to_string();
+        data.fields[0].spray_type = "NONE".to_string();
+        data.fields.push(Field {
+            id: 2,
+            fruit_type: "wheet".to_string(),
+            planned_fruit: "MYMODCROP".to_string(),
+            ..data.fields[0].clone()
+        });
+        data.placeables[0].production_outputs[0].fill_type = "FLOUER".to_string();
+        data.economy = Some(Economy {
+            great_demands: vec![],
+            fill_types: vec![FillTypePrice {
+                fill_type: "FLOUR".to_string(),
+                total_amount: None,
+                price_history: vec![],
+            }],
+        });
+
+        let mut warnings = Vec::new();
+        validate_fill_type_typos(&data, &mut warnings);
+        let found: Vec<(&str, &str, &str)> = warnings
+            .iter()
+            .map(|w| {
+                (
+                    w.params["file"].as_str(),
+                    w.params["value"].as_str(),
+                    w.params["suggestion"].as_str(),
+                )
+            })
+            .collect();
+        // Reported once per file, whatever the case; the unrelated mod crop is not
+        assert_eq!(
+            found,
+            vec![
+                ("fields.xml", "WHEET", "WHEAT"),
+                ("placeables.xml", "FLOUER", "FLOUR"),
+            ]
+        );
+        assert!(warnings.iter().all(|w| w.code == "errors.validation.fillTypeTypo"));
+    }
+
+    #[test]
+    fn test_stale_great_demands() {
+        let mut data = make_savegame_data();
+        data.economy = Some(Economy {
+            great_demands: vec![
+                // Ends on day 5, before the current day
+                great_demand("station01", 4, 18),
+                // Ends on the current day
+                great_demand("station02", 9, 24),
+                great_demand("station03", 12, 24),
+                great_demand("", 0, 0),
+            ],
+            fill_types: vec![],
+        });
+
+        let mut warnings = Vec::new();
+        validate_stale_great_demands(&data, &mut warnings);
+        assert!(warnings.is_empty(), "environment.xml is needed");
+
+        data.environment = Some(environment_on_day(10));
+        validate_stale_great_demands(&data, &mut warnings);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "errors.validation.staleGreatDemand");
+        assert_eq!(warnings[0].params["id"], "station01");
+        assert_eq!(warnings[0].params["endDay"], "5");
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("WHEAT", "WHEAT"), 0);
+        assert_eq!(edit_distance("WHEET", "WHEAT"), 1);
+        assert_eq!(edit_distance("OAT", "OIL"), 2);
+        assert_eq!(edit_distance("", "PEA"), 3);
+    }
+
+    #[test]
+    fn test_vehicle_out_of_bounds_warning() {
+        let mut data = make_savegame_data();
+        data.vehicles[0].position = Some(Position { x: 120.0, y: 80.0, z: -350.0 });
+        data.vehicles[1].position = Some(Position { x: 25000.0, y: 80.0, z: 10.0 });
+        let warnings = validate_savegame(&data);
+        let out: Vec<_> = warnings
+            .iter()
+            .filter(|w| w.code == "errors.validation.vehicleOutOfBounds")
+            .collect();
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].params.get("id").map(|v| v.as_str()), Some("2"));
+        assert_eq!(out[0].params.get("x").map(|v| v.as_str()), Some("25000.0"));
+    }
+
+    #[test]
+    fn test_positions_custom_bound() {
+        let mut data = make_savegame_data();
+        data.vehicles[0].position = Some(Position { x: 1500.0, y: 80.0, z: 0.0 });
+        let mut warnings = Vec::new();
+        validate_positions_within(&data, DEFAULT_MAP_BOUND, &mut warnings);
+        assert!(warnings.is_empty());
+        validate_positions_within(&data, 1024.0, &mut warnings);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_missing_xml_declaration_warning() {
+        let mut data = make_savegame_data();
+        let mut warnings = Vec::new();
+        validate_xml_declarations(&data, &mut warnings);
+        assert!(warnings.is_empty());
+
+        data.files_without_declaration = vec!["vehicles.xml".to_string()];
+        validate_xml_declarations(&data, &mut warnings);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, "errors.validation.missingXmlDeclaration");
+        assert_eq!(warnings[0].params.get("file").map(|v| v.as_str()), Some("vehicles.xml"));
+    }
 }
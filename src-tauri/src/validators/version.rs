@@ -0,0 +1,206 @@
+use std::path::Path;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+use crate::error::AppError;
+use crate::parsers::common::{attr_str, read_xml_file};
+use crate::parsers::files::{savegame_file, CAREER_SAVEGAME, VEHICLES};
+
+/// Base game maps that only exist in Farming Simulator 22.
+const FS22_MAP_IDS: &[&str] = &["MapFR", "MapAlpine"];
+
+/// Mod name prefixes of older game versions, in lookup order.
+const LEGACY_MOD_PREFIXES: &[(&str, &str)] = &[("FS22_", "FS22"), ("FS19_", "FS19")];
+
+/// Sniff the savegame for structural signs of an older game version: an FS22-only
+/// base map, or vehicles identified by a numeric `id` instead of a `uniqueId`.
+/// Returns the detected game, or `None` for an FS25 save.
+pub fn detect_legacy_version(path: &Path) -> Result<Option<String>, AppError> {
+    let names = read_career_names(path)?;
+    if FS22_MAP_IDS.contains(&names.map_id.as_str()) || has_legacy_vehicle_ids(path) {
+        return Ok(Some("FS22".to_string()));
+    }
+    Ok(None)
+}
+
+/// The game an older-version name prefix points to, when the map id or every
+/// mod of the save has one. Converted maps and many FS25 mods keep their FS22
+/// names, so this only warrants a warning, never a refusal.
+pub fn legacy_name_hint(path: &Path) -> Result<Option<String>, AppError> {
+    let names = read_career_names(path)?;
+    if let Some(game) = legacy_game_of(&names.map_id) {
+        return Ok(Some(game.to_string()));
+    }
+    let mod_games: Vec<Option<&str>> = names.mods.iter().map(|m| legacy_game_of(m)).collect();
+    if let Some(Some(game)) = mod_games.first() {
+        if mod_games.iter().all(|g| g.is_some()) {
+            return Ok(Some(game.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+struct CareerNames {
+    map_id: String,
+    mods: Vec<String>,
+}
+
+fn read_career_names(path: &Path) -> Result<CareerNames, AppError> {
+    let content = read_xml_file(&savegame_file(path, CAREER_SAVEGAME))?;
+    let mut reader = Reader::from_str(&content);
+    let mut in_map_id = false;
+    let mut names = CareerNames {
+        map_id: String::new(),
+        mods: Vec::new(),
+    };
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"mapId" => in_map_id = true,
+            Ok(Event::Text(ref t)) if in_map_id => {
+                names.map_id = t.unescape().unwrap_or_default().trim().to_string();
+                in_map_id = false;
+            }
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) if e.name().as_ref() == b"mod" => {
+                names.mods.push(attr_str(e, "modName"));
+            }
+            Ok(Event::Eof) => break,
+            Err(_) => break,
+            _ => {}
+        }
+    }
+
+    Ok(names)
+}
+
+/// Refuse a savegame of another game version, before any parse or write
+/// produces garbage from it.
+pub fn ensure_supported_version(path: &Path) -> Result<(), AppError> {
+    match detect_legacy_version(path)? {
+        Some(detected) => Err(AppError::UnsupportedSaveVersion {
+            path: path.display().to_string(),
+            detected,
+        }),
+        None => Ok(()),
+    }
+}
+
+fn legacy_game_of(name: &str) -> Option<&'static str> {
+    LEGACY_MOD_PREFIXES
+        .iter()
+        .find(|(prefix, _)| name.starts_with(prefix))
+        .map(|(_, game)| *game)
+}
+
+/// Whether the first vehicle of vehicles.xml uses the old numeric `id` attribute.
+fn has_legacy_vehicle_ids(path: &Path) -> bool {
+    let Ok(content) = read_xml_file(&savegame_file(path, VEHICLES)) else {
+        return false;
+    };
+    let mut reader = Reader::from_str(&content);
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e))
+                if e.name().as_ref() == b"vehicle" =>
+            {
+                return attr_str(e, "uniqueId").is_empty() && !attr_str(e, "id").is_empty();
+            }
+            Ok(Event::Eof) | Err(_) => return false,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture_path(name: &str) -> std::path::PathBuf {
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join(name)
+    }
+
+    #[test]
+    fn test_detect_fs25_saves() {
+        assert_eq!(
+            detect_legacy_version(&fixture_path("savegame_complete")).unwrap(),
+            None
+        );
+        assert_eq!(
+            detect_legacy_version(&fixture_path("savegame_modded")).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_refuse_fs22_save() {
+        let path = fixture_path("savegame_fs22");
+        assert_eq!(
+            detect_legacy_version(&path).unwrap().as_deref(),
+            Some("FS22")
+        );
+        assert!(matches!(
+            ensure_supported_version(&path),
+            Err(AppError::UnsupportedSaveVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_fs22_names_only_warn() {
+        // A converted FS22 mod map with FS22-named mods, but FS25 vehicles
+        let dir = std::env::temp_dir().join("fs25_test_vv_fs22_names");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let career = std::fs::read_to_string(
+            fixture_path("savegame_complete").join("careerSavegame.xml"),
+        )
+        .unwrap()
+        .replace(
+            "</settings>",
+            r#"</settings>
+  <mod modName="FS22_Converted" title="Converted" version="1.0" required="true"/>"#,
+        );
+        std::fs::write(dir.join("careerSavegame.xml"), career).unwrap();
+        std::fs::copy(
+            fixture_path("savegame_complete").join("vehicles.xml"),
+            dir.join("vehicles.xml"),
+        )
+        .unwrap();
+
+        assert_eq!(detect_legacy_version(&dir).unwrap(), None);
+        assert!(ensure_supported_version(&dir).is_ok());
+        assert_eq!(legacy_name_hint(&dir).unwrap().as_deref(), Some("FS22"));
+        assert_eq!(
+            legacy_name_hint(&fixture_path("savegame_complete")).unwrap(),
+            None
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_detect_legacy_vehicle_ids() {
+        let dir = std::env::temp_dir().join("fs25_test_vv_vehicle_ids");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(
+            fixture_path("savegame_complete").join("careerSavegame.xml"),
+            dir.join("careerSavegame.xml"),
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("vehicles.xml"),
+            r#"<vehicles><vehicle id="1" filename="data/vehicles/fendt/fendt942Vario/fendt942Vario.xml" /></vehicles>"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            detect_legacy_version(&dir).unwrap().as_deref(),
+            Some("FS22")
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,19 +1,20 @@
 use std::path::Path;
 
-use quick_xml::events::{BytesStart, BytesText, Event};
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, CAREER_SAVEGAME};
+use crate::writers::common::{atomic_write, patch_attributes, write_event, xml_error};
 
 /// Modifies the money in careerSavegame.xml.
 /// Supports both formats:
 ///   - Self-closing: `<statistics money="..." playTime="..." />`
 ///   - Child elements: `<statistics><money>...</money><playTime>...</playTime></statistics>`
 pub fn write_career_money(path: &Path, money: f64) -> Result<(), AppError> {
-    let xml_path = path.join("careerSavegame.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, CAREER_SAVEGAME);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -21,11 +22,6 @@ pub fn write_career_money(path: &Path, money: f64) -> Result<(), AppError> {
     let mut in_statistics = false;
     let mut in_money_tag = false;
 
-    let write_err = |e: std::io::Error| AppError::XmlParseError {
-        file: xml_path.display().to_string(),
-        message: e.to_string(),
-    };
-
     loop {
         match reader.read_event() {
             Ok(Event::Start(ref e)) => {
@@ -33,32 +29,25 @@ pub fn write_career_money(path: &Path, money: f64) -> Result<(), AppError> {
                 if tag == "statistics" {
                     in_statistics = true;
                     // Pass through with attributes (may have attrs in some formats)
-                    let mut elem = BytesStart::new("statistics");
-                    for attr in e.attributes().flatten() {
-                        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-                        if key == "money" {
-                            elem.push_attribute(("money", format!("{:.6}", money).as_str()));
-                        } else {
-                            elem.push_attribute((key.as_str(), String::from_utf8_lossy(&attr.value).as_ref()));
-                        }
-                    }
-                    writer.write_event(Event::Start(elem)).map_err(write_err)?;
+                    let elem = patch_attributes(e, |key| match key {
+                        "money" => Some(format!("{:.6}", money)),
+                        _ => None,
+                    });
+                    write_event(&mut writer, &xml_path, Event::Start(elem))?;
                 } else if in_statistics && tag == "money" {
                     in_money_tag = true;
-                    writer.write_event(Event::Start(e.clone())).map_err(write_err)?;
+                    write_event(&mut writer, &xml_path, Event::Start(e.clone()))?;
                 } else {
-                    writer.write_event(Event::Start(e.clone())).map_err(write_err)?;
+                    write_event(&mut writer, &xml_path, Event::Start(e.clone()))?;
                 }
             }
             Ok(Event::Text(ref e)) => {
                 if in_money_tag {
                     // Replace the money text content
                     let money_str = format!("{}", money as i64);
-                    writer
-                        .write_event(Event::Text(BytesText::new(&money_str)))
-                        .map_err(write_err)?;
+                    write_event(&mut writer, &xml_path, Event::Text(BytesText::new(&money_str)))?;
                 } else {
-                    writer.write_event(Event::Text(e.clone())).map_err(write_err)?;
+                    write_event(&mut writer, &xml_path, Event::Text(e.clone()))?;
                 }
             }
             Ok(Event::End(ref e)) => {
@@ -68,43 +57,159 @@ pub fn write_career_money(path: &Path, money: f64) -> Result<(), AppError> {
                 } else if tag == "statistics" {
                     in_statistics = false;
                 }
-                writer.write_event(Event::End(e.clone())).map_err(write_err)?;
+                write_event(&mut writer, &xml_path, Event::End(e.clone()))?;
             }
             Ok(Event::Empty(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if tag == "statistics" {
                     // Self-closing format: <statistics money="..." />
-                    let mut elem = BytesStart::new("statistics");
-                    for attr in e.attributes().flatten() {
-                        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-                        if key == "money" {
-                            elem.push_attribute(("money", format!("{:.6}", money).as_str()));
-                        } else {
-                            elem.push_attribute((key.as_str(), String::from_utf8_lossy(&attr.value).as_ref()));
-                        }
-                    }
-                    writer.write_event(Event::Empty(elem)).map_err(write_err)?;
+                    let elem = patch_attributes(e, |key| match key {
+                        "money" => Some(format!("{:.6}", money)),
+                        _ => None,
+                    });
+                    write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                 } else {
-                    writer.write_event(Event::Empty(e.clone())).map_err(write_err)?;
+                    write_event(&mut writer, &xml_path, Event::Empty(e.clone()))?;
                 }
             }
             Ok(Event::Eof) => break,
             Ok(event) => {
-                writer.write_event(event.into_owned()).map_err(write_err)?;
+                write_event(&mut writer, &xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
+        }
+    }
+
+    let output = writer.into_inner();
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
+
+    Ok(())
+}
+
+/// Replaces the `<savegameName>` shown in the game's load menu.
+pub fn write_career_name(path: &Path, name: &str) -> Result<(), AppError> {
+    write_settings_text(path, "savegameName", name)
+}
+
+/// Replaces the `<economicDifficulty>` code (1 easy, 2 normal, 3 hard).
+pub fn write_economic_difficulty(path: &Path, code: u8) -> Result<(), AppError> {
+    write_settings_text(path, "economicDifficulty", &code.to_string())
+}
+
+/// Marks the guided tour completed (`true`) so the game stops prompting for it,
+/// or resets it (`false`) so it starts again. A save without a `<guidedTour>`
+/// section gets one appended to `<careerSavegame>`.
+pub fn write_guided_tour(path: &Path, completed: bool) -> Result<(), AppError> {
+    let xml_path = savegame_file(path, CAREER_SAVEGAME);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+
+    let value = completed.to_string();
+    let mut found = false;
+    let mark = |e: &BytesStart| {
+        let mut elem = patch_attributes(e, |key| match key {
+            "completed" => Some(value.clone()),
+            _ => None,
+        });
+        if e.try_get_attribute("completed").ok().flatten().is_none() {
+            elem.push_attribute(("completed", value.as_str()));
+        }
+        elem
+    };
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"guidedTour" => {
+                found = true;
+                write_event(&mut writer, &xml_path, Event::Start(mark(e)))?;
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"guidedTour" => {
+                found = true;
+                write_event(&mut writer, &xml_path, Event::Empty(mark(e)))?;
+            }
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"careerSavegame" && !found => {
+                let mut elem = BytesStart::new("guidedTour");
+                elem.push_attribute(("completed", value.as_str()));
+                write_event(&mut writer, &xml_path, Event::Text(BytesText::new("  ")))?;
+                write_event(&mut writer, &xml_path, Event::Empty(elem))?;
+                write_event(&mut writer, &xml_path, Event::Text(BytesText::new("\n")))?;
+                write_event(&mut writer, &xml_path, Event::End(e.clone()))?;
+            }
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
+        }
+    }
+
+    let output = writer.into_inner();
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
+
+    Ok(())
+}
+
+/// Replaces the text of a `<settings>` child in careerSavegame.xml.
+/// The new value is escaped; every other byte of the file is passed through as-is.
+fn write_settings_text(path: &Path, tag_name: &str, value: &str) -> Result<(), AppError> {
+    let xml_path = savegame_file(path, CAREER_SAVEGAME);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+
+    let mut in_settings = false;
+    let mut in_target = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if tag == "settings" {
+                    in_settings = true;
+                } else if in_settings && tag == tag_name {
+                    in_target = true;
+                    write_event(&mut writer, &xml_path, Event::Start(e.clone()))?;
+                    write_event(&mut writer, &xml_path, Event::Text(BytesText::new(value)))?;
+                    continue;
+                }
+                write_event(&mut writer, &xml_path, Event::Start(e.clone()))?;
+            }
+            Ok(Event::Empty(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if in_settings && tag == tag_name {
+                    // Expand <savegameName/> so it can hold a value
+                    write_event(&mut writer, &xml_path, Event::Start(BytesStart::new(tag.as_str())))?;
+                    write_event(&mut writer, &xml_path, Event::Text(BytesText::new(value)))?;
+                    write_event(&mut writer, &xml_path, Event::End(BytesEnd::new(tag.as_str())))?;
+                } else {
+                    write_event(&mut writer, &xml_path, Event::Empty(e.clone()))?;
+                }
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
+            Ok(Event::Text(_)) | Ok(Event::CData(_)) if in_target => {
+                // Old value, already replaced
+            }
+            Ok(Event::End(ref e)) => {
+                let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
+                if in_target && tag == tag_name {
+                    in_target = false;
+                } else if tag == "settings" {
+                    in_settings = false;
+                }
+                write_event(&mut writer, &xml_path, Event::End(e.clone()))?;
+            }
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
             }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
     Ok(())
 }
@@ -154,4 +259,170 @@ mod tests {
         assert_eq!(after.growth_mode, before.growth_mode);
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    #[test]
+    fn test_write_career_money_preserves_escaped_name_bytes() {
+        let save = setup_fixture("escaped_name");
+        let xml_path = save.join("careerSavegame.xml");
+        let original = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replace(
+                "<savegameName>Test Complete</savegameName>",
+                "<savegameName>Ferme de l'Étang &amp; Fils &lt;2&gt;</savegameName>",
+            );
+        std::fs::write(&xml_path, &original).unwrap();
+
+        write_career_money(&save, 42.0).unwrap();
+        let after = std::fs::read_to_string(&xml_path).unwrap();
+
+        // Only the statistics line changes; every other line is byte-identical
+        let changed: Vec<(&str, &str)> = original
+            .lines()
+            .zip(after.lines())
+            .filter(|(a, b)| a != b)
+            .collect();
+        assert_eq!(changed.len(), 1);
+        assert!(changed[0].1.contains("<statistics"));
+        assert_eq!(original.lines().count(), after.lines().count());
+
+        let career = parse_career(&save).unwrap();
+        assert_eq!(career.savegame_name, "Ferme de l'Étang & Fils <2>");
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_career_money_keeps_attribute_escaping() {
+        let save = setup_fixture("escaped_attr");
+        let xml_path = save.join("careerSavegame.xml");
+        let original = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replace("<statistics money=", "<statistics note=\"A &amp; B\" money=");
+        std::fs::write(&xml_path, &original).unwrap();
+
+        write_career_money(&save, 42.0).unwrap();
+        let after = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(after.contains(r#"note="A &amp; B""#));
+        assert!(!after.contains("&amp;amp;"));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_career_name_special_characters() {
+        let save = setup_fixture("rename");
+        let before = parse_career(&save).unwrap();
+        write_career_name(&save, "Ferme de l'Étang & Fils <2>").unwrap();
+
+        let after = parse_career(&save).unwrap();
+        assert_eq!(after.savegame_name, "Ferme de l'Étang & Fils <2>");
+        assert_eq!(after.map_title, before.map_title);
+        assert!((after.money - before.money).abs() < 0.01);
+
+        let content = std::fs::read_to_string(save.join("careerSavegame.xml")).unwrap();
+        assert!(content.contains("&amp; Fils &lt;2&gt;</savegameName>"));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_career_name_empty_element() {
+        let save = setup_fixture("rename_empty");
+        let xml_path = save.join("careerSavegame.xml");
+        let original = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replace("<savegameName>Test Complete</savegameName>", "<savegameName/>");
+        std::fs::write(&xml_path, &original).unwrap();
+
+        write_career_name(&save, "Nouvelle partie").unwrap();
+        let after = parse_career(&save).unwrap();
+        assert_eq!(after.savegame_name, "Nouvelle partie");
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_career_name_preserves_latin1() {
+        let save = setup_fixture("rename_latin1");
+        let xml_path = save.join("careerSavegame.xml");
+        let original = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replacen("encoding=\"utf-8\"", "encoding=\"ISO-8859-1\"", 1);
+        std::fs::write(&xml_path, &original).unwrap();
+
+        write_career_name(&save, "Ferme Dupré").unwrap();
+
+        let bytes = std::fs::read(&xml_path).unwrap();
+        // Still Latin-1: 'é' is the single byte 0xE9, not UTF-8
+        assert!(bytes.windows(6).any(|w| w == b"Dupr\xE9<"));
+        assert!(std::str::from_utf8(&bytes).is_err());
+        assert_eq!(parse_career(&save).unwrap().savegame_name, "Ferme Dupré");
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_career_name_keeps_declared_utf8() {
+        // Invalid UTF-8 bytes do not make a file declared UTF-8 a Latin-1 one
+        let save = setup_fixture("rename_stray_byte");
+        let xml_path = save.join("careerSavegame.xml");
+        let original = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(original.contains("encoding=\"utf-8\""));
+        let (before, after) = original.split_once("Riverbend Springs").unwrap();
+        let mut bytes = before.as_bytes().to_vec();
+        bytes.extend_from_slice(b"Riverbend \xC9tang");
+        bytes.extend_from_slice(after.as_bytes());
+        std::fs::write(&xml_path, bytes).unwrap();
+
+        write_career_name(&save, "Ferme Dupré – 2").unwrap();
+
+        let content = String::from_utf8(std::fs::read(&xml_path).unwrap()).unwrap();
+        assert!(content.contains("encoding=\"utf-8\""));
+        assert!(content.contains("<savegameName>Ferme Dupré – 2</savegameName>"));
+        let career = parse_career(&save).unwrap();
+        assert_eq!(career.map_title, "Riverbend Étang");
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_economic_difficulty_roundtrip() {
+        let save = setup_fixture("difficulty");
+        write_economic_difficulty(&save, 1).unwrap();
+        assert_eq!(parse_career(&save).unwrap().economic_difficulty, "EASY");
+        write_economic_difficulty(&save, 3).unwrap();
+        let career = parse_career(&save).unwrap();
+        assert_eq!(career.economic_difficulty, "HARD");
+        assert_eq!(career.savegame_name, "Test Complete");
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_guided_tour_adds_missing_section() {
+        let save = setup_fixture("guided_tour_missing");
+        assert_eq!(parse_career(&save).unwrap().guided_tour_completed, None);
+
+        write_guided_tour(&save, true).unwrap();
+        assert_eq!(parse_career(&save).unwrap().guided_tour_completed, Some(true));
+        let content = std::fs::read_to_string(save.join("careerSavegame.xml")).unwrap();
+        assert!(content.contains("  <guidedTour completed=\"true\"/>\n</careerSavegame>"));
+
+        write_guided_tour(&save, false).unwrap();
+        assert_eq!(parse_career(&save).unwrap().guided_tour_completed, Some(false));
+        let content = std::fs::read_to_string(save.join("careerSavegame.xml")).unwrap();
+        assert_eq!(content.matches("<guidedTour").count(), 1);
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_guided_tour_keeps_other_attributes() {
+        let save = setup_fixture("guided_tour_existing");
+        let xml_path = save.join("careerSavegame.xml");
+        let content = std::fs::read_to_string(&xml_path).unwrap().replace(
+            "</careerSavegame>",
+            "  <guidedTour completed=\"false\" step=\"4\"></guidedTour>\n</careerSavegame>",
+        );
+        std::fs::write(&xml_path, content).unwrap();
+        assert_eq!(parse_career(&save).unwrap().guided_tour_completed, Some(false));
+
+        write_guided_tour(&save, true).unwrap();
+        let content = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(content.contains("<guidedTour completed=\"true\" step=\"4\"></guidedTour>"));
+        assert_eq!(parse_career(&save).unwrap().guided_tour_completed, Some(true));
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::Path;
 
 use quick_xml::events::{BytesStart, Event};
@@ -5,20 +6,24 @@ use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
 use crate::models::changes::CollectibleChange;
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, COLLECTIBLES};
+use crate::writers::common::{atomic_write, raw_attr_u32, patch_attributes, write_event, xml_error};
 
+/// Applies collectible changes to collectibles.xml, matching collectibles by index.
+/// Returns the indexes that matched a collectible.
 pub fn write_collectible_changes(
     path: &Path,
     changes: &[CollectibleChange],
-) -> Result<(), AppError> {
-    let xml_path = path.join("collectibles.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+) -> Result<HashSet<u32>, AppError> {
+    let xml_path = savegame_file(path, COLLECTIBLES);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let change_map: std::collections::HashMap<u32, &CollectibleChange> = changes
         .iter()
         .map(|c| (c.index, c))
         .collect();
+    let mut matched: HashSet<u32> = HashSet::new();
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -28,8 +33,9 @@ pub fn write_collectible_changes(
             Ok(Event::Empty(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if tag == "collectible" {
-                    let index = attr_u32(e, "index");
+                    let index = raw_attr_u32(e, "index");
                     if let Some(change) = change_map.get(&index) {
+                        matched.insert(index);
                         let elem = patch_collectible(e, change);
                         write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                     } else {
@@ -43,62 +49,21 @@ pub fn write_collectible_changes(
             Ok(event) => {
                 write_event(&mut writer, &xml_path, event.into_owned())?;
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
-
-    Ok(())
-}
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
-fn attr_str(e: &BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
-
-fn attr_u32(e: &BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
+    Ok(matched)
 }
 
 fn patch_collectible(e: &BytesStart, change: &CollectibleChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("collectible");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "isCollected" => {
-                elem.push_attribute(("isCollected", if change.collected { "true" } else { "false" }));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
-}
-
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer.write_event(event).map_err(|e| AppError::XmlParseError {
-        file: xml_path.display().to_string(),
-        message: e.to_string(),
+    patch_attributes(e, |key| match key {
+        "isCollected" => Some(change.collected.to_string()),
+        "isMarked" => change.marked.map(|marked| marked.to_string()),
+        _ => None,
     })
 }
 
@@ -135,6 +100,7 @@ mod tests {
         let changes = vec![CollectibleChange {
             index: 3,
             collected: true,
+            marked: None,
         }];
         write_collectible_changes(&save, &changes).unwrap();
         let after = parse_collectibles(&save).unwrap();
@@ -150,8 +116,8 @@ mod tests {
 
         // Toggle a few
         let changes = vec![
-            CollectibleChange { index: 0, collected: false },  // was true
-            CollectibleChange { index: 3, collected: true },   // was false
+            CollectibleChange { index: 0, collected: false, marked: None },  // was true
+            CollectibleChange { index: 3, collected: true, marked: None },   // was false
         ];
         write_collectible_changes(&save, &changes).unwrap();
         let after = parse_collectibles(&save).unwrap();
@@ -167,4 +133,26 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    #[test]
+    fn test_write_collectible_marker() {
+        let save = setup_fixture("marker");
+        let before = parse_collectibles(&save).unwrap();
+        assert_eq!(before.iter().find(|c| c.index == 5).unwrap().marked, Some(false));
+        // Collectibles without the attribute have no marker state
+        assert_eq!(before.iter().find(|c| c.index == 3).unwrap().marked, None);
+
+        let changes = vec![
+            CollectibleChange { index: 5, collected: false, marked: Some(true) },
+            CollectibleChange { index: 3, collected: false, marked: Some(true) },
+        ];
+        write_collectible_changes(&save, &changes).unwrap();
+        let after = parse_collectibles(&save).unwrap();
+        let c5 = after.iter().find(|c| c.index == 5).unwrap();
+        assert_eq!(c5.marked, Some(true));
+        assert!(!c5.collected);
+        assert_eq!(after.iter().find(|c| c.index == 3).unwrap().marked, None);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
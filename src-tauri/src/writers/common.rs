@@ -0,0 +1,239 @@
+use std::fmt::Display;
+use std::path::Path;
+use std::time::Duration;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Writer;
+
+use crate::error::AppError;
+
+/// Number of rename attempts before giving up.
+const RENAME_ATTEMPTS: u32 = 5;
+
+/// Delay before the first retry, doubled after each failed attempt (10, 20, 40, 80 ms).
+const RENAME_INITIAL_DELAY_MS: u64 = 10;
+
+/// Run `op` until it succeeds or `attempts` tries have failed, sleeping between tries
+/// with an exponential backoff. Returns the last error.
+fn retry_with_backoff<F>(attempts: u32, mut op: F) -> std::io::Result<()>
+where
+    F: FnMut() -> std::io::Result<()>,
+{
+    let mut delay = Duration::from_millis(RENAME_INITIAL_DELAY_MS);
+    let mut attempt = 1;
+    loop {
+        match op() {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(_) => {
+                std::thread::sleep(delay);
+                delay *= 2;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Write `contents` to `xml_path` atomically: write a `.xml.tmp` sibling, then rename it
+/// over the original. The rename is retried because on Windows it can fail for a moment
+/// while an antivirus or the indexer holds the file open.
+pub fn atomic_write(xml_path: &Path, contents: &[u8]) -> Result<(), AppError> {
+    let tmp_path = xml_path.with_extension("xml.tmp");
+    std::fs::write(&tmp_path, contents)?;
+
+    if let Err(e) = retry_with_backoff(RENAME_ATTEMPTS, || std::fs::rename(&tmp_path, xml_path)) {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(AppError::IoError {
+            message: format!(
+                "{}: {} (after {} attempts)",
+                xml_path.display(),
+                e,
+                RENAME_ATTEMPTS
+            ),
+        });
+    }
+
+    Ok(())
+}
+
+/// Map a quick-xml read or write error on `xml_path` to an `AppError`.
+pub fn xml_error(xml_path: &Path, e: impl Display) -> AppError {
+    AppError::XmlParseError {
+        file: xml_path.display().to_string(),
+        message: e.to_string(),
+    }
+}
+
+/// Write one event, mapping a failure to an `AppError` naming `xml_path`.
+pub fn write_event(
+    writer: &mut Writer<Vec<u8>>,
+    xml_path: &Path,
+    event: Event<'_>,
+) -> Result<(), AppError> {
+    writer.write_event(event).map_err(|e| xml_error(xml_path, e))
+}
+
+/// Value of an attribute as written, entities left escaped, or an empty string
+/// when absent. Unlike `parsers::common::attr_str`, it compares and copies back
+/// byte for byte.
+pub fn raw_attr_str(e: &BytesStart, key: &str) -> String {
+    e.attributes()
+        .flatten()
+        .find(|a| a.key.as_ref() == key.as_bytes())
+        .map(|a| String::from_utf8_lossy(&a.value).to_string())
+        .unwrap_or_default()
+}
+
+/// `raw_attr_str` parsed as an integer, 0 when absent or not a plain integer.
+pub fn raw_attr_u32(e: &BytesStart, key: &str) -> u32 {
+    raw_attr_str(e, key).parse().unwrap_or(0)
+}
+
+/// Rebuild `e` with the attributes for which `replace` returns a new value rewritten.
+/// Every other attribute is copied byte for byte, escaping included, and attribute
+/// order is kept. Attributes missing from `e` are never added.
+pub fn patch_attributes<F>(e: &BytesStart, replace: F) -> BytesStart<'static>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let name = String::from_utf8_lossy(e.name().as_ref()).to_string();
+    let mut elem = BytesStart::new(name);
+    for attr in e.attributes().flatten() {
+        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
+        match replace(&key) {
+            Some(value) => elem.push_attribute((key.as_str(), value.as_str())),
+            None => elem.push_attribute(attr),
+        }
+    }
+    elem
+}
+
+/// Indentation added per nesting level in `content`: the leading whitespace of its
+/// first indented element, or four spaces for a file without any.
+pub fn indent_unit(content: &str) -> String {
+    content
+        .lines()
+        .map(|line| {
+            let trimmed = line.trim_start_matches([' ', '\t']);
+            (&line[..line.len() - trimmed.len()], trimmed)
+        })
+        .find(|(indent, rest)| !indent.is_empty() && rest.starts_with('<'))
+        .map(|(indent, _)| indent.to_string())
+        .unwrap_or_else(|| "    ".to_string())
+}
+
+/// Line ending used by `content`, so inserted lines do not mix `\n` into a `\r\n` file.
+pub fn line_ending(content: &str) -> &'static str {
+    if content.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+/// Indentation of the next element, given the whitespace text preceding it: what
+/// follows its last line break. `None` when the text is not only whitespace.
+pub fn trailing_indent(text: &str) -> Option<String> {
+    if !text.chars().all(char::is_whitespace) {
+        return None;
+    }
+    let last_line = text.rsplit(['\n', '\r']).next().unwrap_or_default();
+    Some(last_line.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_indentation_detection() {
+        let tabs = "<?xml version=\"1.0\"?>\r\n<root>\r\n\t<a/>\r\n</root>";
+        assert_eq!(indent_unit(tabs), "\t");
+        assert_eq!(line_ending(tabs), "\r\n");
+        let spaces = "<root>\n  <a>\n    <b/>\n  </a>\n</root>";
+        assert_eq!(indent_unit(spaces), "  ");
+        assert_eq!(line_ending(spaces), "\n");
+        assert_eq!(indent_unit("<root/>"), "    ");
+
+        assert_eq!(trailing_indent("\n        ").as_deref(), Some("        "));
+        assert_eq!(trailing_indent("\r\n\t\t").as_deref(), Some("\t\t"));
+        assert_eq!(trailing_indent("text"), None);
+    }
+
+    #[test]
+    fn test_atomic_write_replaces_file() {
+        let dir = std::env::temp_dir().join("fs25_test_wc_atomic");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let xml_path = dir.join("farms.xml");
+        std::fs::write(&xml_path, "<farms/>").unwrap();
+
+        atomic_write(&xml_path, b"<farms><farm/></farms>").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&xml_path).unwrap(), "<farms><farm/></farms>");
+        assert!(!dir.join("farms.xml.tmp").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_retry_with_backoff_recovers() {
+        let mut calls = 0;
+        let result = retry_with_backoff(5, || {
+            calls += 1;
+            if calls < 3 {
+                Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied))
+            } else {
+                Ok(())
+            }
+        });
+        assert!(result.is_ok());
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_retry_with_backoff_is_bounded() {
+        let mut calls = 0;
+        let result = retry_with_backoff(3, || {
+            calls += 1;
+            Err(std::io::Error::from(std::io::ErrorKind::PermissionDenied))
+        });
+        assert!(result.is_err());
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_atomic_write_reports_failure_and_cleans_up() {
+        let dir = std::env::temp_dir().join("fs25_test_wc_atomic_fail");
+        let _ = std::fs::remove_dir_all(&dir);
+        // The target is a non-empty directory, so renaming a file over it always fails
+        let xml_path = dir.join("farms.xml");
+        std::fs::create_dir_all(xml_path.join("child")).unwrap();
+
+        let result = atomic_write(&xml_path, b"<farms/>");
+        assert!(matches!(result, Err(AppError::IoError { .. })));
+        assert!(!dir.join("farms.xml.tmp").exists());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_patch_attributes_keeps_others() {
+        let e = BytesStart::from_content(r#"item name="A &amp; B" price="10" age="3""#, 4);
+        let patched = patch_attributes(&e, |key| match key {
+            "price" => Some("20".to_string()),
+            "missing" => Some("1".to_string()),
+            _ => None,
+        });
+
+        assert_eq!(patched.name().as_ref(), b"item");
+        assert_eq!(raw_attr_str(&patched, "name"), "A &amp; B");
+        assert_eq!(raw_attr_str(&patched, "price"), "20");
+        assert_eq!(raw_attr_u32(&patched, "age"), 3);
+        assert_eq!(raw_attr_str(&patched, "missing"), "");
+        let keys: Vec<Vec<u8>> = patched
+            .attributes()
+            .flatten()
+            .map(|a| a.key.as_ref().to_vec())
+            .collect();
+        assert_eq!(keys, vec![b"name".to_vec(), b"price".to_vec(), b"age".to_vec()]);
+    }
+}
@@ -5,15 +5,16 @@ use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
 use crate::models::changes::ContractSettingsChange;
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, CONTRACTS};
+use crate::writers::common::{atomic_write, patch_attributes, write_event, xml_error};
 
 pub fn write_contract_settings(
     path: &Path,
     changes: &ContractSettingsChange,
 ) -> Result<(), AppError> {
-    let xml_path = path.join("r_contracts.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, CONTRACTS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -23,7 +24,7 @@ pub fn write_contract_settings(
             Ok(Event::Start(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if tag == "settings" || tag == "contractSettings" {
-                    let elem = patch_settings(e, &tag, changes);
+                    let elem = patch_settings(e, changes);
                     write_event(&mut writer, &xml_path, Event::Start(elem))?;
                 } else {
                     write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
@@ -32,7 +33,7 @@ pub fn write_contract_settings(
             Ok(Event::Empty(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if tag == "settings" || tag == "contractSettings" {
-                    let elem = patch_settings(e, &tag, changes);
+                    let elem = patch_settings(e, changes);
                     write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                 } else {
                     write_event(&mut writer, &xml_path, Event::Empty(e.clone().into_owned()))?;
@@ -42,56 +43,22 @@ pub fn write_contract_settings(
             Ok(event) => {
                 write_event(&mut writer, &xml_path, event.into_owned())?;
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
     Ok(())
 }
 
-fn patch_settings(e: &BytesStart, tag_name: &str, changes: &ContractSettingsChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new(tag_name.to_string());
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "leaseVehicle" if changes.lease_vehicle.is_some() => {
-                elem.push_attribute(("leaseVehicle", format!("{:.6}", changes.lease_vehicle.unwrap()).as_str()));
-            }
-            "missionPerFarm" if changes.mission_per_farm.is_some() => {
-                elem.push_attribute(("missionPerFarm", format!("{:.6}", changes.mission_per_farm.unwrap()).as_str()));
-            }
-            "allowClearAdd" if changes.allow_clear_add.is_some() => {
-                elem.push_attribute(("allowClearAdd", format!("{:.6}", changes.allow_clear_add.unwrap()).as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
-}
-
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer.write_event(event).map_err(|e| AppError::XmlParseError {
-        file: xml_path.display().to_string(),
-        message: e.to_string(),
+fn patch_settings(e: &BytesStart, changes: &ContractSettingsChange) -> BytesStart<'static> {
+    patch_attributes(e, |key| match key {
+        "leaseVehicle" => changes.lease_vehicle.map(|v| format!("{:.6}", v)),
+        "missionPerFarm" => changes.mission_per_farm.map(|v| format!("{:.6}", v)),
+        "allowClearAdd" => changes.allow_clear_add.map(|v| format!("{:.6}", v)),
+        _ => None,
     })
 }
 
@@ -6,21 +6,36 @@ use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
 use crate::models::changes::{EconomyChanges, GreatDemandAddition, GreatDemandChange};
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, ECONOMY};
+use crate::writers::common::{atomic_write, raw_attr_str, patch_attributes, write_event, xml_error};
 
 pub fn write_economy_changes(
     path: &Path,
     changes: &EconomyChanges,
 ) -> Result<(), AppError> {
-    let xml_path = path.join("economy.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
-
-    let change_map: HashMap<usize, &GreatDemandChange> = changes
-        .great_demand_changes
-        .as_ref()
-        .map(|v| v.iter().map(|c| (c.index, c)).collect())
-        .unwrap_or_default();
+    let xml_path = savegame_file(path, ECONOMY);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    // Changes carrying a uniqueId are matched by id, the others by slot index
+    let mut change_map: HashMap<usize, &GreatDemandChange> = HashMap::new();
+    let mut id_change_map: HashMap<&str, &GreatDemandChange> = HashMap::new();
+    for change in changes.great_demand_changes.iter().flatten() {
+        match change.unique_id.as_deref() {
+            Some(id) if !id.is_empty() => {
+                id_change_map.insert(id, change);
+            }
+            _ => {
+                change_map.insert(change.index, change);
+            }
+        }
+    }
+    let find_change = |e: &BytesStart, index: usize| {
+        id_change_map
+            .get(raw_attr_str(e, "uniqueId").as_str())
+            .or_else(|| change_map.get(&index))
+            .copied()
+    };
 
     let deletions: HashSet<usize> = changes
         .great_demand_deletions
@@ -39,6 +54,8 @@ pub fn write_economy_changes(
     let mut demand_index: usize = 0;
     let mut in_great_demands = false;
     let mut skip_until_end_great_demand = false;
+    // uniqueIds still listed, so additions already present are not appended again
+    let mut listed_ids: HashSet<String> = HashSet::new();
 
     loop {
         match reader.read_event() {
@@ -56,10 +73,12 @@ pub fn write_economy_changes(
                             skip_until_end_great_demand = true;
                             let empty = BytesStart::new("greatDemand");
                             write_event(&mut writer, &xml_path, Event::Empty(empty))?;
-                        } else if let Some(change) = change_map.get(&demand_index) {
+                        } else if let Some(change) = find_change(e, demand_index) {
+                            listed_ids.insert(raw_attr_str(e, "uniqueId"));
                             let elem = patch_great_demand(e, change);
                             write_event(&mut writer, &xml_path, Event::Start(elem))?;
                         } else {
+                            listed_ids.insert(raw_attr_str(e, "uniqueId"));
                             write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                         }
                         demand_index += 1;
@@ -78,11 +97,13 @@ pub fn write_economy_changes(
                         // Delete: replace with empty slot (no attributes)
                         let empty = BytesStart::new("greatDemand");
                         write_event(&mut writer, &xml_path, Event::Empty(empty))?;
-                    } else if let Some(change) = change_map.get(&demand_index) {
+                    } else if let Some(change) = find_change(e, demand_index) {
                         // Modifying an empty slot — should not normally happen
+                        listed_ids.insert(raw_attr_str(e, "uniqueId"));
                         let elem = patch_great_demand(e, change);
                         write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                     } else {
+                        listed_ids.insert(raw_attr_str(e, "uniqueId"));
                         write_event(&mut writer, &xml_path, Event::Empty(e.clone().into_owned()))?;
                     }
                     demand_index += 1;
@@ -98,6 +119,11 @@ pub fn write_economy_changes(
                 } else if tag == "greatDemands" {
                     // Before closing, append additions
                     for addition in additions {
+                        let is_listed = !addition.unique_id.is_empty()
+                            && !listed_ids.insert(addition.unique_id.clone());
+                        if is_listed {
+                            continue;
+                        }
                         let elem = create_great_demand(addition);
                         write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                     }
@@ -113,58 +139,27 @@ pub fn write_economy_changes(
                     write_event(&mut writer, &xml_path, event.into_owned())?;
                 }
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
     Ok(())
 }
 
 fn patch_great_demand(e: &BytesStart, change: &GreatDemandChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("greatDemand");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "fillTypeName" if change.fill_type_name.is_some() => {
-                elem.push_attribute(("fillTypeName", change.fill_type_name.as_ref().unwrap().as_str()));
-            }
-            "demandMultiplier" if change.demand_multiplier.is_some() => {
-                elem.push_attribute(("demandMultiplier", format!("{:.6}", change.demand_multiplier.unwrap()).as_str()));
-            }
-            "demandStartDay" if change.demand_start_day.is_some() => {
-                elem.push_attribute(("demandStartDay", change.demand_start_day.unwrap().to_string().as_str()));
-            }
-            "demandStartHour" if change.demand_start_hour.is_some() => {
-                elem.push_attribute(("demandStartHour", change.demand_start_hour.unwrap().to_string().as_str()));
-            }
-            "demandDuration" if change.demand_duration.is_some() => {
-                elem.push_attribute(("demandDuration", change.demand_duration.unwrap().to_string().as_str()));
-            }
-            "isRunning" if change.is_running.is_some() => {
-                elem.push_attribute(("isRunning", if change.is_running.unwrap() { "true" } else { "false" }));
-            }
-            "isValid" if change.is_valid.is_some() => {
-                elem.push_attribute(("isValid", if change.is_valid.unwrap() { "true" } else { "false" }));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+    patch_attributes(e, |key| match key {
+        "fillTypeName" => change.fill_type_name.clone(),
+        "demandMultiplier" => change.demand_multiplier.map(|v| format!("{:.6}", v)),
+        "demandStartDay" => change.demand_start_day.map(|v| v.to_string()),
+        "demandStartHour" => change.demand_start_hour.map(|v| v.to_string()),
+        "demandDuration" => change.demand_duration.map(|v| v.to_string()),
+        "isRunning" => change.is_running.map(|v| v.to_string()),
+        "isValid" => change.is_valid.map(|v| v.to_string()),
+        _ => None,
+    })
 }
 
 fn create_great_demand(addition: &GreatDemandAddition) -> BytesStart<'static> {
@@ -180,17 +175,6 @@ fn create_great_demand(addition: &GreatDemandAddition) -> BytesStart<'static> {
     elem
 }
 
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer.write_event(event).map_err(|e| AppError::XmlParseError {
-        file: xml_path.display().to_string(),
-        message: e.to_string(),
-    })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +208,7 @@ mod tests {
         let changes = EconomyChanges {
             great_demand_changes: Some(vec![GreatDemandChange {
                 index: 0,
+                unique_id: None,
                 fill_type_name: None,
                 demand_multiplier: Some(2.0),
                 demand_start_day: None,
@@ -278,6 +263,39 @@ mod tests {
         let _ = std::fs::remove_dir_all(&save);
     }
 
+    #[test]
+    fn test_write_economy_add_demand_twice_adds_once() {
+        let save = setup_fixture("add_demand_twice");
+        let before = parse_economy(&save).unwrap();
+
+        let changes = EconomyChanges {
+            great_demand_changes: None,
+            great_demand_additions: Some(vec![GreatDemandAddition {
+                unique_id: "sellingStationNew01".to_string(),
+                fill_type_name: "WHEAT".to_string(),
+                demand_multiplier: 1.25,
+                demand_start_day: 10,
+                demand_start_hour: 6,
+                demand_duration: 48,
+            }]),
+            great_demand_deletions: None,
+        };
+        write_economy_changes(&save, &changes).unwrap();
+        write_economy_changes(&save, &changes).unwrap();
+
+        let after = parse_economy(&save).unwrap();
+        assert_eq!(after.great_demands.len(), before.great_demands.len() + 1);
+
+        // An existing uniqueId is not re-added either
+        let existing = before.great_demands[0].unique_id.clone();
+        let mut again = changes.clone();
+        again.great_demand_additions.as_mut().unwrap()[0].unique_id = existing;
+        write_economy_changes(&save, &again).unwrap();
+        assert_eq!(parse_economy(&save).unwrap().great_demands.len(), after.great_demands.len());
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
     #[test]
     fn test_write_economy_delete_demand() {
         let save = setup_fixture("delete_demand");
@@ -312,6 +330,7 @@ mod tests {
         let changes = EconomyChanges {
             great_demand_changes: Some(vec![GreatDemandChange {
                 index: 2, // The FLOUR demand
+                unique_id: None,
                 fill_type_name: Some("BARLEY".to_string()),
                 demand_multiplier: Some(3.0),
                 demand_start_day: Some(100),
@@ -343,4 +362,45 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    #[test]
+    fn test_write_economy_modify_demand_by_unique_id() {
+        let save = setup_fixture("modify_demand_by_id");
+
+        // The FLOUR demand sits after an empty slot; a stale index must not matter
+        let changes = EconomyChanges {
+            great_demand_changes: Some(vec![GreatDemandChange {
+                index: 1,
+                unique_id: Some("sellingStationGrain02".to_string()),
+                fill_type_name: None,
+                demand_multiplier: Some(2.5),
+                demand_start_day: None,
+                demand_start_hour: None,
+                demand_duration: None,
+                is_running: None,
+                is_valid: None,
+            }]),
+            great_demand_additions: None,
+            great_demand_deletions: None,
+        };
+        write_economy_changes(&save, &changes).unwrap();
+
+        let after = parse_economy(&save).unwrap();
+        assert_eq!(after.great_demands.len(), 2);
+        let flour = after
+            .great_demands
+            .iter()
+            .find(|d| d.unique_id == "sellingStationGrain02")
+            .unwrap();
+        assert_eq!(flour.index, 2);
+        assert!((flour.demand_multiplier - 2.5).abs() < 0.001);
+        let seeds = after.great_demands.iter().find(|d| d.index == 0).unwrap();
+        assert!((seeds.demand_multiplier - 1.1).abs() < 0.001);
+
+        // The empty slot stays empty
+        let content = std::fs::read_to_string(save.join("economy.xml")).unwrap();
+        assert_eq!(content.matches("<greatDemand/>").count(), 2);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
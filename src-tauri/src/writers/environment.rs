@@ -6,19 +6,23 @@ use quick_xml::{Reader, Writer};
 use crate::error::AppError;
 use crate::models::changes::EnvironmentChanges;
 use crate::models::environment::WeatherEvent;
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, ENVIRONMENT};
+use crate::writers::common::{
+    atomic_write, indent_unit, line_ending, trailing_indent, write_event, xml_error,
+};
 
 /// Applies environment changes to environment.xml.
 ///
 /// Scalar fields (dayTime, currentDay, snow height, ground wetness) are patched in place.
-/// If `weather_forecast` is set, the entire `<forecast>` section is replaced.
+/// If `weather_forecast` is set, the entire `<forecast>` section is replaced, indented
+/// like the one it replaces.
 pub fn write_environment_changes(
     path: &Path,
     changes: &EnvironmentChanges,
 ) -> Result<(), AppError> {
-    let xml_path = path.join("environment.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let xml_path = savegame_file(path, ENVIRONMENT);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -27,30 +31,42 @@ pub fn write_environment_changes(
     let mut in_forecast = false;
     let mut skip_until_forecast_end = false;
     let mut forecast_written = false;
+    // Indentation of the element about to be read, and the one found for the forecast
+    let mut indent = String::new();
+    let mut layout = ForecastLayout {
+        instance_indent: None,
+        closing_indent: String::new(),
+        unit: indent_unit(&content),
+        newline: line_ending(&content),
+    };
 
     loop {
-        match reader.read_event() {
+        let event = reader.read_event();
+        if let Ok(Event::Text(ref text)) = event {
+            indent = trailing_indent(&String::from_utf8_lossy(text)).unwrap_or_default();
+        }
+        match event {
             Ok(Event::Start(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 match tag.as_str() {
                     "dayTime" => {
-                        write_ev(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
+                        write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                         // Read original text
                         let original_text = read_text_content(&mut reader);
                         if let Some(val) = changes.day_time {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Text(BytesText::new(&format!("{:.6}", val)).into_owned()),
                             )?;
                         } else {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Text(BytesText::new(&original_text).into_owned()),
                             )?;
                         }
-                        write_ev(
+                        write_event(
                             &mut writer,
                             &xml_path,
                             Event::End(BytesEnd::new("dayTime").into_owned()),
@@ -58,22 +74,22 @@ pub fn write_environment_changes(
                         continue;
                     }
                     "currentDay" => {
-                        write_ev(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
+                        write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                         let original_text = read_text_content(&mut reader);
                         if let Some(val) = changes.current_day {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Text(BytesText::new(&val.to_string()).into_owned()),
                             )?;
                         } else {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Text(BytesText::new(&original_text).into_owned()),
                             )?;
                         }
-                        write_ev(
+                        write_event(
                             &mut writer,
                             &xml_path,
                             Event::End(BytesEnd::new("currentDay").into_owned()),
@@ -82,17 +98,18 @@ pub fn write_environment_changes(
                     }
                     "weather" => {
                         in_weather = true;
-                        write_ev(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
+                        write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                         continue;
                     }
                     "forecast" if in_weather => {
                         in_forecast = true;
+                        layout.closing_indent = indent.clone();
                         if changes.weather_forecast.is_some() {
                             // Skip original forecast content, we'll write our own
                             skip_until_forecast_end = true;
                             continue;
                         } else {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Start(e.clone().into_owned()),
@@ -102,14 +119,20 @@ pub fn write_environment_changes(
                     }
                     _ => {
                         if skip_until_forecast_end {
+                            if tag == "instance" && layout.instance_indent.is_none() {
+                                layout.instance_indent = Some(indent.clone());
+                            }
                             continue;
                         }
-                        write_ev(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
+                        write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                     }
                 }
             }
             Ok(Event::Empty(ref e)) => {
                 if skip_until_forecast_end {
+                    if e.name().as_ref() == b"instance" && layout.instance_indent.is_none() {
+                        layout.instance_indent = Some(indent.clone());
+                    }
                     continue;
                 }
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
@@ -118,9 +141,9 @@ pub fn write_environment_changes(
                         if let Some(val) = changes.snow_height {
                             let mut elem = BytesStart::new("snow");
                             elem.push_attribute(("height", format!("{:.6}", val).as_str()));
-                            write_ev(&mut writer, &xml_path, Event::Empty(elem))?;
+                            write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                         } else {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Empty(e.clone().into_owned()),
@@ -131,9 +154,9 @@ pub fn write_environment_changes(
                         if let Some(val) = changes.ground_wetness {
                             let mut elem = BytesStart::new("ground");
                             elem.push_attribute(("wetness", format!("{:.6}", val).as_str()));
-                            write_ev(&mut writer, &xml_path, Event::Empty(elem))?;
+                            write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                         } else {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Empty(e.clone().into_owned()),
@@ -143,10 +166,11 @@ pub fn write_environment_changes(
                     "forecast" if in_weather => {
                         // Self-closing <forecast/> (empty forecast)
                         if let Some(ref events) = changes.weather_forecast {
-                            write_forecast_section(&mut writer, &xml_path, events)?;
+                            layout.closing_indent = indent.clone();
+                            write_forecast_section(&mut writer, &xml_path, events, &layout)?;
                             forecast_written = true;
                         } else {
-                            write_ev(
+                            write_event(
                                 &mut writer,
                                 &xml_path,
                                 Event::Empty(e.clone().into_owned()),
@@ -154,7 +178,7 @@ pub fn write_environment_changes(
                         }
                     }
                     _ => {
-                        write_ev(
+                        write_event(
                             &mut writer,
                             &xml_path,
                             Event::Empty(e.clone().into_owned()),
@@ -172,22 +196,27 @@ pub fn write_environment_changes(
                             // Write the replacement forecast
                             if let Some(ref events) = changes.weather_forecast {
                                 if !forecast_written {
-                                    write_forecast_section(&mut writer, &xml_path, events)?;
+                                    write_forecast_section(
+                                        &mut writer,
+                                        &xml_path,
+                                        events,
+                                        &layout,
+                                    )?;
                                 }
                             }
                             continue;
                         }
-                        write_ev(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
+                        write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
                     }
                     "weather" => {
                         in_weather = false;
-                        write_ev(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
+                        write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
                     }
                     _ => {
                         if skip_until_forecast_end {
                             continue;
                         }
-                        write_ev(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
+                        write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
                     }
                 }
             }
@@ -196,41 +225,51 @@ pub fn write_environment_changes(
                 if skip_until_forecast_end {
                     continue;
                 }
-                write_ev(&mut writer, &xml_path, event.into_owned())?;
-            }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
+                write_event(&mut writer, &xml_path, event.into_owned())?;
             }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
     Ok(())
 }
 
+/// Whitespace of the `<forecast>` section found in the source file.
+struct ForecastLayout {
+    /// Indentation of the first original `<instance>`, if there was one
+    instance_indent: Option<String>,
+    /// Indentation of `<forecast>`, which its closing tag shares
+    closing_indent: String,
+    unit: String,
+    newline: &'static str,
+}
+
 /// Writes a complete `<forecast>...</forecast>` section with the given events.
+/// Without an original instance to copy, instances are indented one unit deeper
+/// than `<forecast>`.
 fn write_forecast_section(
     writer: &mut Writer<Vec<u8>>,
     xml_path: &Path,
     events: &[WeatherEvent],
+    layout: &ForecastLayout,
 ) -> Result<(), AppError> {
-    write_ev(
+    let instance_indent = layout
+        .instance_indent
+        .clone()
+        .unwrap_or_else(|| format!("{}{}", layout.closing_indent, layout.unit));
+
+    write_event(
         writer,
         xml_path,
         Event::Start(BytesStart::new("forecast")),
     )?;
-    // Newline after <forecast>
-    write_ev(
+    write_event(
         writer,
         xml_path,
-        Event::Text(BytesText::new("\n").into_owned()),
+        Event::Text(BytesText::new(layout.newline).into_owned()),
     )?;
 
     for event in events {
@@ -244,25 +283,25 @@ fn write_forecast_section(
         elem.push_attribute(("startDay", event.start_day.to_string().as_str()));
         elem.push_attribute(("startDayTime", event.start_day_time.to_string().as_str()));
         elem.push_attribute(("duration", event.duration.to_string().as_str()));
-        write_ev(
+        write_event(
             writer,
             xml_path,
-            Event::Text(BytesText::new("            ").into_owned()),
+            Event::Text(BytesText::new(&instance_indent).into_owned()),
         )?;
-        write_ev(writer, xml_path, Event::Empty(elem))?;
-        write_ev(
+        write_event(writer, xml_path, Event::Empty(elem))?;
+        write_event(
             writer,
             xml_path,
-            Event::Text(BytesText::new("\n").into_owned()),
+            Event::Text(BytesText::new(layout.newline).into_owned()),
         )?;
     }
 
-    write_ev(
+    write_event(
         writer,
         xml_path,
-        Event::Text(BytesText::new("        ").into_owned()),
+        Event::Text(BytesText::new(&layout.closing_indent).into_owned()),
     )?;
-    write_ev(
+    write_event(
         writer,
         xml_path,
         Event::End(BytesEnd::new("forecast").into_owned()),
@@ -285,19 +324,6 @@ fn read_text_content(reader: &mut Reader<&[u8]>) -> String {
     buf
 }
 
-fn write_ev(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer
-        .write_event(event)
-        .map_err(|e| AppError::XmlParseError {
-            file: xml_path.display().to_string(),
-            message: e.to_string(),
-        })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -397,6 +423,53 @@ mod tests {
         let _ = std::fs::remove_dir_all(&save);
     }
 
+    /// Lines of `<forecast>` to `</forecast>` in environment.xml of `save`.
+    fn forecast_lines(save: &Path) -> Vec<String> {
+        let content = std::fs::read_to_string(save.join("environment.xml")).unwrap();
+        let start = content.find("<forecast").unwrap();
+        let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+        let end = content.find("</forecast>").unwrap() + "</forecast>".len();
+        content[line_start..end].lines().map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_write_environment_forecast_keeps_indentation() {
+        let save = setup_fixture("env_forecast_indent");
+        let forecast = vec![WeatherEvent {
+            type_name: "SUN".to_string(),
+            season: "SUMMER".to_string(),
+            variation_index: 3,
+            start_day: 54,
+            start_day_time: 10800000,
+            duration: 36000000,
+        }];
+        let changes = EnvironmentChanges {
+            day_time: None,
+            current_day: None,
+            snow_height: None,
+            ground_wetness: None,
+            weather_forecast: Some(forecast.clone()),
+        };
+        write_environment_changes(&save, &changes).unwrap();
+        assert_eq!(
+            forecast_lines(&save),
+            vec![
+                "        <forecast>",
+                r#"            <instance typeName="SUN" season="SUMMER" variationIndex="3" startDay="54" startDayTime="10800000" duration="36000000"/>"#,
+                "        </forecast>",
+            ]
+        );
+
+        // A tab-indented CRLF file with an empty forecast
+        let xml = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\r\n<environment>\r\n\t<weather>\r\n\t\t<forecast/>\r\n\t</weather>\r\n</environment>";
+        std::fs::write(save.join("environment.xml"), xml).unwrap();
+        write_environment_changes(&save, &changes).unwrap();
+        let content = std::fs::read_to_string(save.join("environment.xml")).unwrap();
+        assert!(content.contains("\t\t<forecast>\r\n\t\t\t<instance typeName=\"SUN\""));
+        assert!(content.contains("/>\r\n\t\t</forecast>\r\n\t</weather>"));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
     #[test]
     fn test_write_environment_roundtrip() {
         let save = setup_fixture("env_roundtrip");
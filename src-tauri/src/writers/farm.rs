@@ -1,22 +1,49 @@
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use quick_xml::events::{BytesStart, Event};
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
 use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
+use crate::models::changes::{FarmChange, FinanceChanges, PlayerPermissionChanges};
+use crate::models::farm::FARM_COLORS;
+use crate::parsers::common::{attr_str, attr_str_opt, encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, FARMS};
+use crate::writers::common::{
+    atomic_write, indent_unit, line_ending, patch_attributes, trailing_indent, write_event,
+    xml_error,
+};
 
-/// Modifies money and/or loan in farms.xml for the specified farm.
+/// Modifies money, loan and/or loan interest rate in farms.xml for the specified farm.
 /// Uses patch strategy: reads, modifies only the target attributes, rewrites atomically.
+/// The interest rate is only rewritten when the farm already has a `loanInterestRate`
+/// attribute; it is never added to saves that do not expose it.
 pub fn write_farm_finances(
     path: &Path,
     farm_id: u8,
     money: Option<f64>,
     loan: Option<f64>,
+    loan_interest_rate: Option<f64>,
 ) -> Result<(), AppError> {
-    let xml_path = path.join("farms.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let changes = HashMap::from([(
+        farm_id,
+        FinanceChanges {
+            money,
+            loan,
+            loan_interest_rate,
+        },
+    )]);
+    write_farms_finances(path, &changes)
+}
+
+/// Same as `write_farm_finances` for several farms at once, keyed by farm id,
+/// in a single rewrite of farms.xml.
+pub fn write_farms_finances(
+    path: &Path,
+    changes: &HashMap<u8, FinanceChanges>,
+) -> Result<(), AppError> {
+    let xml_path = savegame_file(path, FARMS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -37,85 +64,346 @@ pub fn write_farm_finances(
                         })
                         .unwrap_or(0);
 
-                    if current_id == farm_id {
-                        let mut elem = BytesStart::new("farm");
-                        for attr in e.attributes().flatten() {
-                            let key =
-                                String::from_utf8_lossy(attr.key.as_ref()).to_string();
-                            match key.as_str() {
-                                "money" if money.is_some() => {
-                                    elem.push_attribute((
-                                        "money",
-                                        format!("{:.6}", money.unwrap()).as_str(),
-                                    ));
-                                }
-                                "loan" if loan.is_some() => {
-                                    elem.push_attribute((
-                                        "loan",
-                                        format!("{:.6}", loan.unwrap()).as_str(),
-                                    ));
-                                }
-                                _ => {
-                                    elem.push_attribute((
-                                        key.as_str(),
-                                        String::from_utf8_lossy(&attr.value).as_ref(),
-                                    ));
-                                }
+                    if let Some(change) = changes.get(&current_id) {
+                        let elem = patch_attributes(e, |key| match key {
+                            "money" => change.money.map(|v| format!("{:.6}", v)),
+                            "loan" => change.loan.map(|v| format!("{:.6}", v)),
+                            "loanInterestRate" => {
+                                change.loan_interest_rate.map(|v| format!("{:.6}", v))
                             }
-                        }
-                        writer
-                            .write_event(Event::Start(elem))
-                            .map_err(|e| AppError::XmlParseError {
-                                file: xml_path.display().to_string(),
-                                message: e.to_string(),
-                            })?;
+                            _ => None,
+                        });
+                        write_event(&mut writer, &xml_path, Event::Start(elem))?;
                     } else {
-                        writer
-                            .write_event(Event::Start(e.clone().into_owned()))
-                            .map_err(|e| AppError::XmlParseError {
-                                file: xml_path.display().to_string(),
-                                message: e.to_string(),
-                            })?;
+                        write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                     }
                 } else {
-                    writer
-                        .write_event(Event::Start(e.clone().into_owned()))
-                        .map_err(|e| AppError::XmlParseError {
-                            file: xml_path.display().to_string(),
-                            message: e.to_string(),
-                        })?;
+                    write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                 }
             }
             Ok(Event::Eof) => break,
             Ok(event) => {
-                writer
-                    .write_event(event.into_owned())
-                    .map_err(|e| AppError::XmlParseError {
-                        file: xml_path.display().to_string(),
-                        message: e.to_string(),
-                    })?;
-            }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
+                write_event(&mut writer, &xml_path, event.into_owned())?;
             }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
     Ok(())
 }
 
+/// Applies farm attribute changes (color) to farms.xml, matching farms by id.
+/// Fails without writing anything when a color is outside `FARM_COLORS`.
+/// Returns the ids that matched a farm.
+pub fn write_farm_changes(path: &Path, changes: &[FarmChange]) -> Result<HashSet<u8>, AppError> {
+    if let Some(color) = changes
+        .iter()
+        .filter_map(|c| c.color)
+        .find(|color| !FARM_COLORS.contains(color))
+    {
+        return Err(AppError::Generic(format!("Invalid farm color: {}", color)));
+    }
+
+    let xml_path = savegame_file(path, FARMS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+    let mut matched: HashSet<u8> = HashSet::new();
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"farm" => {
+                let farm_id: u8 = attr_str(e, "farmId").parse().unwrap_or(0);
+                match changes.iter().find(|c| c.farm_id == farm_id) {
+                    Some(change) => {
+                        matched.insert(farm_id);
+                        let elem = patch_attributes(e, |key| match key {
+                            "color" => change.color.map(|v| v.to_string()),
+                            _ => None,
+                        });
+                        write_event(&mut writer, &xml_path, Event::Start(elem))?;
+                    }
+                    None => write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?,
+                }
+            }
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
+        }
+    }
+
+    let output = writer.into_inner();
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
+
+    Ok(matched)
+}
+
+/// Zero every counter of the `<statistics>` section of `farm_id` in farms.xml,
+/// keeping whole numbers whole. The daily `<finances>` history is left alone.
+/// Returns false, leaving the file untouched, when the farm has no statistics.
+pub fn reset_farm_statistics(path: &Path, farm_id: u8) -> Result<bool, AppError> {
+    let xml_path = savegame_file(path, FARMS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+    let mut in_farm = false;
+    let mut in_statistics = false;
+    let mut reset = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                match e.name().as_ref() {
+                    b"farm" => in_farm = attr_str(e, "farmId").parse() == Ok(farm_id),
+                    b"statistics" if in_farm => in_statistics = true,
+                    _ => {}
+                }
+                write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
+            }
+            Ok(Event::Text(ref e)) if in_statistics => {
+                let text = e.unescape().map_err(|err| xml_error(&xml_path, err))?;
+                let value = text.trim();
+                if value.is_empty() {
+                    write_event(&mut writer, &xml_path, Event::Text(e.clone().into_owned()))?;
+                } else {
+                    let zero = if value.contains('.') { "0.000000" } else { "0" };
+                    write_event(&mut writer, &xml_path, Event::Text(BytesText::new(zero)))?;
+                    reset = true;
+                }
+            }
+            Ok(Event::End(ref e)) => {
+                match e.name().as_ref() {
+                    b"farm" => in_farm = false,
+                    b"statistics" => in_statistics = false,
+                    _ => {}
+                }
+                write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
+            }
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
+        }
+    }
+
+    if reset {
+        let output = writer.into_inner();
+        atomic_write(&xml_path, &encode_xml(&output, encoding))?;
+    }
+
+    Ok(reset)
+}
+
+/// Set the statistic `name` of `farm_id` in farms.xml to `value`, already
+/// formatted. A statistic the section lacks is added at its end, indented like
+/// its siblings; every other statistic is kept as is. Returns false, leaving the
+/// file untouched, when the farm has no statistics.
+pub fn write_farm_statistic(
+    path: &Path,
+    farm_id: u8,
+    name: &str,
+    value: &str,
+) -> Result<bool, AppError> {
+    let xml_path = savegame_file(path, FARMS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+    let mut in_farm = false;
+    let mut in_statistics = false;
+    let mut in_target = false;
+    let mut written = false;
+    let mut found = false;
+    // Whitespace preceding the current tag, to indent an added statistic
+    let mut indent: Option<String> = None;
+
+    loop {
+        let event = reader.read_event();
+        let preceding = indent.take();
+        if let Ok(Event::Text(ref text)) = event {
+            indent = trailing_indent(&String::from_utf8_lossy(text));
+        }
+        match event {
+            Ok(Event::Start(ref e)) => {
+                match e.name().as_ref() {
+                    b"farm" => in_farm = attr_str(e, "farmId").parse() == Ok(farm_id),
+                    b"statistics" if in_farm => {
+                        in_statistics = true;
+                        found = true;
+                    }
+                    tag if in_statistics && tag == name.as_bytes() => in_target = true,
+                    _ => {}
+                }
+                write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
+            }
+            Ok(Event::Text(_)) if in_target => {}
+            Ok(Event::Empty(ref e)) if in_statistics && e.name().as_ref() == name.as_bytes() => {
+                write_statistic(&mut writer, &xml_path, name, value)?;
+                written = true;
+            }
+            Ok(Event::End(ref e)) => {
+                match e.name().as_ref() {
+                    b"farm" => in_farm = false,
+                    b"statistics" if in_statistics => {
+                        if !written {
+                            // The closing tag is preceded by its own indentation:
+                            // indent the added line one unit deeper, then restore it
+                            let unit = preceding.as_ref().map(|_| indent_unit(&content));
+                            let text = BytesText::new(unit.as_deref().unwrap_or_default());
+                            write_event(&mut writer, &xml_path, Event::Text(text))?;
+                            write_statistic(&mut writer, &xml_path, name, value)?;
+                            if let Some(closing_indent) = preceding {
+                                let tail = format!("{}{}", line_ending(&content), closing_indent);
+                                write_event(
+                                    &mut writer,
+                                    &xml_path,
+                                    Event::Text(BytesText::new(&tail)),
+                                )?;
+                            }
+                            written = true;
+                        }
+                        in_statistics = false;
+                    }
+                    tag if in_target && tag == name.as_bytes() => {
+                        write_event(&mut writer, &xml_path, Event::Text(BytesText::new(value)))?;
+                        in_target = false;
+                        written = true;
+                    }
+                    _ => {}
+                }
+                write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
+            }
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
+        }
+    }
+
+    if found {
+        let output = writer.into_inner();
+        atomic_write(&xml_path, &encode_xml(&output, encoding))?;
+    }
+
+    Ok(found)
+}
+
+fn write_statistic(
+    writer: &mut Writer<Vec<u8>>,
+    xml_path: &Path,
+    name: &str,
+    value: &str,
+) -> Result<(), AppError> {
+    write_event(writer, xml_path, Event::Start(BytesStart::new(name)))?;
+    write_event(writer, xml_path, Event::Text(BytesText::new(value)))?;
+    write_event(writer, xml_path, Event::End(BytesEnd::new(name)))
+}
+
+/// Patch the permission attributes of the `<player>` whose `uniqueUserId` is
+/// `user_id` in farms.xml. Flags the player element lacks are appended; every
+/// other attribute is kept as is. Returns false, leaving the file untouched,
+/// when no player has this id.
+pub fn write_player_permissions(
+    path: &Path,
+    user_id: &str,
+    changes: &PlayerPermissionChanges,
+) -> Result<bool, AppError> {
+    rewrite_player(path, user_id, |e| {
+        let mut elem = patch_attributes(e, |key| changes.value_for(key).map(|v| v.to_string()));
+        for key in PlayerPermissionChanges::ATTRIBUTES {
+            if let Some(value) = changes.value_for(key) {
+                if attr_str_opt(e, key).is_none() {
+                    elem.push_attribute((key, value.to_string().as_str()));
+                }
+            }
+        }
+        elem
+    })
+}
+
+/// Set the `lastNickname` of the `<player>` whose `uniqueUserId` is `user_id` in
+/// farms.xml, the name the game shows for them. The attribute is added when the
+/// player element lacks it. Returns false, leaving the file untouched, when no
+/// player has this id.
+pub fn write_player_nickname(path: &Path, user_id: &str, nickname: &str) -> Result<bool, AppError> {
+    rewrite_player(path, user_id, |e| {
+        let mut elem = patch_attributes(e, |key| {
+            (key == "lastNickname").then(|| nickname.to_string())
+        });
+        if attr_str_opt(e, "lastNickname").is_none() {
+            elem.push_attribute(("lastNickname", nickname));
+        }
+        elem
+    })
+}
+
+/// Rewrite farms.xml with the `<player>` whose `uniqueUserId` is `user_id`
+/// replaced by what `patch` makes of it. Returns false, leaving the file
+/// untouched, when no player has this id.
+fn rewrite_player<F>(path: &Path, user_id: &str, patch: F) -> Result<bool, AppError>
+where
+    F: Fn(&BytesStart) -> BytesStart<'static>,
+{
+    let xml_path = savegame_file(path, FARMS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+    let mut found = false;
+
+    let patch_player = |e: &BytesStart| -> Option<BytesStart<'static>> {
+        if e.name().as_ref() != b"player" || attr_str(e, "uniqueUserId") != user_id {
+            return None;
+        }
+        Some(patch(e))
+    };
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => match patch_player(e) {
+                Some(elem) => {
+                    found = true;
+                    write_event(&mut writer, &xml_path, Event::Start(elem))?;
+                }
+                None => write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?,
+            },
+            Ok(Event::Empty(ref e)) => match patch_player(e) {
+                Some(elem) => {
+                    found = true;
+                    write_event(&mut writer, &xml_path, Event::Empty(elem))?;
+                }
+                None => write_event(&mut writer, &xml_path, Event::Empty(e.clone().into_owned()))?,
+            },
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
+        }
+    }
+
+    if found {
+        let output = writer.into_inner();
+        atomic_write(&xml_path, &encode_xml(&output, encoding))?;
+    }
+
+    Ok(found)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::parsers::farm::parse_farms;
+    use crate::services::xml_diff::xml_equivalent;
 
     fn setup_fixture(name: &str) -> std::path::PathBuf {
         let src = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -137,7 +425,7 @@ mod tests {
     #[test]
     fn test_write_farm_money() {
         let save = setup_fixture("money");
-        write_farm_finances(&save, 1, Some(777777.0), None).unwrap();
+        write_farm_finances(&save, 1, Some(777777.0), None, None).unwrap();
         let farms = parse_farms(&save).unwrap();
         assert!((farms[0].money - 777777.0).abs() < 0.01);
         let _ = std::fs::remove_dir_all(&save);
@@ -146,7 +434,7 @@ mod tests {
     #[test]
     fn test_write_farm_loan() {
         let save = setup_fixture("loan");
-        write_farm_finances(&save, 1, None, Some(12345.0)).unwrap();
+        write_farm_finances(&save, 1, None, Some(12345.0), None).unwrap();
         let farms = parse_farms(&save).unwrap();
         assert!((farms[0].loan - 12345.0).abs() < 0.01);
         let _ = std::fs::remove_dir_all(&save);
@@ -156,7 +444,7 @@ mod tests {
     fn test_write_farm_preserves_stats() {
         let save = setup_fixture("preserve");
         let before = parse_farms(&save).unwrap();
-        write_farm_finances(&save, 1, Some(1.0), Some(2.0)).unwrap();
+        write_farm_finances(&save, 1, Some(1.0), Some(2.0), None).unwrap();
         let after = parse_farms(&save).unwrap();
 
         assert_eq!(after[0].farm_id, before[0].farm_id);
@@ -165,4 +453,231 @@ mod tests {
         assert_eq!(after[0].players.len(), before[0].players.len());
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    #[test]
+    fn test_reset_farm_statistics() {
+        let save = setup_fixture("reset_statistics");
+        assert!(!reset_farm_statistics(&save, 9).unwrap());
+        assert!(reset_farm_statistics(&save, 1).unwrap());
+
+        let farm = &parse_farms(&save).unwrap()[0];
+        assert_eq!(farm.statistics.bale_count, 0);
+        assert_eq!(farm.statistics.revenue, 0.0);
+        assert_eq!(farm.statistics.traveled_distance, 0.0);
+        assert!((farm.money - 1_000_000.0).abs() < 0.01);
+        let content = std::fs::read_to_string(save.join("farms.xml")).unwrap();
+        assert!(content.contains("<baleCount>0</baleCount>"));
+        assert!(content.contains("<playTime>0.000000</playTime>"));
+        // The finance history is not a statistic
+        assert!(content.contains("<newVehiclesCost>-150000.000000</newVehiclesCost>"));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_farm_statistic() {
+        let save = setup_fixture("statistic");
+        assert!(!write_farm_statistic(&save, 9, "baleCount", "1").unwrap());
+        assert!(write_farm_statistic(&save, 1, "workedHectares", "1000.000000").unwrap());
+        // Not in the fixture: added at the end of the section
+        assert!(write_farm_statistic(&save, 1, "breedCowsCount", "12").unwrap());
+
+        let farm = &parse_farms(&save).unwrap()[0];
+        assert_eq!(farm.statistics.worked_hectares, 1000.0);
+        assert_eq!(farm.statistics.breed_cows_count, 12);
+        assert_eq!(farm.statistics.bale_count, 42);
+        assert_eq!(farm.statistics.threshed_hectares, 75.0);
+        let content = std::fs::read_to_string(save.join("farms.xml")).unwrap();
+        let added = "</playTime>\n      <breedCowsCount>12</breedCowsCount>\n    </statistics>";
+        assert!(content.contains(added));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_farm_preserves_escaped_name() {
+        let save = setup_fixture("escaped_name");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replace(r#"name="My Farm""#, r#"name="Dupré &amp; Fils""#);
+        std::fs::write(&xml_path, &original).unwrap();
+
+        write_farm_finances(&save, 1, Some(1.0), None, None).unwrap();
+        let after = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(after.contains(r#"name="Dupré &amp; Fils""#));
+        assert!(!after.contains("&amp;amp;"));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_farm_preserves_large_counts() {
+        let save = setup_fixture("large_counts");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replace("<baleCount>42</baleCount>", "<baleCount>5000000000</baleCount>");
+        std::fs::write(&xml_path, &original).unwrap();
+
+        let before = parse_farms(&save).unwrap();
+        assert_eq!(before[0].statistics.bale_count, 5_000_000_000);
+
+        write_farm_finances(&save, 1, Some(1.0), None, None).unwrap();
+        let after = parse_farms(&save).unwrap();
+        assert_eq!(after[0].statistics.bale_count, 5_000_000_000);
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_farm_loan_interest_rate_round_trip() {
+        let save = setup_fixture("interest_rate");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replace(r#"loan="50000.000000""#, r#"loan="50000.000000" loanInterestRate="3.500000""#);
+        std::fs::write(&xml_path, &original).unwrap();
+
+        let before = parse_farms(&save).unwrap();
+        assert_eq!(before[0].loan_interest_rate, Some(3.5));
+
+        write_farm_finances(&save, 1, None, None, Some(1.25)).unwrap();
+        let after = parse_farms(&save).unwrap();
+        assert_eq!(after[0].loan_interest_rate, Some(1.25));
+        assert!((after[0].loan - 50000.0).abs() < 0.01);
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_farm_color_round_trip() {
+        let save = setup_fixture("color");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path).unwrap().replace(
+            "</farms>",
+            r#"  <farm farmId="2" name="Neighbours" color="3" loan="0.000000" money="5000.000000">
+  </farm>
+</farms>"#,
+        );
+        std::fs::write(&xml_path, &original).unwrap();
+
+        let recolor = |farm_id, color| FarmChange {
+            farm_id,
+            color: Some(color),
+        };
+        write_farm_changes(&save, &[recolor(1, 7), recolor(2, 12)]).unwrap();
+        let farms = parse_farms(&save).unwrap();
+        assert_eq!((farms[0].color, farms[1].color), (7, 12));
+        assert_eq!(farms[0].name, "My Farm");
+        assert!((farms[0].loan - 50000.0).abs() < 0.01);
+        assert_eq!(farms[0].players.len(), 1);
+
+        write_farm_changes(&save, &[recolor(1, 1), recolor(2, 3)]).unwrap();
+        let after = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(xml_equivalent(&after, &original));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_farm_color_invalid() {
+        let save = setup_fixture("color_invalid");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path).unwrap();
+
+        for color in [0, 17] {
+            let changes = [FarmChange {
+                farm_id: 1,
+                color: Some(color),
+            }];
+            assert!(matches!(
+                write_farm_changes(&save, &changes),
+                Err(AppError::Generic(_))
+            ));
+        }
+        assert_eq!(std::fs::read_to_string(&xml_path).unwrap(), original);
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_player_nickname_special_characters() {
+        let save = setup_fixture("player_nickname");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path).unwrap().replace(
+            "</players>",
+            r#"  <player uniqueUserId="player2-uid" farmManager="false" />
+    </players>"#,
+        );
+        std::fs::write(&xml_path, &original).unwrap();
+
+        assert!(!write_player_nickname(&save, "nobody", "Nobody").unwrap());
+        assert_eq!(std::fs::read_to_string(&xml_path).unwrap(), original);
+
+        assert!(write_player_nickname(&save, "player1-uid", "Éloïse \"O'Brien\" & <Co>").unwrap());
+        // No nickname yet: the attribute is added
+        assert!(write_player_nickname(&save, "player2-uid", "Helper").unwrap());
+
+        let players = &parse_farms(&save).unwrap()[0].players;
+        assert_eq!(players[0].last_nickname, "Éloïse \"O'Brien\" & <Co>");
+        assert!(players[0].farm_manager);
+        assert_eq!(players[1].last_nickname, "Helper");
+        let content = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(content.contains("&amp; &lt;Co&gt;"));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_player_permissions_round_trip() {
+        let save = setup_fixture("player_permissions");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path).unwrap().replace(
+            "</players>",
+            r#"  <player uniqueUserId="player2-uid" farmManager="false" lastNickname="Helper" manageContracts="true" />
+    </players>"#,
+        );
+        std::fs::write(&xml_path, &original).unwrap();
+
+        let revoke = PlayerPermissionChanges {
+            manage_contracts: Some(false),
+            ..Default::default()
+        };
+        assert!(write_player_permissions(&save, "player2-uid", &revoke).unwrap());
+        let players = &parse_farms(&save).unwrap()[0].players;
+        assert!(players[0].manage_contracts);
+        assert!(!players[1].manage_contracts);
+        assert_eq!(players[1].last_nickname, "Helper");
+
+        let grant = PlayerPermissionChanges {
+            manage_contracts: Some(true),
+            ..Default::default()
+        };
+        write_player_permissions(&save, "player2-uid", &grant).unwrap();
+        let after = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(xml_equivalent(&after, &original));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_player_permissions_unknown_player() {
+        let save = setup_fixture("player_permissions_unknown");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path).unwrap();
+
+        let changes = PlayerPermissionChanges {
+            farm_manager: Some(false),
+            ..Default::default()
+        };
+        assert!(!write_player_permissions(&save, "nobody", &changes).unwrap());
+        assert_eq!(std::fs::read_to_string(&xml_path).unwrap(), original);
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_farm_loan_interest_rate_absent_is_noop() {
+        let save = setup_fixture("interest_rate_absent");
+        let xml_path = save.join("farms.xml");
+        let original = std::fs::read_to_string(&xml_path).unwrap();
+
+        write_farm_finances(&save, 1, None, None, Some(1.25)).unwrap();
+        let after = std::fs::read_to_string(&xml_path).unwrap();
+        assert!(!after.contains("loanInterestRate"));
+        assert_eq!(parse_farms(&save).unwrap()[0].loan_interest_rate, None);
+        assert_eq!(after.len(), original.len());
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
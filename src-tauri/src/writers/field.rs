@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::Path;
 
 use quick_xml::events::{BytesStart, Event};
@@ -5,17 +6,19 @@ use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
 use crate::models::changes::{FarmlandChange, FieldChange};
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, FARMLAND, FIELDS};
+use crate::writers::common::{atomic_write, raw_attr_u32, patch_attributes, write_event, xml_error};
 
 /// Applies field changes to fields.xml.
-/// Fields are identified by their `id` attribute.
-pub fn write_field_changes(path: &Path, changes: &[FieldChange]) -> Result<(), AppError> {
-    let xml_path = path.join("fields.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+/// Fields are identified by their `id` attribute. Returns the ids that matched a field.
+pub fn write_field_changes(path: &Path, changes: &[FieldChange]) -> Result<HashSet<u32>, AppError> {
+    let xml_path = savegame_file(path, FIELDS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let change_map: std::collections::HashMap<u32, &FieldChange> =
         changes.iter().map(|c| (c.id, c)).collect();
+    let mut matched: HashSet<u32> = HashSet::new();
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -25,8 +28,9 @@ pub fn write_field_changes(path: &Path, changes: &[FieldChange]) -> Result<(), A
             Ok(Event::Empty(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if tag == "field" {
-                    let id = attr_u32(e, "id");
+                    let id = raw_attr_u32(e, "id");
                     if let Some(change) = change_map.get(&id) {
+                        matched.insert(id);
                         let elem = patch_field(e, change);
                         write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                     } else {
@@ -48,32 +52,27 @@ pub fn write_field_changes(path: &Path, changes: &[FieldChange]) -> Result<(), A
             Ok(event) => {
                 write_event(&mut writer, &xml_path, event.into_owned())?;
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
-    Ok(())
+    Ok(matched)
 }
 
-/// Applies farmland changes to farmland.xml.
-pub fn write_farmland_changes(path: &Path, changes: &[FarmlandChange]) -> Result<(), AppError> {
-    let xml_path = path.join("farmland.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+/// Applies farmland changes to farmland.xml. Returns the ids that matched a farmland.
+pub fn write_farmland_changes(
+    path: &Path,
+    changes: &[FarmlandChange],
+) -> Result<HashSet<u32>, AppError> {
+    let xml_path = savegame_file(path, FARMLAND);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let change_map: std::collections::HashMap<u32, &FarmlandChange> =
         changes.iter().map(|c| (c.id, c)).collect();
+    let mut matched: HashSet<u32> = HashSet::new();
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -83,8 +82,9 @@ pub fn write_farmland_changes(path: &Path, changes: &[FarmlandChange]) -> Result
             Ok(Event::Empty(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if tag == "farmland" {
-                    let id = attr_u32(e, "id");
+                    let id = raw_attr_u32(e, "id");
                     if let Some(change) = change_map.get(&id) {
+                        matched.insert(id);
                         let elem = patch_farmland(e, change);
                         write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                     } else {
@@ -106,158 +106,46 @@ pub fn write_farmland_changes(path: &Path, changes: &[FarmlandChange]) -> Result
             Ok(event) => {
                 write_event(&mut writer, &xml_path, event.into_owned())?;
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
-
-    Ok(())
-}
-
-fn attr_str(e: &BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
-fn attr_u32(e: &BytesStart, key: &str) -> u32 {
-    attr_str(e, key).parse().unwrap_or(0)
+    Ok(matched)
 }
 
 fn patch_field(e: &BytesStart, change: &FieldChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("field");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "fruitType" if change.fruit_type.is_some() => {
-                elem.push_attribute(("fruitType", change.fruit_type.as_ref().unwrap().as_str()));
-            }
-            "plannedFruit" if change.planned_fruit.is_some() => {
-                elem.push_attribute((
-                    "plannedFruit",
-                    change.planned_fruit.as_ref().unwrap().as_str(),
-                ));
-            }
-            "growthState" if change.growth_state.is_some() => {
-                elem.push_attribute((
-                    "growthState",
-                    change.growth_state.unwrap().to_string().as_str(),
-                ));
-            }
-            "groundType" if change.ground_type.is_some() => {
-                elem.push_attribute((
-                    "groundType",
-                    change.ground_type.as_ref().unwrap().as_str(),
-                ));
-            }
-            "weedState" if change.weed_state.is_some() => {
-                elem.push_attribute((
-                    "weedState",
-                    change.weed_state.unwrap().to_string().as_str(),
-                ));
-            }
-            "stoneLevel" if change.stone_level.is_some() => {
-                elem.push_attribute((
-                    "stoneLevel",
-                    change.stone_level.unwrap().to_string().as_str(),
-                ));
-            }
-            "sprayLevel" if change.spray_level.is_some() => {
-                elem.push_attribute((
-                    "sprayLevel",
-                    change.spray_level.unwrap().to_string().as_str(),
-                ));
-            }
-            "sprayType" if change.spray_type.is_some() => {
-                elem.push_attribute(("sprayType", change.spray_type.as_ref().unwrap().as_str()));
-            }
-            "limeLevel" if change.lime_level.is_some() => {
-                elem.push_attribute((
-                    "limeLevel",
-                    change.lime_level.unwrap().to_string().as_str(),
-                ));
-            }
-            "plowLevel" if change.plow_level.is_some() => {
-                elem.push_attribute((
-                    "plowLevel",
-                    change.plow_level.unwrap().to_string().as_str(),
-                ));
-            }
-            "rollerLevel" if change.roller_level.is_some() => {
-                elem.push_attribute((
-                    "rollerLevel",
-                    change.roller_level.unwrap().to_string().as_str(),
-                ));
-            }
-            "stubbleShredLevel" if change.stubble_shred_level.is_some() => {
-                elem.push_attribute((
-                    "stubbleShredLevel",
-                    change.stubble_shred_level.unwrap().to_string().as_str(),
-                ));
-            }
-            "waterLevel" if change.water_level.is_some() => {
-                elem.push_attribute((
-                    "waterLevel",
-                    change.water_level.unwrap().to_string().as_str(),
-                ));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+    patch_attributes(e, |key| match key {
+        "fruitType" => change.fruit_type.clone(),
+        "plannedFruit" => change.planned_fruit.clone(),
+        "growthState" => change.growth_state.map(|v| v.to_string()),
+        "groundType" => change.ground_type.clone(),
+        "weedState" => change.weed_state.map(|v| v.to_string()),
+        "stoneLevel" => change.stone_level.map(|v| v.to_string()),
+        "sprayLevel" => change.spray_level.map(|v| v.to_string()),
+        "sprayType" => change.spray_type.clone(),
+        "limeLevel" => change.lime_level.map(|v| v.to_string()),
+        "plowLevel" => change.plow_level.map(|v| v.to_string()),
+        "rollerLevel" => change.roller_level.map(|v| v.to_string()),
+        "stubbleShredLevel" => change.stubble_shred_level.map(|v| v.to_string()),
+        "waterLevel" => change.water_level.map(|v| v.to_string()),
+        _ => None,
+    })
 }
 
 fn patch_farmland(e: &BytesStart, change: &FarmlandChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("farmland");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "farmId" => {
-                elem.push_attribute(("farmId", change.farm_id.to_string().as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
-}
-
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer
-        .write_event(event)
-        .map_err(|e| AppError::XmlParseError {
-            file: xml_path.display().to_string(),
-            message: e.to_string(),
-        })
+    patch_attributes(e, |key| match key {
+        "farmId" => Some(change.farm_id.to_string()),
+        _ => None,
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::changes::FieldOperation;
     use crate::parsers::field::{parse_farmlands, parse_fields};
 
     fn setup_fixture(name: &str) -> std::path::PathBuf {
@@ -384,4 +272,36 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    #[test]
+    fn test_write_prepared_field_roundtrip() {
+        let save = setup_fixture("field_prepared");
+        let operations = [
+            FieldOperation::Plow,
+            FieldOperation::Cultivate,
+            FieldOperation::Lime,
+            FieldOperation::Fertilize,
+        ];
+        write_field_changes(&save, &[FieldChange::prepared(3, &operations)]).unwrap();
+        let fields = parse_fields(&save).unwrap();
+        let f = fields.iter().find(|f| f.id == 3).unwrap();
+        assert_eq!(f.fruit_type, "UNKNOWN");
+        assert_eq!(f.growth_state, 0);
+        assert_eq!(f.weed_state, 0);
+        assert_eq!(f.ground_type, "CULTIVATED");
+        assert_eq!(f.plow_level, 1);
+        assert_eq!(f.lime_level, 3);
+        assert_eq!(f.spray_level, 2);
+        // Untouched by field work
+        assert_eq!(f.stone_level, 2);
+        assert_eq!(f.planned_fruit, "FALLOW");
+
+        // Preparing it again leaves the file as it is
+        let once = std::fs::read_to_string(save.join("fields.xml")).unwrap();
+        write_field_changes(&save, &[FieldChange::prepared(3, &operations)]).unwrap();
+        let twice = std::fs::read_to_string(save.join("fields.xml")).unwrap();
+        assert_eq!(once, twice);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use crate::error::AppError;
+use crate::parsers::common::{encode_xml, has_xml_declaration, read_xml_file_with_encoding};
+use crate::parsers::files::savegame_file;
+use crate::writers::common::atomic_write;
+
+/// Body of `content` without leading whitespace or a truncated declaration.
+fn strip_broken_declaration(content: &str) -> &str {
+    let trimmed = content.trim_start();
+    if has_xml_declaration(trimmed) || !trimmed.starts_with("<?xml") {
+        return trimmed;
+    }
+    match trimmed[1..].find('<') {
+        Some(next) => &trimmed[next + 1..],
+        None => "",
+    }
+}
+
+/// Make sure `file` of the savegame starts with a well-formed XML declaration.
+/// Leading whitespace is dropped and a missing or truncated declaration is replaced
+/// by the one the game writes, naming the encoding the file is stored in.
+/// Returns whether the file was changed.
+pub fn ensure_xml_header(path: &Path, file: &str) -> Result<bool, AppError> {
+    let xml_path = savegame_file(path, file);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+    if has_xml_declaration(&content) {
+        return Ok(false);
+    }
+
+    let body = strip_broken_declaration(&content);
+    let repaired = if has_xml_declaration(body) {
+        body.to_string()
+    } else {
+        format!(
+            "<?xml version=\"1.0\" encoding=\"{}\" standalone=\"no\"?>\n{}",
+            encoding.declaration_name(),
+            body
+        )
+    };
+
+    atomic_write(&xml_path, &encode_xml(repaired.as_bytes(), encoding))?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::farm::parse_farms;
+
+    fn setup_fixture(name: &str) -> std::path::PathBuf {
+        let src = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_complete");
+        let dst = std::env::temp_dir().join(format!("fs25_test_wh_{}", name));
+        let _ = std::fs::remove_dir_all(&dst);
+        std::fs::create_dir_all(&dst).unwrap();
+        for entry in std::fs::read_dir(&src).unwrap() {
+            let entry = entry.unwrap();
+            if entry.file_type().unwrap().is_file() {
+                std::fs::copy(entry.path(), dst.join(entry.file_name())).unwrap();
+            }
+        }
+        dst
+    }
+
+    fn strip_header(path: &Path, file: &str) {
+        let content = std::fs::read_to_string(path.join(file)).unwrap();
+        let body = &content[content.find("?>").unwrap() + 2..];
+        std::fs::write(path.join(file), body.trim_start()).unwrap();
+    }
+
+    #[test]
+    fn test_inserts_missing_header() {
+        let path = setup_fixture("missing");
+        strip_header(&path, "farms.xml");
+
+        assert!(ensure_xml_header(&path, "farms.xml").unwrap());
+
+        let content = std::fs::read_to_string(path.join("farms.xml")).unwrap();
+        assert!(content
+            .starts_with("<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"no\"?>\n<farms>"));
+        assert_eq!(parse_farms(&path).unwrap()[0].name, "My Farm");
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_replaces_truncated_header() {
+        let path = setup_fixture("truncated");
+        strip_header(&path, "farms.xml");
+        let body = std::fs::read_to_string(path.join("farms.xml")).unwrap();
+        std::fs::write(
+            path.join("farms.xml"),
+            format!("<?xml version=\"1.0\" {}", body),
+        )
+        .unwrap();
+
+        assert!(ensure_xml_header(&path, "farms.xml").unwrap());
+
+        let content = std::fs::read_to_string(path.join("farms.xml")).unwrap();
+        assert_eq!(content.matches("<?xml").count(), 1);
+        assert!(content.contains("?>\n<farms>"));
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_repairs_file_name_variant() {
+        let path = setup_fixture("variant");
+        strip_header(&path, "farms.xml");
+        std::fs::rename(path.join("farms.xml"), path.join("Farms.XML")).unwrap();
+
+        assert!(ensure_xml_header(&path, "farms.xml").unwrap());
+
+        let content = std::fs::read_to_string(path.join("Farms.XML")).unwrap();
+        assert!(content.starts_with("<?xml"));
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[test]
+    fn test_valid_header_untouched() {
+        let path = setup_fixture("valid");
+        let before = std::fs::read(path.join("farms.xml")).unwrap();
+
+        assert!(!ensure_xml_header(&path, "farms.xml").unwrap());
+
+        assert_eq!(std::fs::read(path.join("farms.xml")).unwrap(), before);
+        let _ = std::fs::remove_dir_all(&path);
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::error::AppError;
+use crate::models::item::is_pallet_filename;
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, ITEMS, VEHICLES};
+use crate::writers::common::{atomic_write, raw_attr_str, write_event, xml_error};
+
+/// Remove every bale lying on the map from items.xml. Returns the number of bales
+/// removed; a savegame without items.xml has none.
+pub fn clear_bales(path: &Path) -> Result<usize, AppError> {
+    let xml_path = savegame_file(path, ITEMS);
+    if !xml_path.exists() {
+        return Ok(0);
+    }
+    remove_elements(&xml_path, b"item", |e| raw_attr_str(e, "className") == "Bale")
+}
+
+/// Remove the pallets and big bags of vehicles.xml. Pallets referenced by another
+/// vehicle (strapped on a trailer, attached...) are kept; pallets put away in a
+/// storage are saved with the storage placeable and are not affected.
+/// Returns the number of pallets removed.
+pub fn clear_pallets(path: &Path) -> Result<usize, AppError> {
+    let xml_path = savegame_file(path, VEHICLES);
+    let (content, _) = read_xml_file_with_encoding(&xml_path)?;
+    let referenced = referenced_ids(&content, &xml_path)?;
+
+    remove_elements(&xml_path, b"vehicle", |e| {
+        is_pallet_filename(&raw_attr_str(e, "filename"))
+            && !referenced.contains(&raw_attr_str(e, "uniqueId"))
+    })
+}
+
+/// Every attribute value found inside a vehicle, below its own element. Pallets
+/// loaded on a trailer are pointed at this way.
+fn referenced_ids(content: &str, xml_path: &Path) -> Result<HashSet<String>, AppError> {
+    let mut reader = Reader::from_str(content);
+    let mut ids = HashSet::new();
+    let mut depth: u32 = 0;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) => {
+                if depth > 0 {
+                    collect_values(e, &mut ids);
+                    depth += 1;
+                } else if e.name().as_ref() == b"vehicle" {
+                    depth = 1;
+                }
+            }
+            Ok(Event::Empty(ref e)) if depth > 0 => collect_values(e, &mut ids),
+            Ok(Event::End(_)) if depth > 0 => depth -= 1,
+            Ok(Event::Eof) => break,
+            Err(e) => return Err(xml_error(xml_path, e)),
+            _ => {}
+        }
+    }
+
+    Ok(ids)
+}
+
+fn collect_values(e: &BytesStart, ids: &mut HashSet<String>) {
+    for attr in e.attributes().flatten() {
+        ids.insert(String::from_utf8_lossy(&attr.value).to_string());
+    }
+}
+
+/// Drop the `tag` elements matching `remove`, with everything they contain.
+/// Returns the number of elements dropped; the file is left untouched when there are none.
+fn remove_elements(
+    xml_path: &Path,
+    tag: &[u8],
+    remove: impl Fn(&BytesStart) -> bool,
+) -> Result<usize, AppError> {
+    let (content, encoding) = read_xml_file_with_encoding(xml_path)?;
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+    let mut skip_depth: u32 = 0;
+    let mut removed = 0;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(_)) if skip_depth > 0 => skip_depth += 1,
+            Ok(Event::End(_)) if skip_depth > 0 => skip_depth -= 1,
+            Ok(Event::Eof) => break,
+            Ok(_) if skip_depth > 0 => {}
+            Ok(Event::Start(ref e)) if e.name().as_ref() == tag && remove(e) => {
+                skip_depth = 1;
+                removed += 1;
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == tag && remove(e) => {
+                removed += 1;
+            }
+            Ok(event) => {
+                write_event(&mut writer, xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(xml_path, e)),
+        }
+    }
+
+    if removed > 0 {
+        let output = writer.into_inner();
+        atomic_write(xml_path, &encode_xml(&output, encoding))?;
+    }
+
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setup_dir(name: &str) -> std::path::PathBuf {
+        let dst = std::env::temp_dir().join(format!("fs25_test_wi_{}", name));
+        let _ = std::fs::remove_dir_all(&dst);
+        std::fs::create_dir_all(&dst).unwrap();
+        dst
+    }
+
+    #[test]
+    fn test_clear_bales() {
+        let save = setup_dir("bales");
+        std::fs::write(
+            save.join("items.xml"),
+            r#"<?xml version="1.0" encoding="utf-8" standalone="no"?>
+<items>
+  <item className="Bale" filename="data/objects/squarebales/squarebale120.xml" position="10 0 10" farmId="1" fillType="STRAW" />
+  <item className="Bale" filename="data/objects/roundbales/roundbale125.xml" position="12 0 10" farmId="1">
+    <wrapping state="1" />
+  </item>
+  <item className="Lumber" position="20 0 20" />
+</items>"#,
+        )
+        .unwrap();
+
+        assert_eq!(clear_bales(&save).unwrap(), 2);
+        let after = std::fs::read_to_string(save.join("items.xml")).unwrap();
+        assert!(!after.contains("Bale"));
+        assert!(!after.contains("wrapping"));
+        assert!(after.contains("className=\"Lumber\""));
+
+        std::fs::remove_file(save.join("items.xml")).unwrap();
+        assert_eq!(clear_bales(&save).unwrap(), 0);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_clear_pallets_keeps_loaded_ones() {
+        let save = setup_dir("pallets");
+        std::fs::write(
+            save.join("vehicles.xml"),
+            r#"<?xml version="1.0" encoding="utf-8" standalone="no"?>
+<vehicles>
+  <vehicle filename="data/vehicles/krampe/krampeBandit750/krampeBandit750.xml" uniqueId="vehicle0001" farmId="1">
+    <tensionBelts>
+      <belt index="1" isActive="true">
+        <object uniqueId="vehicle0003" />
+      </belt>
+    </tensionBelts>
+  </vehicle>
+  <vehicle filename="data/objects/pallets/fertilizerTank/fertilizerTank.xml" uniqueId="vehicle0002" farmId="1">
+    <fillUnit>
+      <unit index="0" fillType="FERTILIZER" fillLevel="1000.000000" />
+    </fillUnit>
+  </vehicle>
+  <vehicle filename="data/objects/pallets/pioneer/pioneerPallet.xml" uniqueId="vehicle0003" farmId="1" />
+  <vehicle filename="data/objects/bigBag/seeds/bigBagSeeds.xml" uniqueId="vehicle0004" farmId="1" />
+</vehicles>"#,
+        )
+        .unwrap();
+
+        assert_eq!(clear_pallets(&save).unwrap(), 2);
+        let after = std::fs::read_to_string(save.join("vehicles.xml")).unwrap();
+        assert!(after.contains("uniqueId=\"vehicle0001\""));
+        assert!(!after.contains("uniqueId=\"vehicle0002\""));
+        assert!(!after.contains("FERTILIZER"));
+        assert!(after.contains("uniqueId=\"vehicle0003\""));
+        assert!(!after.contains("uniqueId=\"vehicle0004\""));
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::Path;
 
 use quick_xml::events::{BytesStart, Event};
@@ -6,19 +7,22 @@ use quick_xml::{Reader, Writer};
 use crate::error::AppError;
 use crate::models::changes::MissionChange;
 use crate::models::mission::MissionStatus;
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, MISSIONS};
+use crate::writers::common::{atomic_write, raw_attr_str, patch_attributes, write_event, xml_error};
 
 fn is_mission_tag(tag: &str) -> bool {
     tag.ends_with("Mission") && tag != "missions"
 }
 
+/// Applies mission changes to missions.xml, matching missions by uniqueId.
+/// Returns the uniqueIds that matched a mission.
 pub fn write_mission_changes(
     path: &Path,
     changes: &[MissionChange],
-) -> Result<(), AppError> {
-    let xml_path = path.join("missions.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+) -> Result<HashSet<String>, AppError> {
+    let xml_path = savegame_file(path, MISSIONS);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let change_map: std::collections::HashMap<&str, &MissionChange> = changes
         .iter()
@@ -31,18 +35,20 @@ pub fn write_mission_changes(
     // Track whether we're inside a mission that has a matching change
     let mut active_change: Option<&MissionChange> = None;
     let mut active_mission_tag: Option<String> = None;
+    let mut matched: HashSet<String> = HashSet::new();
 
     loop {
         match reader.read_event() {
             Ok(Event::Start(ref e)) => {
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
                 if is_mission_tag(&tag) {
-                    let id = attr_str(e, "uniqueId");
+                    let id = raw_attr_str(e, "uniqueId");
                     if let Some(change) = change_map.get(id.as_str()) {
+                        matched.insert(id.clone());
                         active_change = Some(change);
                         active_mission_tag = Some(tag.clone());
                         // Patch status on mission tag if changed
-                        let elem = patch_mission_tag(e, &tag, change);
+                        let elem = patch_mission_tag(e, change);
                         write_event(&mut writer, &xml_path, Event::Start(elem))?;
                     } else {
                         write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
@@ -85,103 +91,39 @@ pub fn write_mission_changes(
             Ok(event) => {
                 write_event(&mut writer, &xml_path, event.into_owned())?;
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
-
-    Ok(())
-}
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
-fn attr_str(e: &BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
+    Ok(matched)
 }
 
-fn patch_mission_tag(e: &BytesStart, tag_name: &str, change: &MissionChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new(tag_name.to_string());
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "status" if change.status.is_some() => {
-                let status = MissionStatus::from_str(change.status.as_ref().unwrap());
-                elem.push_attribute(("status", status.to_xml_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+fn patch_mission_tag(e: &BytesStart, change: &MissionChange) -> BytesStart<'static> {
+    patch_attributes(e, |key| match key {
+        "status" => change
+            .status
+            .as_ref()
+            .map(|v| MissionStatus::from_str(v).to_xml_str().to_string()),
+        _ => None,
+    })
 }
 
 fn patch_harvest(e: &BytesStart, change: &MissionChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("harvest");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "depositedLiters" if change.deposited_liters.is_some() => {
-                elem.push_attribute(("depositedLiters", format!("{:.6}", change.deposited_liters.unwrap()).as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+    patch_attributes(e, |key| match key {
+        "depositedLiters" => change.deposited_liters.map(|v| format!("{:.6}", v)),
+        _ => None,
+    })
 }
 
 fn patch_info(e: &BytesStart, change: &MissionChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("info");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "reward" if change.reward.is_some() => {
-                elem.push_attribute(("reward", format!("{:.6}", change.reward.unwrap()).as_str()));
-            }
-            "completion" if change.completion.is_some() => {
-                elem.push_attribute(("completion", format!("{:.6}", change.completion.unwrap()).as_str()));
-            }
-            "reimbursement" if change.reimbursement.is_some() => {
-                elem.push_attribute(("reimbursement", format!("{:.6}", change.reimbursement.unwrap()).as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
-}
-
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer.write_event(event).map_err(|e| AppError::XmlParseError {
-        file: xml_path.display().to_string(),
-        message: e.to_string(),
+    patch_attributes(e, |key| match key {
+        "reward" => change.reward.map(|v| format!("{:.6}", v)),
+        "completion" => change.completion.map(|v| format!("{:.6}", v)),
+        "reimbursement" => change.reimbursement.map(|v| format!("{:.6}", v)),
+        _ => None,
     })
 }
 
@@ -1,11 +1,17 @@
 pub mod career;
 pub mod collectible;
+pub mod common;
 pub mod contract;
 pub mod economy;
 pub mod environment;
 pub mod farm;
 pub mod field;
+pub mod header;
+pub mod item;
 pub mod mission;
 pub mod placeable;
+#[cfg(test)]
+mod roundtrip;
 pub mod sale;
+pub mod tree;
 pub mod vehicle;
@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::Path;
 
 use quick_xml::events::{BytesStart, Event};
@@ -5,17 +6,19 @@ use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
 use crate::models::changes::{PlaceableChange, ProductionStockChange};
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, PLACEABLES};
+use crate::writers::common::{atomic_write, raw_attr_str, patch_attributes, write_event, xml_error};
 
 /// Applies a list of placeable changes to placeables.xml.
 /// Patch strategy: reads original XML, modifies only targeted attributes/elements, rewrites atomically.
+/// Returns the indexes that matched a placeable.
 pub fn write_placeable_changes(
     path: &Path,
     changes: &[PlaceableChange],
-) -> Result<(), AppError> {
-    let xml_path = path.join("placeables.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+) -> Result<HashSet<usize>, AppError> {
+    let xml_path = savegame_file(path, PLACEABLES);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     let change_map: std::collections::HashMap<usize, &PlaceableChange> = changes
         .iter()
@@ -33,6 +36,7 @@ pub fn write_placeable_changes(
     let mut in_production_point = false;
     let mut in_production_input = false;
     let mut in_production_output = false;
+    let mut matched: HashSet<usize> = HashSet::new();
 
     loop {
         match reader.read_event() {
@@ -46,6 +50,7 @@ pub fn write_placeable_changes(
                         placeable_index += 1;
 
                         if let Some(change) = current_change {
+                            matched.insert(placeable_index - 1);
                             let elem = patch_placeable_start(e, change);
                             write_event(&mut writer, &xml_path, Event::Start(elem))?;
                         } else {
@@ -109,9 +114,9 @@ pub fn write_placeable_changes(
                         }
                         "storage" if in_production_input => {
                             if let Some(ref input_changes) = change.production_inputs {
-                                let fill_type = attr_str(e, "fillType");
+                                let fill_type = raw_attr_str(e, "fillType");
                                 if let Some(sc) = input_changes.iter().find(|s| s.fill_type == fill_type) {
-                                    let elem = patch_storage(e, sc);
+                                    let elem = patch_storage(e, sc, change.allow_overfill);
                                     write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                                     continue;
                                 }
@@ -119,9 +124,9 @@ pub fn write_placeable_changes(
                         }
                         "storage" if in_production_output => {
                             if let Some(ref output_changes) = change.production_outputs {
-                                let fill_type = attr_str(e, "fillType");
+                                let fill_type = raw_attr_str(e, "fillType");
                                 if let Some(sc) = output_changes.iter().find(|s| s.fill_type == fill_type) {
-                                    let elem = patch_storage(e, sc);
+                                    let elem = patch_storage(e, sc, change.allow_overfill);
                                     write_event(&mut writer, &xml_path, Event::Empty(elem))?;
                                     continue;
                                 }
@@ -166,111 +171,118 @@ pub fn write_placeable_changes(
             Ok(event) => {
                 write_event(&mut writer, &xml_path, event.into_owned())?;
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
-    Ok(())
+    Ok(matched)
 }
 
-fn attr_str(e: &BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
-}
+/// Drop the `<stats>` of the `<sellingStation>` sections of placeables.xml, for
+/// the placeable at `index` or every placeable when `None`. The game starts them
+/// over from zero. Returns the number of entries removed; the file is left
+/// untouched when there are none.
+pub fn clear_selling_station_stats(path: &Path, index: Option<usize>) -> Result<usize, AppError> {
+    let xml_path = savegame_file(path, PLACEABLES);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
-fn patch_placeable_start(e: &BytesStart, change: &PlaceableChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("placeable");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "farmId" if change.farm_id.is_some() => {
-                elem.push_attribute((
-                    "farmId",
-                    change.farm_id.unwrap().to_string().as_str(),
-                ));
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+
+    let mut placeable_index: usize = 0;
+    let mut depth: u32 = 0;
+    let mut targeted = false;
+    let mut in_selling_station = false;
+    let mut skip_depth: u32 = 0;
+    let mut removed = 0;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(_)) if skip_depth > 0 => skip_depth += 1,
+            Ok(Event::End(_)) if skip_depth > 0 => skip_depth -= 1,
+            Ok(Event::Eof) => break,
+            Ok(_) if skip_depth > 0 => {}
+            Ok(Event::Start(ref e)) => {
+                depth += 1;
+                match e.name().as_ref() {
+                    b"placeable" if depth == 2 => {
+                        targeted = index.is_none_or(|i| i == placeable_index);
+                        placeable_index += 1;
+                    }
+                    b"sellingStation" => in_selling_station = targeted,
+                    b"stats" if in_selling_station => {
+                        skip_depth = 1;
+                        depth -= 1;
+                        removed += 1;
+                        continue;
+                    }
+                    _ => {}
+                }
+                write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
             }
-            "price" if change.price.is_some() => {
-                elem.push_attribute((
-                    "price",
-                    format!("{:.6}", change.price.unwrap()).as_str(),
-                ));
+            Ok(Event::Empty(ref e)) if in_selling_station && e.name().as_ref() == b"stats" => {
+                removed += 1;
             }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
+            Ok(Event::End(ref e)) => {
+                match e.name().as_ref() {
+                    b"placeable" if depth == 2 => targeted = false,
+                    b"sellingStation" => in_selling_station = false,
+                    _ => {}
+                }
+                depth = depth.saturating_sub(1);
+                write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
+            }
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
             }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
-    elem
-}
 
-fn patch_material_complete(e: &BytesStart) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("material");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "amountRemaining" => {
-                elem.push_attribute(("amountRemaining", "0"));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
+    if removed > 0 {
+        let output = writer.into_inner();
+        atomic_write(&xml_path, &encode_xml(&output, encoding))?;
     }
-    elem
+
+    Ok(removed)
 }
 
-fn patch_storage(e: &BytesStart, change: &ProductionStockChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("storage");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "fillLevel" => {
-                elem.push_attribute((
-                    "fillLevel",
-                    format!("{:.6}", change.amount).as_str(),
-                ));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+fn patch_placeable_start(e: &BytesStart, change: &PlaceableChange) -> BytesStart<'static> {
+    patch_attributes(e, |key| match key {
+        "farmId" => change.farm_id.map(|v| v.to_string()),
+        "price" => change.price.map(|v| format!("{:.6}", v)),
+        _ => None,
+    })
 }
 
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer
-        .write_event(event)
-        .map_err(|e| AppError::XmlParseError {
-            file: xml_path.display().to_string(),
-            message: e.to_string(),
-        })
+fn patch_material_complete(e: &BytesStart) -> BytesStart<'static> {
+    patch_attributes(e, |key| match key {
+        "amountRemaining" => Some("0".to_string()),
+        _ => None,
+    })
+}
+
+/// Patch a production storage fill level. Unless `allow_overfill` is set, the amount
+/// is clamped to `[0, capacity]` so the game never loads an overfilled storage.
+fn patch_storage(
+    e: &BytesStart,
+    change: &ProductionStockChange,
+    allow_overfill: bool,
+) -> BytesStart<'static> {
+    let capacity: f64 = raw_attr_str(e, "capacity").parse().unwrap_or(0.0);
+    let amount = if allow_overfill || capacity <= 0.0 {
+        change.amount
+    } else {
+        change.amount.clamp(0.0, capacity)
+    };
+
+    patch_attributes(e, |key| match key {
+        "fillLevel" => Some(format!("{:.6}", amount)),
+        _ => None,
+    })
 }
 
 #[cfg(test)]
@@ -295,6 +307,44 @@ mod tests {
         dst
     }
 
+    fn add_selling_stations(save: &std::path::Path) {
+        let xml_path = save.join("placeables.xml");
+        let stats = r#"<sellingStation>
+      <stats fillType="WHEAT" received="12000.000000" paid="5400.000000" />
+    </sellingStation>
+  </placeable>"#;
+        let content = std::fs::read_to_string(&xml_path)
+            .unwrap()
+            .replace("  </placeable>", &format!("  {}", stats));
+        std::fs::write(&xml_path, content).unwrap();
+    }
+
+    #[test]
+    fn test_clear_selling_station_stats() {
+        use crate::parsers::placeable::parse_selling_stations;
+
+        let save = setup_fixture("selling_stats");
+        add_selling_stations(&save);
+        assert_eq!(parse_selling_stations(&save).unwrap().len(), 4);
+
+        assert_eq!(clear_selling_station_stats(&save, Some(1)).unwrap(), 1);
+        let indices: Vec<usize> = parse_selling_stations(&save)
+            .unwrap()
+            .iter()
+            .map(|s| s.placeable_index)
+            .collect();
+        assert_eq!(indices, vec![0, 2, 3]);
+
+        assert_eq!(clear_selling_station_stats(&save, None).unwrap(), 3);
+        assert!(parse_selling_stations(&save).unwrap().is_empty());
+        // The sections and the rest of the placeables are kept
+        let content = std::fs::read_to_string(save.join("placeables.xml")).unwrap();
+        assert_eq!(content.matches("<sellingStation>").count(), 4);
+        assert_eq!(parse_placeables(&save).unwrap().len(), 4);
+        assert_eq!(clear_selling_station_stats(&save, None).unwrap(), 0);
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
     #[test]
     fn test_write_placeable_owner() {
         let save = setup_fixture("owner");
@@ -305,6 +355,7 @@ mod tests {
             complete_construction: false,
             production_inputs: None,
             production_outputs: None,
+            allow_overfill: false,
         }];
         write_placeable_changes(&save, &changes).unwrap();
         let placeables = parse_placeables(&save).unwrap();
@@ -327,6 +378,7 @@ mod tests {
             complete_construction: true,
             production_inputs: None,
             production_outputs: None,
+            allow_overfill: false,
         }];
         write_placeable_changes(&save, &changes).unwrap();
         let after = parse_placeables(&save).unwrap();
@@ -362,6 +414,7 @@ mod tests {
                 amount: 5000.0,
             }]),
             production_outputs: None,
+            allow_overfill: false,
         }];
         write_placeable_changes(&save, &changes).unwrap();
         let after = parse_placeables(&save).unwrap();
@@ -388,6 +441,7 @@ mod tests {
             complete_construction: false,
             production_inputs: None,
             production_outputs: None,
+            allow_overfill: false,
         }];
         write_placeable_changes(&save, &changes).unwrap();
         let after = parse_placeables(&save).unwrap();
@@ -403,4 +457,47 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    fn overfill_change(index: usize, fill_type: &str, allow_overfill: bool) -> Vec<PlaceableChange> {
+        vec![PlaceableChange {
+            index,
+            farm_id: None,
+            price: None,
+            complete_construction: false,
+            production_inputs: Some(vec![ProductionStockChange {
+                fill_type: fill_type.to_string(),
+                amount: 1e9,
+            }]),
+            production_outputs: None,
+            allow_overfill,
+        }]
+    }
+
+    #[test]
+    fn test_write_placeable_production_stock_clamped_to_capacity() {
+        let save = setup_fixture("overfill_clamped");
+        let before = parse_placeables(&save).unwrap();
+        let prod = before.iter().find(|p| !p.production_inputs.is_empty()).unwrap();
+        let input = prod.production_inputs[0].clone();
+
+        write_placeable_changes(&save, &overfill_change(prod.index, &input.fill_type, false)).unwrap();
+        let after = parse_placeables(&save).unwrap();
+        let stock = &after[prod.index].production_inputs[0];
+        assert!((stock.amount - input.capacity).abs() < 0.01);
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_placeable_production_stock_allow_overfill() {
+        let save = setup_fixture("overfill_allowed");
+        let before = parse_placeables(&save).unwrap();
+        let prod = before.iter().find(|p| !p.production_inputs.is_empty()).unwrap();
+        let fill_type = prod.production_inputs[0].fill_type.clone();
+
+        write_placeable_changes(&save, &overfill_change(prod.index, &fill_type, true)).unwrap();
+        let after = parse_placeables(&save).unwrap();
+        let stock = &after[prod.index].production_inputs[0];
+        assert!((stock.amount - 1e9).abs() < 0.01);
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
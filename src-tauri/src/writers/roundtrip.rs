@@ -0,0 +1,350 @@
+//! Randomized parse → write → parse round-trip over the whole writer surface.
+//!
+//! Each case copies the complete fixture, draws valid changes from a seeded RNG,
+//! applies them through the writers and parses the save again. The parsed data
+//! must equal the original data with exactly those changes applied: changed
+//! fields hold the new values and everything else is untouched.
+
+use std::path::{Path, PathBuf};
+
+use rand::rngs::StdRng;
+use rand::{Rng as _, SeedableRng};
+use serde_json::Value;
+
+use crate::commands::savegame::load_savegame;
+use crate::models::changes::{
+    CollectibleChange, ContractSettingsChange, EconomyChanges, EnvironmentChanges, FarmChange,
+    FarmlandChange, FieldChange, GreatDemandChange, MissionChange, PlaceableChange, SaleChange, VehicleChange,
+};
+use crate::models::farm::FARM_COLORS;
+use crate::models::SavegameData;
+use crate::writers;
+
+const SEEDS: std::ops::RangeInclusive<u64> = 1..=16;
+
+/// Draws from a `StdRng` seeded per case, so failing cases can be replayed from
+/// their seed.
+struct Rng(StdRng);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(StdRng::seed_from_u64(seed))
+    }
+
+    /// Uniform integer in `lo..=hi`.
+    fn range(&mut self, lo: u32, hi: u32) -> u32 {
+        self.0.gen_range(lo..=hi)
+    }
+
+    /// Value in `lo..=hi` with two decimals, which survives the writers' `{:.6}` formatting.
+    fn hundredths(&mut self, lo: u32, hi: u32) -> f64 {
+        self.range(lo * 100, hi * 100) as f64 / 100.0
+    }
+
+    fn chance(&mut self) -> bool {
+        self.0.gen_bool(0.5)
+    }
+
+    /// `Some` value drawn by `draw` half of the time, `None` otherwise.
+    fn maybe<T>(&mut self, draw: impl FnOnce(&mut Self) -> T) -> Option<T> {
+        if self.chance() {
+            Some(draw(self))
+        } else {
+            None
+        }
+    }
+
+    fn pick(&mut self, len: usize) -> usize {
+        self.0.gen_range(0..len)
+    }
+}
+
+fn setup_fixture(seed: u64) -> PathBuf {
+    let src = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("savegame_complete");
+    let dst = std::env::temp_dir().join(format!("fs25_test_roundtrip_{}", seed));
+    let _ = std::fs::remove_dir_all(&dst);
+    std::fs::create_dir_all(&dst).unwrap();
+    for entry in std::fs::read_dir(&src).unwrap() {
+        let entry = entry.unwrap();
+        if entry.file_type().unwrap().is_file() {
+            std::fs::copy(entry.path(), dst.join(entry.file_name())).unwrap();
+        }
+    }
+    dst
+}
+
+fn load(path: &Path) -> SavegameData {
+    load_savegame(path.display().to_string()).unwrap()
+}
+
+fn farm_finances(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.farms.len());
+    let farm = &mut expected.farms[index];
+    let money = rng.maybe(|r| r.range(0, 5_000_000) as f64);
+    let loan = rng.maybe(|r| r.range(0, 500_000) as f64);
+    writers::farm::write_farm_finances(save, farm.farm_id, money, loan, None).unwrap();
+    farm.money = money.unwrap_or(farm.money);
+    farm.loan = loan.unwrap_or(farm.loan);
+}
+
+fn farm_color(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.farms.len());
+    let farm = &mut expected.farms[index];
+    let color = rng.range(*FARM_COLORS.start() as u32, *FARM_COLORS.end() as u32) as u8;
+    let change = FarmChange {
+        farm_id: farm.farm_id,
+        color: Some(color),
+    };
+    writers::farm::write_farm_changes(save, std::slice::from_ref(&change)).unwrap();
+    farm.color = color;
+}
+
+fn vehicle(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.vehicles.len());
+    let vehicle = &mut expected.vehicles[index];
+    let change = VehicleChange {
+        unique_id: vehicle.unique_id.clone(),
+        age: rng.maybe(|r| r.range(0, 240) as f64),
+        price: rng.maybe(|r| r.range(1_000, 800_000) as f64),
+        operating_time: rng.maybe(|r| r.hundredths(0, 5_000)),
+        damage: rng.maybe(|r| r.hundredths(0, 1)),
+        ..Default::default()
+    };
+    writers::vehicle::write_vehicle_changes(save, std::slice::from_ref(&change)).unwrap();
+    vehicle.age = change.age.unwrap_or(vehicle.age);
+    vehicle.price = change.price.unwrap_or(vehicle.price);
+    vehicle.operating_time = change.operating_time.unwrap_or(vehicle.operating_time);
+    vehicle.damage = change.damage.unwrap_or(vehicle.damage);
+}
+
+fn sale(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.sales.len());
+    let item = &mut expected.sales[index];
+    let change = SaleChange {
+        index: item.index,
+        delete: false,
+        price: rng.maybe(|r| r.range(1_000, 500_000)),
+        damage: rng.maybe(|r| r.hundredths(0, 1)),
+        wear: rng.maybe(|r| r.hundredths(0, 1)),
+        age: rng.maybe(|r| r.range(0, 120)),
+        operating_time: rng.maybe(|r| r.hundredths(0, 1_000)),
+        time_left: rng.maybe(|r| r.range(1, 60)),
+    };
+    writers::sale::write_sale_changes(save, std::slice::from_ref(&change)).unwrap();
+    item.price = change.price.unwrap_or(item.price);
+    item.damage = change.damage.unwrap_or(item.damage);
+    item.wear = change.wear.unwrap_or(item.wear);
+    item.age = change.age.unwrap_or(item.age);
+    item.operating_time = change.operating_time.unwrap_or(item.operating_time);
+    item.time_left = change.time_left.unwrap_or(item.time_left);
+}
+
+fn field(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.fields.len());
+    let field = &mut expected.fields[index];
+    let change = FieldChange {
+        id: field.id,
+        fruit_type: None,
+        planned_fruit: None,
+        growth_state: rng.maybe(|r| r.range(0, 10) as u8),
+        ground_type: None,
+        weed_state: rng.maybe(|r| r.range(0, 9) as u8),
+        stone_level: rng.maybe(|r| r.range(0, 3) as u8),
+        spray_level: rng.maybe(|r| r.range(0, 2) as u8),
+        spray_type: None,
+        lime_level: rng.maybe(|r| r.range(0, 3) as u8),
+        plow_level: rng.maybe(|r| r.range(0, 1) as u8),
+        roller_level: rng.maybe(|r| r.range(0, 1) as u8),
+        stubble_shred_level: rng.maybe(|r| r.range(0, 1) as u8),
+        water_level: None,
+    };
+    writers::field::write_field_changes(save, std::slice::from_ref(&change)).unwrap();
+    field.growth_state = change.growth_state.unwrap_or(field.growth_state);
+    field.weed_state = change.weed_state.unwrap_or(field.weed_state);
+    field.stone_level = change.stone_level.unwrap_or(field.stone_level);
+    field.spray_level = change.spray_level.unwrap_or(field.spray_level);
+    field.lime_level = change.lime_level.unwrap_or(field.lime_level);
+    field.plow_level = change.plow_level.unwrap_or(field.plow_level);
+    field.roller_level = change.roller_level.unwrap_or(field.roller_level);
+    field.stubble_shred_level = change
+        .stubble_shred_level
+        .unwrap_or(field.stubble_shred_level);
+}
+
+fn farmland(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.farmlands.len());
+    let farmland = &mut expected.farmlands[index];
+    let change = FarmlandChange {
+        id: farmland.id,
+        farm_id: rng.range(0, 1) as u8,
+    };
+    writers::field::write_farmland_changes(save, std::slice::from_ref(&change)).unwrap();
+    farmland.farm_id = change.farm_id;
+}
+
+fn placeable(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.placeables.len());
+    let placeable = &mut expected.placeables[index];
+    let change = PlaceableChange {
+        index: placeable.index,
+        farm_id: None,
+        price: Some(rng.range(1_000, 900_000) as f64),
+        complete_construction: false,
+        production_inputs: None,
+        production_outputs: None,
+        allow_overfill: false,
+    };
+    writers::placeable::write_placeable_changes(save, std::slice::from_ref(&change)).unwrap();
+    placeable.price = change.price.unwrap();
+}
+
+fn mission(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.missions.len());
+    let mission = &mut expected.missions[index];
+    let change = MissionChange {
+        unique_id: mission.unique_id.clone(),
+        reward: rng.maybe(|r| r.range(100, 50_000) as f64),
+        completion: rng.maybe(|r| r.hundredths(0, 1)),
+        status: None,
+        reimbursement: rng.maybe(|r| r.range(0, 5_000) as f64),
+        deposited_liters: None,
+    };
+    writers::mission::write_mission_changes(save, std::slice::from_ref(&change)).unwrap();
+    mission.reward = change.reward.unwrap_or(mission.reward);
+    mission.completion = change.completion.unwrap_or(mission.completion);
+    mission.reimbursement = change.reimbursement.unwrap_or(mission.reimbursement);
+}
+
+fn collectible(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let index = rng.pick(expected.collectibles.len());
+    let collectible = &mut expected.collectibles[index];
+    let change = CollectibleChange {
+        index: collectible.index,
+        collected: rng.chance(),
+        marked: None,
+    };
+    writers::collectible::write_collectible_changes(save, std::slice::from_ref(&change)).unwrap();
+    collectible.collected = change.collected;
+}
+
+fn contract_settings(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let settings = expected.contract_settings.as_mut().unwrap();
+    let change = ContractSettingsChange {
+        lease_vehicle: rng.maybe(|r| r.hundredths(0, 2)),
+        mission_per_farm: rng.maybe(|r| r.hundredths(0, 2)),
+        allow_clear_add: rng.maybe(|r| r.hundredths(0, 2)),
+    };
+    writers::contract::write_contract_settings(save, &change).unwrap();
+    settings.lease_vehicle = change.lease_vehicle.unwrap_or(settings.lease_vehicle);
+    settings.mission_per_farm = change.mission_per_farm.unwrap_or(settings.mission_per_farm);
+    settings.allow_clear_add = change.allow_clear_add.unwrap_or(settings.allow_clear_add);
+}
+
+fn environment(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let environment = expected.environment.as_mut().unwrap();
+    let change = EnvironmentChanges {
+        // Seconds since midnight, as set_time_of_day writes it
+        day_time: rng.maybe(|r| r.range(0, 86_399) as f64),
+        current_day: rng.maybe(|r| r.range(1, 200)),
+        snow_height: None,
+        ground_wetness: None,
+        weather_forecast: None,
+    };
+    writers::environment::write_environment_changes(save, &change).unwrap();
+    environment.day_time = change.day_time.unwrap_or(environment.day_time);
+    environment.current_day = change.current_day.unwrap_or(environment.current_day);
+}
+
+fn great_demand(rng: &mut Rng, save: &Path, expected: &mut SavegameData) {
+    let economy = expected.economy.as_mut().unwrap();
+    let index = rng.pick(economy.great_demands.len());
+    let demand = &mut economy.great_demands[index];
+    let change = GreatDemandChange {
+        index: demand.index,
+        unique_id: rng.maybe(|_| demand.unique_id.clone()),
+        fill_type_name: None,
+        demand_multiplier: rng.maybe(|r| r.hundredths(1, 3)),
+        demand_start_day: rng.maybe(|r| r.range(1, 120)),
+        demand_start_hour: rng.maybe(|r| r.range(0, 23)),
+        demand_duration: rng.maybe(|r| r.range(1, 48)),
+        is_running: rng.maybe(Rng::chance),
+        is_valid: None,
+    };
+    let changes = EconomyChanges {
+        great_demand_changes: Some(vec![change.clone()]),
+        great_demand_additions: None,
+        great_demand_deletions: None,
+    };
+    writers::economy::write_economy_changes(save, &changes).unwrap();
+    demand.demand_multiplier = change.demand_multiplier.unwrap_or(demand.demand_multiplier);
+    demand.demand_start_day = change.demand_start_day.unwrap_or(demand.demand_start_day);
+    demand.demand_start_hour = change.demand_start_hour.unwrap_or(demand.demand_start_hour);
+    demand.demand_duration = change.demand_duration.unwrap_or(demand.demand_duration);
+    demand.is_running = change.is_running.unwrap_or(demand.is_running);
+}
+
+/// JSON path of the first value that differs between `a` and `b`.
+fn first_difference(a: &Value, b: &Value, path: &str) -> Option<String> {
+    match (a, b) {
+        (Value::Object(x), Value::Object(y)) => x.iter().find_map(|(k, v)| {
+            first_difference(
+                v,
+                y.get(k).unwrap_or(&Value::Null),
+                &format!("{}.{}", path, k),
+            )
+        }),
+        (Value::Array(x), Value::Array(y)) if x.len() == y.len() => x
+            .iter()
+            .zip(y)
+            .enumerate()
+            .find_map(|(i, (v, w))| first_difference(v, w, &format!("{}[{}]", path, i))),
+        _ if a == b => None,
+        _ => Some(format!("{}: expected {}, got {}", path, a, b)),
+    }
+}
+
+#[test]
+fn test_random_changes_roundtrip() {
+    let edits: [fn(&mut Rng, &Path, &mut SavegameData); 12] = [
+        farm_finances,
+        farm_color,
+        vehicle,
+        sale,
+        field,
+        farmland,
+        placeable,
+        mission,
+        collectible,
+        contract_settings,
+        environment,
+        great_demand,
+    ];
+
+    for seed in SEEDS {
+        let mut rng = Rng::new(seed);
+        let save = setup_fixture(seed);
+        let mut expected = load(&save);
+
+        // Several edits per case, so some stack on the same file
+        for _ in 0..8 {
+            let edit = edits[rng.pick(edits.len())];
+            edit(&mut rng, &save, &mut expected);
+        }
+
+        let mut actual = load(&save);
+        // Diagnostics depend on the values drawn, not on what the writers preserve
+        actual.warnings.clear();
+        expected.warnings.clear();
+
+        let expected = serde_json::to_value(&expected).unwrap();
+        let actual = serde_json::to_value(&actual).unwrap();
+        if let Some(difference) = first_difference(&expected, &actual, "data") {
+            panic!("seed {}: {}", seed, difference);
+        }
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+}
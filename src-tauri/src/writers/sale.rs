@@ -1,21 +1,25 @@
+use std::collections::HashSet;
 use std::path::Path;
 
+use quick_xml::escape::escape;
 use quick_xml::events::{BytesStart, Event};
 use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
 use crate::models::changes::{SaleAddition, SaleChange};
+use crate::parsers::common::{attr_str, encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, SALES};
+use crate::writers::common::{atomic_write, patch_attributes, write_event, xml_error};
 
 /// Applies sale changes to sales.xml.
 /// Items are identified by their position index (0-based count of <item> elements).
+/// Returns the indexes that matched an item.
 pub fn write_sale_changes(
     path: &Path,
     changes: &[SaleChange],
-) -> Result<(), AppError> {
-    let xml_path = path.join("sales.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+) -> Result<HashSet<usize>, AppError> {
+    let xml_path = savegame_file(path, SALES);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     // Build lookup map by index
     let change_map: std::collections::HashMap<usize, &SaleChange> = changes
@@ -29,6 +33,7 @@ pub fn write_sale_changes(
     let mut item_index: usize = 0;
     let mut skip_until_item_end = false;
     let mut skip_depth: u32 = 0;
+    let mut matched: HashSet<usize> = HashSet::new();
 
     loop {
         match reader.read_event() {
@@ -42,6 +47,7 @@ pub fn write_sale_changes(
 
                 if tag == "item" {
                     if let Some(change) = change_map.get(&item_index) {
+                        matched.insert(item_index);
                         item_index += 1;
                         if change.delete {
                             skip_until_item_end = true;
@@ -81,73 +87,143 @@ pub fn write_sale_changes(
                     write_event(&mut writer, &xml_path, event.into_owned())?;
                 }
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
-    Ok(())
+    Ok(matched)
 }
 
 fn patch_item_start(e: &BytesStart, change: &SaleChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("item");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "price" if change.price.is_some() => {
-                elem.push_attribute(("price", change.price.unwrap().to_string().as_str()));
-            }
-            "damage" if change.damage.is_some() => {
-                elem.push_attribute(("damage", format!("{:.6}", change.damage.unwrap()).as_str()));
-            }
-            "wear" if change.wear.is_some() => {
-                elem.push_attribute(("wear", format!("{:.6}", change.wear.unwrap()).as_str()));
-            }
-            "age" if change.age.is_some() => {
-                elem.push_attribute(("age", change.age.unwrap().to_string().as_str()));
-            }
-            "operatingTime" if change.operating_time.is_some() => {
-                elem.push_attribute((
-                    "operatingTime",
-                    format!("{:.6}", change.operating_time.unwrap() * 3600.0).as_str(),
-                ));
-            }
-            "timeLeft" if change.time_left.is_some() => {
-                elem.push_attribute(("timeLeft", change.time_left.unwrap().to_string().as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
+    patch_attributes(e, |key| match key {
+        "price" => change.price.map(|v| v.to_string()),
+        "damage" => change.damage.map(|v| format!("{:.6}", v)),
+        "wear" => change.wear.map(|v| format!("{:.6}", v)),
+        "age" => change.age.map(|v| v.to_string()),
+        "operatingTime" => change.operating_time.map(|v| format!("{:.6}", v * 3600.0)),
+        "timeLeft" => change.time_left.map(|v| v.to_string()),
+        _ => None,
+    })
+}
+
+/// Values of a sale item that make two items identical.
+#[derive(Debug, PartialEq)]
+struct SaleItemKey {
+    xml_filename: String,
+    price: u32,
+    age: u32,
+    damage: f64,
+    wear: f64,
+    operating_time: f64,
+    time_left: u32,
+    configurations: Vec<(String, String)>,
+}
+
+impl SaleItemKey {
+    fn from_addition(addition: &SaleAddition) -> Self {
+        SaleItemKey {
+            xml_filename: addition.xml_filename.clone(),
+            price: addition.price,
+            age: addition.age,
+            damage: addition.damage,
+            wear: addition.wear,
+            operating_time: addition.operating_time * 3600.0,
+            time_left: addition.time_left,
+            configurations: addition
+                .bought_configurations
+                .iter()
+                .flatten()
+                .map(|c| (c.name.clone(), c.id.clone()))
+                .collect(),
+        }
+    }
+
+    fn from_item(e: &BytesStart) -> Self {
+        SaleItemKey {
+            xml_filename: attr_str(e, "xmlFilename"),
+            price: attr_str(e, "price").parse().unwrap_or(0),
+            age: attr_str(e, "age").parse().unwrap_or(0),
+            damage: attr_str(e, "damage").parse().unwrap_or(0.0),
+            wear: attr_str(e, "wear").parse().unwrap_or(0.0),
+            operating_time: attr_str(e, "operatingTime").parse().unwrap_or(0.0),
+            time_left: attr_str(e, "timeLeft").parse().unwrap_or(0),
+            configurations: Vec::new(),
+        }
+    }
+
+    /// Floats are compared at the precision they are written with.
+    fn matches(&self, other: &SaleItemKey) -> bool {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-5;
+        self.xml_filename == other.xml_filename
+            && self.price == other.price
+            && self.age == other.age
+            && close(self.damage, other.damage)
+            && close(self.wear, other.wear)
+            && close(self.operating_time, other.operating_time)
+            && self.time_left == other.time_left
+            && self.configurations == other.configurations
+    }
+}
+
+/// Keys of the items already listed in sales.xml.
+fn existing_item_keys(content: &str, xml_path: &Path) -> Result<Vec<SaleItemKey>, AppError> {
+    let mut reader = Reader::from_str(content);
+    let mut keys: Vec<SaleItemKey> = Vec::new();
+    let mut in_item = false;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"item" => {
+                keys.push(SaleItemKey::from_item(e));
+                in_item = true;
             }
+            Ok(Event::Empty(ref e)) => match e.name().as_ref() {
+                b"item" => keys.push(SaleItemKey::from_item(e)),
+                b"boughtConfiguration" if in_item => {
+                    if let Some(key) = keys.last_mut() {
+                        key.configurations.push((attr_str(e, "name"), attr_str(e, "id")));
+                    }
+                }
+                _ => {}
+            },
+            Ok(Event::End(ref e)) if e.name().as_ref() == b"item" => in_item = false,
+            Ok(Event::Eof) => break,
+            Ok(_) => {}
+            Err(e) => return Err(xml_error(xml_path, e)),
         }
     }
-    elem
+
+    Ok(keys)
 }
 
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer.write_event(event).map_err(|e| AppError::XmlParseError {
-        file: xml_path.display().to_string(),
-        message: e.to_string(),
-    })
+/// Drop the additions identical to an item already listed, so running the same
+/// additions again does not list them twice. Each listed item absorbs one addition.
+fn skip_listed_additions(
+    additions: &[SaleAddition],
+    mut listed: Vec<SaleItemKey>,
+) -> Vec<&SaleAddition> {
+    additions
+        .iter()
+        .filter(|addition| {
+            let key = SaleItemKey::from_addition(addition);
+            match listed.iter().position(|item| item.matches(&key)) {
+                Some(pos) => {
+                    listed.swap_remove(pos);
+                    false
+                }
+                None => true,
+            }
+        })
+        .collect()
 }
 
 /// Adds new sale items to sales.xml.
 /// If the file doesn't exist, creates it from scratch.
+/// Additions identical to an item already listed are skipped, which makes
+/// re-running the same additions a no-op.
 pub fn write_sale_additions(
     path: &Path,
     additions: &[SaleAddition],
@@ -156,7 +232,7 @@ pub fn write_sale_additions(
         return Ok(());
     }
 
-    let xml_path = path.join("sales.xml");
+    let xml_path = savegame_file(path, SALES);
 
     if !xml_path.exists() {
         // Create sales.xml from scratch
@@ -165,19 +241,22 @@ pub fn write_sale_additions(
             xml.push_str(&format_sale_item(addition));
         }
         xml.push_str("</sales>\n");
-        std::fs::write(&xml_path, &xml)?;
+        atomic_write(&xml_path, xml.as_bytes())?;
         return Ok(());
     }
 
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    let additions = skip_listed_additions(additions, existing_item_keys(&content, &xml_path)?);
+    if additions.is_empty() {
+        return Ok(());
+    }
 
     let mut result = String::with_capacity(content.len() + additions.len() * 200);
 
     if let Some(insert_pos) = content.rfind("</sales>") {
         result.push_str(&content[..insert_pos]);
-        for addition in additions {
+        for addition in &additions {
             result.push_str(&format_sale_item(addition));
         }
         result.push_str(&content[insert_pos..]);
@@ -185,41 +264,53 @@ pub fn write_sale_additions(
         // Empty savegame: <sales/> self-closing → expand to <sales>...</sales>
         result.push_str(&content[..self_close_pos]);
         result.push_str("<sales>\n");
-        for addition in additions {
+        for addition in &additions {
             result.push_str(&format_sale_item(addition));
         }
         result.push_str("</sales>");
         result.push_str(&content[self_close_pos + "<sales/>".len()..]);
     } else {
-        return Err(AppError::XmlParseError {
-            file: xml_path.display().to_string(),
-            message: "Missing <sales> root element".to_string(),
-        });
+        return Err(xml_error(&xml_path, "Missing <sales> root element"));
     }
 
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &result)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
+    atomic_write(&xml_path, &encode_xml(result.as_bytes(), encoding))?;
 
     Ok(())
 }
 
 fn format_sale_item(addition: &SaleAddition) -> String {
-    format!(
-        "    <item xmlFilename=\"{}\" age=\"{}\" price=\"{}\" damage=\"{:.6}\" wear=\"{:.6}\" operatingTime=\"{:.6}\" timeLeft=\"{}\" isGenerated=\"false\"/>\n",
-        addition.xml_filename,
+    let attributes = format!(
+        "xmlFilename=\"{}\" age=\"{}\" price=\"{}\" damage=\"{:.6}\" wear=\"{:.6}\" operatingTime=\"{:.6}\" timeLeft=\"{}\" isGenerated=\"false\"",
+        escape(addition.xml_filename.as_str()),
         addition.age,
         addition.price,
         addition.damage,
         addition.wear,
         addition.operating_time * 3600.0, // hours → seconds
         addition.time_left,
-    )
+    );
+
+    match addition.bought_configurations {
+        Some(ref configs) if !configs.is_empty() => {
+            let mut item = format!("    <item {}>\n      <boughtConfigurations>\n", attributes);
+            for config in configs {
+                item.push_str(&format!(
+                    "        <boughtConfiguration name=\"{}\" id=\"{}\" />\n",
+                    escape(config.name.as_str()),
+                    escape(config.id.as_str())
+                ));
+            }
+            item.push_str("      </boughtConfigurations>\n    </item>\n");
+            item
+        }
+        _ => format!("    <item {}/>\n", attributes),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::sale::BoughtConfiguration;
     use crate::parsers::sale::parse_sales;
 
     fn setup_fixture(name: &str) -> std::path::PathBuf {
@@ -365,6 +456,7 @@ mod tests {
             age: 0,
             operating_time: 0.0,
             time_left: 30,
+            bought_configurations: None,
         }];
         write_sale_additions(&save, &additions).unwrap();
         let sales = parse_sales(&save).unwrap();
@@ -397,6 +489,7 @@ mod tests {
             age: 10,
             operating_time: 120.0, // minutes
             time_left: 15,
+            bought_configurations: None,
         }];
         write_sale_additions(&save, &additions).unwrap();
         let sales = parse_sales(&save).unwrap();
@@ -440,6 +533,7 @@ mod tests {
             age: 0,
             operating_time: 0.0,
             time_left: 30,
+            bought_configurations: None,
         }];
         write_sale_additions(&save, &additions).unwrap();
 
@@ -472,6 +566,7 @@ mod tests {
             age: 0,
             operating_time: 0.0,
             time_left: 30,
+            bought_configurations: None,
         }];
         write_sale_additions(&save, &additions).unwrap();
 
@@ -495,6 +590,7 @@ mod tests {
             age: 0,
             operating_time: 0.0,
             time_left: 30,
+            bought_configurations: None,
         }];
         write_sale_additions(&save, &additions).unwrap();
         let after = parse_sales(&save).unwrap();
@@ -509,4 +605,109 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    #[test]
+    fn test_write_sale_add_with_configurations() {
+        let save = setup_fixture("sale_add_configs");
+        let before_count = parse_sales(&save).unwrap().len();
+
+        let additions = vec![SaleAddition {
+            xml_filename: "data/vehicles/fendt/vario900/vario900.xml".to_string(),
+            price: 180000,
+            damage: 0.2,
+            wear: 0.3,
+            age: 24,
+            operating_time: 150.0,
+            time_left: 10,
+            bought_configurations: Some(vec![
+                BoughtConfiguration {
+                    name: "motor".to_string(),
+                    id: "3".to_string(),
+                },
+                BoughtConfiguration {
+                    name: "wheel".to_string(),
+                    id: "2".to_string(),
+                },
+            ]),
+        }];
+        write_sale_additions(&save, &additions).unwrap();
+        let sales = parse_sales(&save).unwrap();
+
+        assert_eq!(sales.len(), before_count + 1);
+        let added = &sales[before_count];
+        assert_eq!(added.price, 180000);
+        assert_eq!(added.bought_configurations.len(), 2);
+        assert_eq!(added.bought_configurations[0].name, "motor");
+        assert_eq!(added.bought_configurations[0].id, "3");
+        assert_eq!(added.bought_configurations[1].name, "wheel");
+        assert_eq!(added.bought_configurations[1].id, "2");
+        // Existing items keep their configurations
+        assert_eq!(sales[1].bought_configurations.len(), 2);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_sale_add_escapes_text() {
+        let save = setup_fixture("sale_add_escape");
+        let before_count = parse_sales(&save).unwrap().len();
+
+        let additions = vec![SaleAddition {
+            xml_filename: "$moddir$FS25_A&B/tractor.xml".to_string(),
+            price: 1000,
+            damage: 0.0,
+            wear: 0.0,
+            age: 1,
+            operating_time: 1.0,
+            time_left: 5,
+            bought_configurations: Some(vec![BoughtConfiguration {
+                name: "design<\"color\">".to_string(),
+                id: "1&2".to_string(),
+            }]),
+        }];
+        write_sale_additions(&save, &additions).unwrap();
+        let sales = parse_sales(&save).unwrap();
+
+        assert_eq!(sales.len(), before_count + 1);
+        let added = &sales[before_count];
+        assert_eq!(added.xml_filename, "$moddir$FS25_A&B/tractor.xml");
+        assert_eq!(added.bought_configurations[0].name, "design<\"color\">");
+        assert_eq!(added.bought_configurations[0].id, "1&2");
+        // Matched against its unescaped attributes: not added twice
+        write_sale_additions(&save, &additions).unwrap();
+        assert_eq!(parse_sales(&save).unwrap().len(), before_count + 1);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_sale_add_twice_adds_once() {
+        let save = setup_fixture("sale_add_twice");
+        let before_count = parse_sales(&save).unwrap().len();
+
+        let additions = vec![SaleAddition {
+            xml_filename: "data/vehicles/fendt/vario900/vario900.xml".to_string(),
+            price: 250000,
+            damage: 0.1,
+            wear: 0.2,
+            age: 3,
+            operating_time: 12.5,
+            time_left: 30,
+            bought_configurations: Some(vec![BoughtConfiguration {
+                name: "motor".to_string(),
+                id: "2".to_string(),
+            }]),
+        }];
+        write_sale_additions(&save, &additions).unwrap();
+        write_sale_additions(&save, &additions).unwrap();
+        assert_eq!(parse_sales(&save).unwrap().len(), before_count + 1);
+
+        // A different configuration is a different item
+        let mut other = additions.clone();
+        other[0].bought_configurations = None;
+        write_sale_additions(&save, &other).unwrap();
+        assert_eq!(parse_sales(&save).unwrap().len(), before_count + 2);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
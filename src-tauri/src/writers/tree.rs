@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::error::AppError;
+use crate::models::changes::TreeChanges;
+use crate::models::tree::tree_max_growth_stage;
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, TREE_PLANT};
+use crate::parsers::tree::GROWTH_STAGE_ATTRS;
+use crate::writers::common::{atomic_write, raw_attr_str, patch_attributes, write_event, xml_error};
+
+/// Set selected (or all) planted trees in treePlant.xml to their mature stage.
+/// Trees of unknown types, or already at or beyond their max stage, are left untouched.
+pub fn write_tree_changes(path: &Path, changes: &TreeChanges) -> Result<(), AppError> {
+    let xml_path = savegame_file(path, TREE_PLANT);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
+
+    let selected: Option<HashSet<usize>> = changes
+        .mature_indices
+        .as_ref()
+        .map(|indices| indices.iter().copied().collect());
+
+    let mut reader = Reader::from_str(&content);
+    let mut writer = Writer::new(Vec::new());
+    let mut tree_index: usize = 0;
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(ref e)) if e.name().as_ref() == b"tree" => {
+                let elem = patch_tree(e, tree_index, selected.as_ref());
+                tree_index += 1;
+                write_event(&mut writer, &xml_path, Event::Start(elem))?;
+            }
+            Ok(Event::Empty(ref e)) if e.name().as_ref() == b"tree" => {
+                let elem = patch_tree(e, tree_index, selected.as_ref());
+                tree_index += 1;
+                write_event(&mut writer, &xml_path, Event::Empty(elem))?;
+            }
+            Ok(Event::Eof) => break,
+            Ok(event) => {
+                write_event(&mut writer, &xml_path, event.into_owned())?;
+            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
+        }
+    }
+
+    let output = writer.into_inner();
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
+
+    Ok(())
+}
+
+fn patch_tree(
+    e: &BytesStart,
+    index: usize,
+    selected: Option<&HashSet<usize>>,
+) -> BytesStart<'static> {
+    let is_selected = selected.map(|s| s.contains(&index)).unwrap_or(true);
+    let max_stage = tree_max_growth_stage(&raw_attr_str(e, "treeType"));
+    let stage_attr = GROWTH_STAGE_ATTRS
+        .iter()
+        .find(|key| !raw_attr_str(e, key).is_empty());
+
+    let (max_stage, stage_attr) = match (is_selected, max_stage, stage_attr) {
+        (true, Some(max), Some(attr)) => (max, *attr),
+        _ => return e.clone().into_owned(),
+    };
+    let current: u8 = raw_attr_str(e, stage_attr).parse().unwrap_or(0);
+    if current >= max_stage {
+        return e.clone().into_owned();
+    }
+
+    patch_attributes(e, |key| {
+        if key == stage_attr {
+            Some(max_stage.to_string())
+        } else if key == "isGrowing" {
+            Some("false".to_string())
+        } else {
+            None
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::tree::parse_trees;
+
+    fn setup_fixture(name: &str) -> std::path::PathBuf {
+        let src = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("savegame_complete");
+        let dst = std::env::temp_dir().join(format!("fs25_test_wt_{}", name));
+        let _ = std::fs::remove_dir_all(&dst);
+        std::fs::create_dir_all(&dst).unwrap();
+        for entry in std::fs::read_dir(&src).unwrap() {
+            let entry = entry.unwrap();
+            if entry.file_type().unwrap().is_file() {
+                std::fs::copy(entry.path(), dst.join(entry.file_name())).unwrap();
+            }
+        }
+        dst
+    }
+
+    #[test]
+    fn test_write_tree_single_roundtrip() {
+        let save = setup_fixture("single");
+        let before = parse_trees(&save).unwrap();
+        assert_eq!(before[0].growth_stage, 2);
+
+        let changes = TreeChanges {
+            mature_indices: Some(vec![0]),
+        };
+        write_tree_changes(&save, &changes).unwrap();
+        let after = parse_trees(&save).unwrap();
+
+        assert_eq!(after[0].growth_stage, 7);
+        assert!(!after[0].is_growing);
+        // Position preserved
+        let (p0, p1) = (
+            before[0].position.as_ref().unwrap(),
+            after[0].position.as_ref().unwrap(),
+        );
+        assert!((p0.x - p1.x).abs() < 0.001 && (p0.z - p1.z).abs() < 0.001);
+        // Unselected trees untouched
+        assert_eq!(after[2].growth_stage, before[2].growth_stage);
+        assert!(after[2].is_growing);
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_tree_all_respects_max_stage() {
+        let save = setup_fixture("all");
+        let changes = TreeChanges {
+            mature_indices: None,
+        };
+        write_tree_changes(&save, &changes).unwrap();
+        let after = parse_trees(&save).unwrap();
+
+        assert_eq!(after[0].growth_stage, 7);
+        assert_eq!(after[1].growth_stage, 7);
+        assert_eq!(after[2].growth_stage, 5);
+        assert_eq!(after[3].growth_stage, 5);
+        assert!(after
+            .iter()
+            .all(|t| t.growth_stage <= tree_max_growth_stage(&t.tree_type).unwrap()));
+
+        let content = std::fs::read_to_string(save.join("treePlant.xml")).unwrap();
+        assert!(content.contains(r#"splitShapeFileId="5""#));
+
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_tree_unknown_type_untouched() {
+        let dir = std::env::temp_dir().join("fs25_test_wt_unknown");
+        let _ = std::fs::create_dir_all(&dir);
+        std::fs::write(
+            dir.join("treePlant.xml"),
+            r#"<treePlant><tree treeType="MOD_PALM" growthStateI="1" isGrowing="true"/></treePlant>"#,
+        )
+        .unwrap();
+        write_tree_changes(
+            &dir,
+            &TreeChanges {
+                mature_indices: None,
+            },
+        )
+        .unwrap();
+        let after = parse_trees(&dir).unwrap();
+        assert_eq!(after[0].growth_stage, 1);
+        assert!(after[0].is_growing);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
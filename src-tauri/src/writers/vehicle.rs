@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::path::Path;
 
 use quick_xml::events::{BytesStart, Event};
@@ -5,23 +6,41 @@ use quick_xml::{Reader, Writer};
 
 use crate::error::AppError;
 use crate::models::changes::{VehicleChange, FillUnitChange};
+use crate::models::vehicle::{build_attachment_tree, AttachmentNode};
+use crate::parsers::common::{encode_xml, read_xml_file_with_encoding};
+use crate::parsers::files::{savegame_file, VEHICLES};
+use crate::parsers::vehicle::parse_vehicles_xml;
+use crate::writers::common::{atomic_write, raw_attr_str, patch_attributes, write_event, xml_error};
 
 /// Applies a list of vehicle changes to vehicles.xml.
 /// Patch strategy: reads original XML, modifies only targeted attributes, rewrites atomically.
+/// Returns the uniqueIds of the changes that matched a vehicle.
 pub fn write_vehicle_changes(
     path: &Path,
     changes: &[VehicleChange],
-) -> Result<(), AppError> {
-    let xml_path = path.join("vehicles.xml");
-    let content = std::fs::read_to_string(&xml_path).map_err(|e| AppError::IoError {
-        message: format!("{}: {}", xml_path.display(), e),
-    })?;
+) -> Result<HashSet<String>, AppError> {
+    let xml_path = savegame_file(path, VEHICLES);
+    let (content, encoding) = read_xml_file_with_encoding(&xml_path)?;
 
     // Build a lookup map for quick access
     let change_map: std::collections::HashMap<&str, &VehicleChange> = changes
         .iter()
         .map(|c| (c.unique_id.as_str(), c))
         .collect();
+    // Attachments pointing at a deleted vehicle are dropped with it
+    let mut deleted_ids: std::collections::HashSet<String> = changes
+        .iter()
+        .filter(|c| c.delete)
+        .map(|c| c.unique_id.clone())
+        .collect();
+    if changes.iter().any(|c| c.delete && c.cascade) {
+        let tree = build_attachment_tree(&parse_vehicles_xml(&content, &xml_path)?);
+        for change in changes.iter().filter(|c| c.delete && c.cascade) {
+            if let Some(node) = AttachmentNode::find(&tree, &change.unique_id) {
+                deleted_ids.extend(node.unique_ids().into_iter().map(str::to_string));
+            }
+        }
+    }
 
     let mut reader = Reader::from_str(&content);
     let mut writer = Writer::new(Vec::new());
@@ -32,6 +51,10 @@ pub fn write_vehicle_changes(
     let mut skip_depth: u32 = 0;
     let mut in_fill_unit = false;
     let mut current_fill_changes: Option<&Vec<FillUnitChange>> = None;
+    let mut stop_worker = false;
+    let mut in_ai_job = false;
+    let mut skip_job_depth: u32 = 0;
+    let mut matched: HashSet<String> = HashSet::new();
 
     loop {
         match reader.read_event() {
@@ -42,28 +65,44 @@ pub fn write_vehicle_changes(
                     skip_depth += 1;
                     continue;
                 }
+                if skip_job_depth > 0 {
+                    skip_job_depth += 1;
+                    continue;
+                }
 
                 match tag.as_str() {
                     "vehicle" => {
-                        let id = attr_str(e, "uniqueId");
+                        let id = raw_attr_str(e, "uniqueId");
+                        if change_map.contains_key(id.as_str()) {
+                            matched.insert(id.clone());
+                        }
+                        if deleted_ids.contains(&id) {
+                            skip_until_vehicle_end = true;
+                            skip_depth = 1;
+                            current_vehicle_id = Some(id);
+                            continue;
+                        }
                         if let Some(change) = change_map.get(id.as_str()) {
-                            if change.delete {
-                                skip_until_vehicle_end = true;
-                                skip_depth = 1;
-                                current_vehicle_id = Some(id);
-                                continue;
-                            }
                             // Modify vehicle attributes
                             let elem = patch_vehicle_start(e, change);
                             current_vehicle_id = Some(id);
                             current_fill_changes = change.fill_units.as_ref();
+                            stop_worker = change.stop_worker;
                             write_event(&mut writer, &xml_path, Event::Start(elem))?;
                         } else {
                             current_vehicle_id = None;
                             current_fill_changes = None;
+                            stop_worker = false;
                             write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
                         }
                     }
+                    "aiJobVehicle" if current_vehicle_id.is_some() => {
+                        in_ai_job = true;
+                        write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
+                    }
+                    "job" if in_ai_job && stop_worker => {
+                        skip_job_depth = 1;
+                    }
                     "fillUnit" if current_vehicle_id.is_some() => {
                         in_fill_unit = true;
                         write_event(&mut writer, &xml_path, Event::Start(e.clone().into_owned()))?;
@@ -86,15 +125,25 @@ pub fn write_vehicle_changes(
                 }
             }
             Ok(Event::Empty(ref e)) => {
-                if skip_until_vehicle_end {
+                if skip_until_vehicle_end || skip_job_depth > 0 {
                     continue;
                 }
 
                 let tag = String::from_utf8_lossy(e.name().as_ref()).to_string();
 
+                if tag == "job" && in_ai_job && stop_worker {
+                    continue;
+                }
+
+                if tag == "attachedVehicle"
+                    && deleted_ids.contains(raw_attr_str(e, "attachedVehicleUniqueId").as_str())
+                {
+                    continue;
+                }
+
                 if tag == "unit" && in_fill_unit {
                     if let Some(fill_changes) = current_fill_changes {
-                        let unit_index: u32 = attr_str(e, "index").parse().unwrap_or(u32::MAX);
+                        let unit_index: u32 = raw_attr_str(e, "index").parse().unwrap_or(u32::MAX);
                         if let Some(fc) = fill_changes.iter().find(|f| f.index == unit_index) {
                             let elem = patch_fill_unit(e, fc);
                             write_event(&mut writer, &xml_path, Event::Empty(elem))?;
@@ -145,17 +194,26 @@ pub fn write_vehicle_changes(
                     }
                     continue;
                 }
+                if skip_job_depth > 0 {
+                    skip_job_depth -= 1;
+                    continue;
+                }
 
                 match tag.as_str() {
                     "vehicle" => {
                         current_vehicle_id = None;
                         current_fill_changes = None;
+                        stop_worker = false;
                         write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
                     }
                     "fillUnit" => {
                         in_fill_unit = false;
                         write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
                     }
+                    "aiJobVehicle" => {
+                        in_ai_job = false;
+                        write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
+                    }
                     _ => {
                         write_event(&mut writer, &xml_path, Event::End(e.clone().into_owned()))?;
                     }
@@ -163,33 +221,18 @@ pub fn write_vehicle_changes(
             }
             Ok(Event::Eof) => break,
             Ok(event) => {
-                if !skip_until_vehicle_end {
+                if !skip_until_vehicle_end && skip_job_depth == 0 {
                     write_event(&mut writer, &xml_path, event.into_owned())?;
                 }
             }
-            Err(e) => {
-                return Err(AppError::XmlParseError {
-                    file: xml_path.display().to_string(),
-                    message: e.to_string(),
-                });
-            }
+            Err(e) => return Err(xml_error(&xml_path, e)),
         }
     }
 
     let output = writer.into_inner();
-    let tmp_path = xml_path.with_extension("xml.tmp");
-    std::fs::write(&tmp_path, &output)?;
-    std::fs::rename(&tmp_path, &xml_path)?;
-
-    Ok(())
-}
+    atomic_write(&xml_path, &encode_xml(&output, encoding))?;
 
-fn attr_str(e: &BytesStart, key: &str) -> String {
-    e.attributes()
-        .flatten()
-        .find(|a| a.key.as_ref() == key.as_bytes())
-        .map(|a| String::from_utf8_lossy(&a.value).to_string())
-        .unwrap_or_default()
+    Ok(matched)
 }
 
 fn property_state_to_xml(state: &str) -> &str {
@@ -203,132 +246,45 @@ fn property_state_to_xml(state: &str) -> &str {
 }
 
 fn patch_vehicle_start(e: &BytesStart, change: &VehicleChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("vehicle");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "age" if change.age.is_some() => {
-                elem.push_attribute(("age", format!("{:.6}", change.age.unwrap()).as_str()));
-            }
-            "price" if change.price.is_some() => {
-                elem.push_attribute(("price", format!("{:.6}", change.price.unwrap()).as_str()));
-            }
-            "farmId" if change.farm_id.is_some() => {
-                elem.push_attribute(("farmId", change.farm_id.unwrap().to_string().as_str()));
-            }
-            "propertyState" if change.property_state.is_some() => {
-                elem.push_attribute((
-                    "propertyState",
-                    property_state_to_xml(change.property_state.as_ref().unwrap()),
-                ));
-            }
-            "operatingTime" if change.operating_time.is_some() => {
-                elem.push_attribute((
-                    "operatingTime",
-                    format!("{:.6}", change.operating_time.unwrap() * 3600.0).as_str(),
-                ));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+    patch_attributes(e, |key| match key {
+        "age" => change.age.map(|v| format!("{:.6}", v)),
+        "price" => change.price.map(|v| format!("{:.6}", v)),
+        "farmId" => change.farm_id.map(|v| v.to_string()),
+        "propertyState" => change
+            .property_state
+            .as_ref()
+            .map(|v| property_state_to_xml(v).to_string()),
+        "operatingTime" => change.operating_time.map(|v| format!("{:.6}", v * 3600.0)),
+        _ => None,
+    })
 }
 
 fn patch_wearable(e: &BytesStart, change: &VehicleChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("wearable");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "damage" if change.damage.is_some() => {
-                elem.push_attribute(("damage", format!("{:.6}", change.damage.unwrap()).as_str()));
-            }
-            "wear" if change.wear.is_some() => {
-                elem.push_attribute(("wear", format!("{:.6}", change.wear.unwrap()).as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+    patch_attributes(e, |key| match key {
+        "damage" => change.damage.map(|v| format!("{:.6}", v)),
+        "wear" => change.wear.map(|v| format!("{:.6}", v)),
+        _ => None,
+    })
 }
 
 fn patch_wearable_start(e: &BytesStart, change: &VehicleChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("wearable");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "damage" if change.damage.is_some() => {
-                elem.push_attribute(("damage", format!("{:.6}", change.damage.unwrap()).as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+    patch_attributes(e, |key| match key {
+        "damage" => change.damage.map(|v| format!("{:.6}", v)),
+        _ => None,
+    })
 }
 
 fn patch_wear_node(e: &BytesStart, change: &VehicleChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("wearNode");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "amount" if change.wear.is_some() => {
-                elem.push_attribute(("amount", format!("{:.6}", change.wear.unwrap()).as_str()));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
+    patch_attributes(e, |key| match key {
+        "amount" => change.wear.map(|v| format!("{:.6}", v)),
+        _ => None,
+    })
 }
 
 fn patch_fill_unit(e: &BytesStart, change: &FillUnitChange) -> BytesStart<'static> {
-    let mut elem = BytesStart::new("unit");
-    for attr in e.attributes().flatten() {
-        let key = String::from_utf8_lossy(attr.key.as_ref()).to_string();
-        match key.as_str() {
-            "fillLevel" => {
-                elem.push_attribute((
-                    "fillLevel",
-                    format!("{:.6}", change.fill_level).as_str(),
-                ));
-            }
-            _ => {
-                elem.push_attribute((
-                    key.as_str(),
-                    String::from_utf8_lossy(&attr.value).as_ref(),
-                ));
-            }
-        }
-    }
-    elem
-}
-
-fn write_event(
-    writer: &mut Writer<Vec<u8>>,
-    xml_path: &Path,
-    event: Event<'static>,
-) -> Result<(), AppError> {
-    writer.write_event(event).map_err(|e| AppError::XmlParseError {
-        file: xml_path.display().to_string(),
-        message: e.to_string(),
+    patch_attributes(e, |key| match key {
+        "fillLevel" => Some(format!("{:.6}", change.fill_level)),
+        _ => None,
     })
 }
 
@@ -359,15 +315,8 @@ mod tests {
         let save = setup_fixture("price");
         let changes = vec![VehicleChange {
             unique_id: "vehicle0001".to_string(),
-            delete: false,
-            age: None,
             price: Some(999999.0),
-            farm_id: None,
-            property_state: None,
-            operating_time: None,
-            damage: None,
-            wear: None,
-            fill_units: None,
+            ..Default::default()
         }];
         write_vehicle_changes(&save, &changes).unwrap();
         let vehicles = parse_vehicles(&save).unwrap();
@@ -376,20 +325,28 @@ mod tests {
         let _ = std::fs::remove_dir_all(&save);
     }
 
+    #[test]
+    fn test_write_vehicle_reports_matched_ids() {
+        let save = setup_fixture("matched_ids");
+        let change = |unique_id: &str| VehicleChange {
+            unique_id: unique_id.to_string(),
+            age: Some(1.0),
+            ..Default::default()
+        };
+        let matched =
+            write_vehicle_changes(&save, &[change("vehicle0002"), change("vehicle9999")]).unwrap();
+        assert_eq!(matched, HashSet::from(["vehicle0002".to_string()]));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
     #[test]
     fn test_write_vehicle_age() {
         let save = setup_fixture("age");
         let changes = vec![VehicleChange {
             unique_id: "vehicle0001".to_string(),
-            delete: false,
             age: Some(0.0),
-            price: None,
-            farm_id: None,
-            property_state: None,
-            operating_time: Some(0.0), // 0 hours
-            damage: None,
-            wear: None,
-            fill_units: None,
+            operating_time: Some(0.0),
+            ..Default::default()
         }];
         write_vehicle_changes(&save, &changes).unwrap();
         let vehicles = parse_vehicles(&save).unwrap();
@@ -404,18 +361,11 @@ mod tests {
         let save = setup_fixture("fill");
         let changes = vec![VehicleChange {
             unique_id: "vehicle0001".to_string(),
-            delete: false,
-            age: None,
-            price: None,
-            farm_id: None,
-            property_state: None,
-            operating_time: None,
-            damage: None,
-            wear: None,
             fill_units: Some(vec![FillUnitChange {
                 index: 0,
                 fill_level: 500.0,
             }]),
+            ..Default::default()
         }];
         write_vehicle_changes(&save, &changes).unwrap();
         let vehicles = parse_vehicles(&save).unwrap();
@@ -431,14 +381,7 @@ mod tests {
         let changes = vec![VehicleChange {
             unique_id: "vehicle0002".to_string(),
             delete: true,
-            age: None,
-            price: None,
-            farm_id: None,
-            property_state: None,
-            operating_time: None,
-            damage: None,
-            wear: None,
-            fill_units: None,
+            ..Default::default()
         }];
         write_vehicle_changes(&save, &changes).unwrap();
         let vehicles = parse_vehicles(&save).unwrap();
@@ -447,21 +390,51 @@ mod tests {
         let _ = std::fs::remove_dir_all(&save);
     }
 
+    #[test]
+    fn test_write_vehicle_delete_drops_attachment() {
+        let save = setup_fixture("delete_attached");
+        let changes = vec![VehicleChange {
+            unique_id: "vehicle0003".to_string(),
+            delete: true,
+            ..Default::default()
+        }];
+        write_vehicle_changes(&save, &changes).unwrap();
+        let vehicles = parse_vehicles(&save).unwrap();
+        assert_eq!(vehicles.len(), 2);
+        let tractor = vehicles.iter().find(|v| v.unique_id == "vehicle0001").unwrap();
+        assert!(tractor.attached_implements.is_empty());
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
+    #[test]
+    fn test_write_vehicle_delete_cascade() {
+        let save = setup_fixture("delete_cascade");
+        let changes = vec![VehicleChange {
+            unique_id: "vehicle0001".to_string(),
+            delete: true,
+            cascade: true,
+            ..Default::default()
+        }];
+        write_vehicle_changes(&save, &changes).unwrap();
+        let vehicles = parse_vehicles(&save).unwrap();
+        // The trailer attached to the tractor goes with it
+        let ids: Vec<&str> = vehicles.iter().map(|v| v.unique_id.as_str()).collect();
+        assert_eq!(ids, vec!["vehicle0002"]);
+        assert!(vehicles.iter().all(|v| v
+            .attached_implements
+            .iter()
+            .all(|a| ids.contains(&a.attached_vehicle_unique_id.as_str()))));
+        let _ = std::fs::remove_dir_all(&save);
+    }
+
     #[test]
     fn test_write_vehicle_preserves_unknown() {
         let save = setup_fixture("preserve");
         let before = parse_vehicles(&save).unwrap();
         let changes = vec![VehicleChange {
             unique_id: "vehicle0001".to_string(),
-            delete: false,
-            age: None,
             price: Some(1.0),
-            farm_id: None,
-            property_state: None,
-            operating_time: None,
-            damage: None,
-            wear: None,
-            fill_units: None,
+            ..Default::default()
         }];
         write_vehicle_changes(&save, &changes).unwrap();
         let after = parse_vehicles(&save).unwrap();
@@ -489,18 +462,15 @@ mod tests {
         // Modify then re-read
         let changes = vec![VehicleChange {
             unique_id: "vehicle0001".to_string(),
-            delete: false,
             age: Some(99.0),
             price: Some(123456.0),
-            farm_id: None,
-            property_state: None,
-            operating_time: Some(999.0), // 999 hours, writer converts to 59940 minutes in XML
-            damage: None,
-            wear: None,
+            operating_time: Some(999.0),
+            // 999 hours,
             fill_units: Some(vec![
                 FillUnitChange { index: 0, fill_level: 111.0 },
                 FillUnitChange { index: 1, fill_level: 22.0 },
             ]),
+            ..Default::default()
         }];
         write_vehicle_changes(&save, &changes).unwrap();
         let after = parse_vehicles(&save).unwrap();
@@ -526,27 +496,13 @@ mod tests {
         let changes = vec![
             VehicleChange {
                 unique_id: "vehicle0001".to_string(),
-                delete: false,
-                age: None,
                 price: Some(100.0),
-                farm_id: None,
-                property_state: None,
-                operating_time: None,
-                damage: None,
-                wear: None,
-                fill_units: None,
+                ..Default::default()
             },
             VehicleChange {
                 unique_id: "vehicle0003".to_string(),
-                delete: false,
                 age: Some(0.0),
-                price: None,
-                farm_id: None,
-                property_state: None,
-                operating_time: None,
-                damage: None,
-                wear: None,
-                fill_units: None,
+                ..Default::default()
             },
         ];
         write_vehicle_changes(&save, &changes).unwrap();
@@ -560,4 +516,24 @@ mod tests {
 
         let _ = std::fs::remove_dir_all(&save);
     }
+
+    #[test]
+    fn test_write_vehicle_stop_worker() {
+        let save = setup_fixture("stop_worker");
+        let changes = vec![VehicleChange {
+            unique_id: "vehicle0001".to_string(),
+            stop_worker: true,
+            ..Default::default()
+        }];
+        write_vehicle_changes(&save, &changes).unwrap();
+
+        let vehicles = parse_vehicles(&save).unwrap();
+        let v = vehicles.iter().find(|v| v.unique_id == "vehicle0001").unwrap();
+        assert_eq!(v.worker_task, None);
+        assert_eq!(v.attached_implements.len(), 1);
+        let content = std::fs::read_to_string(save.join("vehicles.xml")).unwrap();
+        assert!(content.contains("<aiJobVehicle>"));
+        assert!(!content.contains("<job "));
+        let _ = std::fs::remove_dir_all(&save);
+    }
 }
@@ -70,6 +70,14 @@ export function useVehicleImages() {
     return bytes;
   }
 
+  /// Re-resolve images without deleting the converted files, e.g. after a mod update.
+  async function resetImageIndex(): Promise<number> {
+    const dropped = await invoke<number>("reset_image_index");
+    imageCache.value.clear();
+    triggerRef(imageCache);
+    return dropped;
+  }
+
   async function getCacheSize(): Promise<number> {
     return invoke<number>("get_image_cache_size");
   }
@@ -81,6 +89,7 @@ export function useVehicleImages() {
     getImageUrl,
     detectGamePath,
     clearDiskCache,
+    resetImageIndex,
     getCacheSize,
   };
 }
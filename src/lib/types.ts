@@ -6,11 +6,20 @@ export interface LocalizedMessage {
 export interface SavegameSummary {
   path: string;
   name: string;
+  mapId: string;
   mapTitle: string;
+  mapDisplay: string;
+  isModMap: boolean;
   money: number;
   playTime: number;
   saveDate: string;
   economicDifficulty: string;
+  isMultiplayer: boolean;
+}
+
+export interface SavegameListing {
+  basePath: string;
+  summary: SavegameSummary;
 }
 
 export interface CareerSavegame {
@@ -18,6 +27,8 @@ export interface CareerSavegame {
   creationDate: string;
   mapId: string;
   mapTitle: string;
+  mapDisplay: string;
+  isModMap: boolean;
   saveDate: string;
   economicDifficulty: string;
   money: number;
@@ -31,6 +42,14 @@ export interface CareerSavegame {
   snowEnabled: boolean;
   fuelUsage: number;
   trafficEnabled: boolean;
+  guidedTourCompleted: boolean | null;
+  economicMultipliers: EconomicMultipliers;
+}
+
+export interface EconomicMultipliers {
+  income: number;
+  expenses: number;
+  custom: boolean;
 }
 
 export interface Farm {
@@ -38,12 +57,42 @@ export interface Farm {
   name: string;
   color: number;
   loan: number;
+  loanInterestRate: number | null;
   money: number;
   players: FarmPlayer[];
   statistics: FarmStatistics;
   dailyFinances: DailyFinance[];
 }
 
+/** Estimated balance over the next in-game days; not a forecast of actual play. */
+export interface FinanceProjection {
+  farmId: number;
+  startingMoney: number;
+  averageDailyNet: number;
+  daysSampled: number;
+  balances: ProjectedBalance[];
+}
+
+export interface ProjectedBalance {
+  day: number;
+  money: number;
+}
+
+export interface LoanPayoff {
+  farmId: number;
+  loanBefore: number;
+  loanAfter: number;
+  moneyBefore: number;
+  moneyAfter: number;
+  paid: boolean;
+}
+
+export interface PlayerEntry {
+  farmId: number;
+  farmName: string;
+  player: FarmPlayer;
+}
+
 export interface FarmPlayer {
   uniqueUserId: string;
   farmManager: boolean;
@@ -174,6 +223,7 @@ export interface Vehicle {
   configurations: VehicleConfiguration[];
   fillUnits: FillUnit[];
   attachedImplements: AttachedImplement[];
+  workerTask: string | null;
 }
 
 export interface VehicleConfiguration {
@@ -236,6 +286,29 @@ export interface Farmland {
   farmId: number;
 }
 
+export interface FieldDetail {
+  id: number;
+  farmId: number;
+  fruitType: string;
+  growthState: number;
+  areaHectares: number | null;
+  value: number | null;
+}
+
+export interface FarmFieldOwnership {
+  farmId: number;
+  ownedFields: number;
+  ownedPercent: number;
+}
+
+export interface FieldOwnershipSummary {
+  totalFields: number;
+  ownedFields: number;
+  contractedFields: number;
+  ownedPercent: number;
+  farms: FarmFieldOwnership[];
+}
+
 export interface FieldChangePayload {
   id: number;
   fruitType?: string;
@@ -253,6 +326,8 @@ export interface FieldChangePayload {
   waterLevel?: number;
 }
 
+export type FieldOperation = "plow" | "cultivate" | "lime" | "fertilize";
+
 export interface FarmlandChangePayload {
   id: number;
   farmId: number;
@@ -321,6 +396,9 @@ export interface Environment {
   weatherForecast: WeatherEvent[];
   snowHeight: number;
   groundWetness: number;
+  /** Past weather, oldest first; null for saves that do not keep it. */
+  weatherHistory: WeatherEvent[] | null;
+  timeSinceLastRain: number | null;
 }
 
 export interface WeatherEvent {
@@ -372,6 +450,18 @@ export interface ProductionStock {
   capacity: number;
 }
 
+export interface SellingStation {
+  placeableIndex: number;
+  displayName: string;
+  stats: SellingStationStat[];
+}
+
+export interface SellingStationStat {
+  fillType: string;
+  received: number;
+  paid: number;
+}
+
 export interface PlaceableChangePayload {
   index: number;
   farmId?: number;
@@ -379,6 +469,7 @@ export interface PlaceableChangePayload {
   completeConstruction: boolean;
   productionInputs?: ProductionStockChangePayload[];
   productionOutputs?: ProductionStockChangePayload[];
+  allowOverfill?: boolean;
 }
 
 export interface ProductionStockChangePayload {
@@ -402,6 +493,27 @@ export interface Mission {
 export interface Collectible {
   index: number;
   collected: boolean;
+  name: string;
+  region: string | null;
+  marked: boolean | null;
+}
+
+export interface PlantedTree {
+  index: number;
+  treeType: string;
+  growthStage: number;
+  isGrowing: boolean;
+  position: Position | null;
+}
+
+export interface TreeTypeCount {
+  treeType: string;
+  /** A u64 on the Rust side, exact as a number up to 2^53 trees. */
+  count: number;
+}
+
+export interface TreeChanges {
+  matureIndices: number[] | null;
 }
 
 export interface ContractSettings {
@@ -422,6 +534,7 @@ export interface MissionChangePayload {
 export interface CollectibleChangePayload {
   index: number;
   collected: boolean;
+  marked?: boolean;
 }
 
 export interface ContractSettingsChangePayload {
@@ -460,6 +573,7 @@ export interface PeriodPrice {
 
 export interface GreatDemandChangePayload {
   index: number;
+  uniqueId?: string;
   fillTypeName?: string;
   demandMultiplier?: number;
   demandStartDay?: number;
@@ -495,23 +609,110 @@ export interface SavegameData {
   placeables: Placeable[];
   missions: Mission[];
   collectibles: Collectible[];
+  trees: PlantedTree[];
+  treeCounts: TreeTypeCount[];
   contractSettings: ContractSettings | null;
   environment: Environment | null;
   economy: Economy | null;
+  filesWithoutDeclaration: string[];
   warnings: LocalizedMessage[];
 }
 
+export interface FileLoadError {
+  file: string;
+  message: string;
+}
+
+export interface SafeLoadResult {
+  data: SavegameData;
+  errors: FileLoadError[];
+}
+
+export type ValidatorSeverity = "info" | "warning" | "error";
+
+/** Described by the `validators.<id>` translation. */
+export interface ValidatorInfo {
+  id: string;
+  severity: ValidatorSeverity;
+}
+
+export interface ValidatorReport {
+  id: string;
+  severity: ValidatorSeverity;
+  diagnostics: LocalizedMessage[];
+}
+
 export interface BackupInfo {
   name: string;
   path: string;
   createdAt: string;
   sizeBytes: number;
+  gameCreated: boolean;
+}
+
+export interface SavegameBackups {
+  savegamePath: string;
+  savegameName: string;
+  savegameExists: boolean;
+  backups: BackupInfo[];
+  totalBytes: number;
+}
+
+export interface BackupOverview {
+  savegames: SavegameBackups[];
+  totalBytes: number;
+}
+
+export interface BackupRetentionPolicy {
+  maxTotalBytes: number;
+  allowDeletingLast?: boolean;
+}
+
+export interface PrunedBackup {
+  savegamePath: string;
+  name: string;
+  sizeBytes: number;
+}
+
+export interface PruneResult {
+  pruned: PrunedBackup[];
+  reclaimedBytes: number;
+  remainingBytes: number;
+}
+
+export interface FarmChangePayload {
+  farmId: number;
+  color?: number;
 }
 
 export interface FinanceChanges {
   money?: number;
   loan?: number;
-}
+  loanInterestRate?: number;
+}
+
+export type PlayerPermissionChanges = Partial<
+  Pick<
+    FarmPlayer,
+    | "farmManager"
+    | "buyVehicle"
+    | "sellVehicle"
+    | "buyPlaceable"
+    | "sellPlaceable"
+    | "manageContracts"
+    | "tradeAnimals"
+    | "createFields"
+    | "landscaping"
+    | "hireAssistant"
+    | "resetVehicle"
+    | "manageProductions"
+    | "cutTrees"
+    | "manageRights"
+    | "transferMoney"
+    | "updateFarm"
+    | "manageContracting"
+  >
+>;
 
 export interface CatalogVehicle {
   xmlFilename: string;
@@ -522,6 +723,52 @@ export interface CatalogVehicle {
   source: "baseGame" | { mod: string };
 }
 
+export type CatalogKind = "vehicle" | "implement" | "pallet";
+
+export interface UsedMod {
+  modName: string;
+  title: string;
+  required: boolean;
+  saveVersion: string;
+  installedVersion: string;
+  versionMismatch: boolean;
+}
+
+export interface AffectedVehicle {
+  uniqueId: string;
+  displayName: string;
+  farmId: number;
+}
+
+export interface MissingVehicleMod {
+  modName: string;
+  installed: boolean;
+  vehicles: AffectedVehicle[];
+}
+
+export type ImageFailure =
+  | "skippedPdlc"
+  | "modNotFound"
+  | "xmlNotFound"
+  | "xmlNoImage"
+  | "ddsNotFound"
+  | "decodeFailed";
+
+export interface GamePathCheck {
+  valid: boolean;
+  version: string | null;
+  missingMarkers: string[];
+  problems: LocalizedMessage[];
+}
+
+export interface ImageDiagnosis {
+  filename: string;
+  route: "baseGame" | "mod" | "skipped";
+  imagePath: string | null;
+  failure: ImageFailure | null;
+  error: string | null;
+}
+
 export interface SaleAdditionPayload {
   xmlFilename: string;
   price: number;
@@ -530,6 +777,7 @@ export interface SaleAdditionPayload {
   age: number;
   operatingTime: number;
   timeLeft: number;
+  boughtConfigurations?: BoughtConfiguration[];
 }
 
 /** Update information from GitHub Releases */
@@ -552,6 +800,9 @@ export interface VehicleChangePayload {
   damage?: number;
   wear?: number;
   fillUnits?: FillUnitChangePayload[];
+  stopWorker?: boolean;
+  /** With `delete`, also delete the implements attached below the vehicle */
+  cascade?: boolean;
 }
 
 export interface FillUnitChangePayload {
@@ -572,6 +823,7 @@ export interface SaleChangePayload {
 
 export interface SavegameChanges {
   finance?: FinanceChanges;
+  farms?: FarmChangePayload[];
   vehicles?: VehicleChangePayload[];
   sales?: SaleChangePayload[];
   saleAdditions?: SaleAdditionPayload[];
@@ -583,6 +835,8 @@ export interface SavegameChanges {
   contractSettings?: ContractSettingsChangePayload;
   environment?: EnvironmentChanges;
   economy?: EconomyChanges;
+  trees?: TreeChanges;
+  onlyFiles?: string[];
 }
 
 export interface SaveResult {
@@ -590,4 +844,154 @@ export interface SaveResult {
   backupPath: string | null;
   filesModified: string[];
   errors: LocalizedMessage[];
+  unmatchedChanges: UnmatchedChange[];
+}
+
+export interface UnmatchedChange {
+  file: string;
+  key: string;
+}
+
+export interface UnappliedChange {
+  file: string;
+  target: string;
+  property: string | null;
+  expected: string | null;
+  actual: string | null;
+}
+
+export interface VerifiedSaveResult {
+  result: SaveResult;
+  unapplied: UnappliedChange[];
+  unverified: string[];
+}
+
+export interface FreshStartKeep {
+  vehicles: boolean;
+  land: boolean;
+  statistics: boolean;
+  startingMoney?: number;
+}
+
+export interface FreshStartResult {
+  save: VerifiedSaveResult;
+  startingMoney: number;
+  vehiclesRemoved: number;
+  farmlandsReleased: number;
+  statisticsReset: boolean;
+}
+
+export interface UnsavedChanges {
+  hasChanges: boolean;
+  files: string[];
+  errors: LocalizedMessage[];
+}
+
+export interface BatchSaveResult {
+  path: string;
+  result: SaveResult;
+}
+
+export interface NetWorth {
+  farmId: number;
+  money: number;
+  vehiclesValue: number;
+  placeablesValue: number;
+  storedCropsValue: number;
+  loan: number;
+  total: number;
+}
+
+export interface VehicleValue {
+  uniqueId: string;
+  price: number;
+  implementsPrice: number;
+  fillValue: number;
+  total: number;
+  unpricedFillTypes: string[];
+}
+
+export interface SaveFileSize {
+  file: string;
+  sizeBytes: number;
+  entityCount: number | null;
+}
+
+export interface LandscapingReport {
+  files: SaveFileSize[];
+  totalBytes: number;
+  decorationCount: number;
+}
+
+export type FileChange = "added" | "removed" | "modified";
+
+export interface FileDiff {
+  file: string;
+  change: FileChange;
+}
+
+export interface SavegameDiff {
+  beforePath: string;
+  afterPath: string;
+  files: FileDiff[];
+}
+
+export type ReportFormat = "markdown" | "html";
+
+export interface ActiveWorker {
+  uniqueId: string;
+  displayName: string;
+  farmId: number;
+  task: string;
+}
+
+export type FuelKind = "diesel" | "def" | "electricCharge" | "methane";
+
+export interface FuelLevel {
+  kind: FuelKind;
+  fillLevel: number;
+  capacity: number;
+  isLow: boolean;
+}
+
+export interface VehicleFuelReport {
+  uniqueId: string;
+  displayName: string;
+  levels: FuelLevel[];
+}
+
+export type MilestoneCategory = "fields" | "animals" | "money" | "farming";
+
+export interface MilestoneProgress {
+  id: string;
+  category: MilestoneCategory;
+  current: number;
+  target: number;
+  percent: number;
+  achieved: boolean;
+}
+
+export interface FleetWear {
+  vehicleCount: number;
+  totalOperatingTime: number;
+  averageAge: number;
+  averageDamage: number;
+  averageWear: number;
+}
+
+export interface FleetWearScaling {
+  before: FleetWear;
+  after: FleetWear;
+}
+
+export interface AttachmentNode {
+  uniqueId: string;
+  children: AttachmentNode[];
+}
+
+export type LooseItemKind = "bale" | "pallet";
+
+export interface ClearedItems {
+  bales: number;
+  pallets: number;
 }
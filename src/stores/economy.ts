@@ -149,6 +149,7 @@ export const useEconomyStore = defineStore("economy", () => {
       if (!changed) continue;
 
       const change: GreatDemandChangePayload = { index: d.index };
+      if (orig.uniqueId) change.uniqueId = orig.uniqueId;
       if (d.fillTypeName !== orig.fillTypeName) change.fillTypeName = d.fillTypeName;
       if (d.demandMultiplier !== orig.demandMultiplier) change.demandMultiplier = d.demandMultiplier;
       if (d.demandStartDay !== orig.demandStartDay) change.demandStartDay = d.demandStartDay;
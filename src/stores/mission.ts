@@ -73,7 +73,7 @@ export const useMissionStore = defineStore("mission", () => {
       if (m.depositedLiters !== orig.depositedLiters) count++;
     }
     for (let i = 0; i < collectibles.value.length; i++) {
-      if (collectibles.value[i].collected !== originalCollectibles.value[i]?.collected) {
+      if (isCollectibleChanged(i)) {
         count++;
       }
     }
@@ -96,10 +96,14 @@ export const useMissionStore = defineStore("mission", () => {
     });
   }
 
+  function isCollectibleChanged(i: number): boolean {
+    const c = collectibles.value[i];
+    const orig = originalCollectibles.value[i];
+    return c.collected !== orig?.collected || c.marked !== orig?.marked;
+  }
+
   function isCollectiblesDirty(): boolean {
-    return collectibles.value.some(
-      (c, i) => c.collected !== originalCollectibles.value[i]?.collected,
-    );
+    return collectibles.value.some((_, i) => isCollectibleChanged(i));
   }
 
   function isContractSettingsDirty(): boolean {
@@ -246,6 +250,13 @@ export const useMissionStore = defineStore("mission", () => {
     }
   }
 
+  function toggleCollectibleMarker(index: number) {
+    const c = collectibles.value.find((co) => co.index === index);
+    if (c && c.marked !== null) {
+      c.marked = !c.marked;
+    }
+  }
+
   function collectAll() {
     for (const c of collectibles.value) {
       c.collected = true;
@@ -316,8 +327,10 @@ export const useMissionStore = defineStore("mission", () => {
     for (let i = 0; i < collectibles.value.length; i++) {
       const c = collectibles.value[i];
       const orig = originalCollectibles.value[i];
-      if (c.collected !== orig?.collected) {
-        collectibleChanges.push({ index: c.index, collected: c.collected });
+      if (isCollectibleChanged(i)) {
+        const change: CollectibleChangePayload = { index: c.index, collected: c.collected };
+        if (c.marked !== null && c.marked !== orig?.marked) change.marked = c.marked;
+        collectibleChanges.push(change);
       }
     }
     if (collectibleChanges.length > 0) {
@@ -365,6 +378,7 @@ export const useMissionStore = defineStore("mission", () => {
     canCompleteMission,
     completeMission,
     toggleCollectible,
+    toggleCollectibleMarker,
     collectAll,
     resetAllCollectibles,
     updateContractSettings,
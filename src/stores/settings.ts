@@ -2,6 +2,7 @@ import { defineStore } from "pinia";
 import { ref } from "vue";
 import { load } from "@tauri-apps/plugin-store";
 import { useTheme, type ThemeMode } from "@/composables/useTheme";
+import { useTauri } from "@/composables/useTauri";
 import i18n from "@/plugins/i18n";
 
 const STORE_FILE = "settings.json";
@@ -15,9 +16,12 @@ export const useSettingsStore = defineStore("settings", () => {
   const maxBackups = ref(10);
   const gamePath = ref<string | null>(null);
   const checkForUpdatesOnStartup = ref(true);
+  // Savegame file names to use instead of the expected ones, by expected name
+  const saveFileNames = ref<Record<string, string>>({});
   const settingsLoaded = ref(false);
 
   const { initTheme, setTheme: applyTheme } = useTheme();
+  const { invokeCommand } = useTauri();
 
   async function loadSettings() {
     try {
@@ -30,6 +34,7 @@ export const useSettingsStore = defineStore("settings", () => {
       const savedMaxBackups = await store.get<number>("maxBackups");
       const savedGamePath = await store.get<string | null>("gamePath");
       const savedCheckForUpdates = await store.get<boolean>("checkForUpdatesOnStartup");
+      const savedSaveFileNames = await store.get<Record<string, string>>("saveFileNames");
 
       if (savedLocale) {
         locale.value = savedLocale;
@@ -46,6 +51,10 @@ export const useSettingsStore = defineStore("settings", () => {
       maxBackups.value = savedMaxBackups ?? 10;
       gamePath.value = savedGamePath ?? null;
       checkForUpdatesOnStartup.value = savedCheckForUpdates ?? true;
+      saveFileNames.value = savedSaveFileNames ?? {};
+      if (Object.keys(saveFileNames.value).length > 0) {
+        await invokeCommand("set_save_file_names", { names: saveFileNames.value });
+      }
 
       // Apply locale and theme
       i18n.global.locale.value = locale.value as "fr" | "en";
@@ -72,6 +81,7 @@ export const useSettingsStore = defineStore("settings", () => {
       await store.set("maxBackups", maxBackups.value);
       await store.set("gamePath", gamePath.value);
       await store.set("checkForUpdatesOnStartup", checkForUpdatesOnStartup.value);
+      await store.set("saveFileNames", saveFileNames.value);
       await store.save();
     } catch {
       // Silently fail — settings are still in memory
@@ -125,6 +135,12 @@ export const useSettingsStore = defineStore("settings", () => {
     await persist();
   }
 
+  async function setSaveFileNames(names: Record<string, string>) {
+    await invokeCommand("set_save_file_names", { names });
+    saveFileNames.value = names;
+    await persist();
+  }
+
   return {
     locale,
     theme,
@@ -135,6 +151,7 @@ export const useSettingsStore = defineStore("settings", () => {
     maxBackups,
     gamePath,
     checkForUpdatesOnStartup,
+    saveFileNames,
     loadSettings,
     setLocale,
     setTheme,
@@ -145,5 +162,6 @@ export const useSettingsStore = defineStore("settings", () => {
     setMaxBackups,
     setGamePath,
     setCheckForUpdatesOnStartup,
+    setSaveFileNames,
   };
 });